@@ -3,8 +3,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-    MultiSignature,
-    traits::{IdentifyAccount, Verify},
+    BoundedVec, MultiSignature,
+    traits::{ConstU32, IdentifyAccount, Verify},
 };
 
 /// Balance of an account.
@@ -31,9 +31,10 @@ pub type Nonce = u32;
 /// Transfers below SMALL_TRANSFER_LIMIT are considered small transfers
 pub const SMALL_TRANSFER_LIMIT: Balance = 500_000_000; // 0.5 TAO
 
-#[derive(
-    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, Debug, MaxEncodedLen, TypeInfo,
-)]
+/// The maximum number of hotkeys a `HotkeyStaking` proxy can be restricted to
+pub const MAX_PROXY_HOTKEYS: u32 = 4;
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, Debug, MaxEncodedLen, TypeInfo)]
 pub enum ProxyType {
     Any,
     Owner, // Subnet owner Calls
@@ -50,6 +51,16 @@ pub enum ProxyType {
     RootWeights,
     ChildKeys,
     SudoUncheckedSetCode,
+    SubnetOwner(u16),   // Subnet owner Calls on the given subnet only
+    SubnetStaking(u16), // Staking Calls on the given subnet only
+    SubnetWeights(u16), // Weights Calls on the given subnet only
+    HotkeyStaking(BoundedVec<AccountId, ConstU32<MAX_PROXY_HOTKEYS>>), // Staking Calls into the given hotkeys only
+    // Transfer and staking Calls spending at most `budget` per `window` blocks
+    SpendingLimit {
+        budget: Balance,
+        window: BlockNumber,
+    },
+    Weights, // Weight setting Calls on any subnet
 }
 
 impl Default for ProxyType {
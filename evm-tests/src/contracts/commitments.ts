@@ -0,0 +1,139 @@
+export const ICOMMITMENTS_ADDRESS = "0x0000000000000000000000000000000000000808";
+
+export const ICommitmentsABI = [
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "clearCommitment",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "account",
+                type: "bytes32",
+            },
+        ],
+        name: "getCommitment",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "block",
+                type: "uint64",
+            },
+            {
+                internalType: "uint64",
+                name: "deposit",
+                type: "uint64",
+            },
+            {
+                components: [
+                    {
+                        internalType: "uint8",
+                        name: "kind",
+                        type: "uint8",
+                    },
+                    {
+                        internalType: "bytes",
+                        name: "data",
+                        type: "bytes",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "revealRound",
+                        type: "uint64",
+                    },
+                ],
+                internalType: "struct CommitmentField[]",
+                name: "fields",
+                type: "tuple[]",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "account",
+                type: "bytes32",
+            },
+        ],
+        name: "getRevealedCommitments",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes",
+                        name: "data",
+                        type: "bytes",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "revealedBlock",
+                        type: "uint64",
+                    },
+                ],
+                internalType: "struct RevealedCommitment[]",
+                name: "",
+                type: "tuple[]",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                components: [
+                    {
+                        internalType: "uint8",
+                        name: "kind",
+                        type: "uint8",
+                    },
+                    {
+                        internalType: "bytes",
+                        name: "data",
+                        type: "bytes",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "revealRound",
+                        type: "uint64",
+                    },
+                ],
+                internalType: "struct CommitmentField[]",
+                name: "fields",
+                type: "tuple[]",
+            },
+        ],
+        name: "setCommitment",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+];
@@ -1,6 +1,180 @@
 export const INEURON_ADDRESS = "0x0000000000000000000000000000000000000804";
 
 export const INeuronABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint128",
+                name: "ip",
+                type: "uint128",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "port",
+                type: "uint16",
+            },
+        ],
+        name: "AxonServed",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "coldkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16",
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "burned",
+                type: "uint64",
+            },
+        ],
+        name: "NeuronRegistered",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint128",
+                name: "ip",
+                type: "uint128",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "port",
+                type: "uint16",
+            },
+        ],
+        name: "PrometheusServed",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "bytes32",
+                name: "commitHash",
+                type: "bytes32",
+            },
+        ],
+        name: "WeightsCommitted",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "versionKey",
+                type: "uint64",
+            },
+        ],
+        name: "WeightsRevealed",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint64",
+                name: "versionKey",
+                type: "uint64",
+            },
+        ],
+        name: "WeightsSet",
+        type: "event",
+    },
     {
         inputs: [
             {
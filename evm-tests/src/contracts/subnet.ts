@@ -1,6 +1,81 @@
 export const ISUBNET_ADDRESS = "0x0000000000000000000000000000000000000803";
 
 export const ISubnetABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "alphaLow",
+                type: "uint16",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "alphaHigh",
+                type: "uint16",
+            },
+        ],
+        name: "AlphaValuesSet",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes4",
+                name: "setter",
+                type: "bytes4",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "value",
+                type: "uint256",
+            },
+        ],
+        name: "HyperparameterSet",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "owner",
+                type: "bytes32",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+        ],
+        name: "NetworkRegistered",
+        type: "event",
+    },
     {
         inputs: [
             {
@@ -20,6 +95,30 @@ export const ISubnetABI = [
         stateMutability: "view",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint64",
+                name: "window",
+                type: "uint64",
+            },
+        ],
+        name: "getAlphaTwap",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
     {
         inputs: [
             {
@@ -0,0 +1,115 @@
+import * as assert from "assert";
+import { getAliceSigner, getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { PolkadotSigner, TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58, convertH160ToPublicKey } from "../src/address-utils"
+import { ethers } from "ethers"
+import { ICOMMITMENTS_ADDRESS, ICommitmentsABI } from "../src/contracts/commitments"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, burnedRegister,
+} from "../src/subtensor"
+
+// field kinds, see `precompiles/src/solidity/commitments.sol`
+const FIELD_RAW = 1;
+const FIELD_SHA_256 = 3;
+const FIELD_TIMELOCK_ENCRYPTED = 6;
+
+describe("Test commitments precompile set and get commitments", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+
+    // sudo account alice as signer
+    let alice: PolkadotSigner;
+
+    let netuid: number;
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+        alice = await getAliceSigner();
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(alice.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+
+        // only hotkeys registered on the subnet can commit
+        await burnedRegister(api, netuid, convertH160ToSS58(wallet.address), coldkey)
+    })
+
+    it("EVM set commitment via call precompile", async () => {
+        const contract = new ethers.Contract(ICOMMITMENTS_ADDRESS, ICommitmentsABI, wallet);
+        const ss58Address = convertH160ToSS58(wallet.address)
+        const publicKey = convertH160ToPublicKey(wallet.address)
+
+        // the runtime allows a single field per commitment, so each kind is committed on its own
+        const hash = ethers.sha256(ethers.toUtf8Bytes("model"))
+        for (const field of [
+            { kind: FIELD_RAW, data: ethers.hexlify(ethers.toUtf8Bytes("https://example.com/model")), revealRound: 0 },
+            { kind: FIELD_SHA_256, data: hash, revealRound: 0 },
+            { kind: FIELD_TIMELOCK_ENCRYPTED, data: "0x0102030405", revealRound: 1_000_000_000 },
+        ]) {
+            const tx = await contract.setCommitment(netuid, [field])
+            await tx.wait()
+
+            const registration = await api.query.Commitments.CommitmentOf.getValue(netuid, ss58Address)
+            assert.ok(registration !== undefined)
+            assert.equal(registration.info.fields.length, 1)
+
+            const [block, , fields] = await contract.getCommitment(netuid, publicKey)
+            assert.equal(block, BigInt(registration.block))
+            assert.equal(fields.length, 1)
+            assert.equal(fields[0].kind, BigInt(field.kind))
+            assert.equal(fields[0].data, field.data)
+            assert.equal(fields[0].revealRound, BigInt(field.revealRound))
+        }
+
+        // nothing is revealed before the reveal round
+        const revealed = await contract.getRevealedCommitments(netuid, publicKey)
+        assert.equal(revealed.length, 0)
+    })
+
+    it("EVM set commitment rejects invalid fields", async () => {
+        const contract = new ethers.Contract(ICOMMITMENTS_ADDRESS, ICommitmentsABI, wallet);
+
+        // hashes must be 32 bytes
+        await assert.rejects(
+            contract.setCommitment(netuid, [{ kind: FIELD_SHA_256, data: "0x01", revealRound: 0 }])
+        )
+        // unknown kind
+        await assert.rejects(
+            contract.setCommitment(netuid, [{ kind: 42, data: "0x", revealRound: 0 }])
+        )
+        // more fields than the runtime allows
+        await assert.rejects(
+            contract.setCommitment(netuid, [
+                { kind: FIELD_RAW, data: "0x01", revealRound: 0 },
+                { kind: FIELD_RAW, data: "0x02", revealRound: 0 },
+            ])
+        )
+    })
+
+    it("EVM clear commitment via call precompile", async () => {
+        const contract = new ethers.Contract(ICOMMITMENTS_ADDRESS, ICommitmentsABI, wallet);
+        const tx = await contract.clearCommitment(netuid)
+        await tx.wait()
+
+        const ss58Address = convertH160ToSS58(wallet.address)
+        const registration = await api.query.Commitments.CommitmentOf.getValue(netuid, ss58Address)
+        assert.equal(registration, undefined)
+
+        const [block, deposit, fields] = await contract.getCommitment(netuid, convertH160ToPublicKey(wallet.address))
+        assert.equal(block, BigInt(0))
+        assert.equal(deposit, BigInt(0))
+        assert.equal(fields.length, 0)
+    })
+});
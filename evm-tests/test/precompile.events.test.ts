@@ -0,0 +1,263 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58, convertH160ToPublicKey } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, burnedRegister,
+    setCommitRevealWeightsEnabled, setWeightsSetRateLimit,
+} from "../src/subtensor"
+import { ISUBNET_ADDRESS, ISubnetABI } from "../src/contracts/subnet"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI, ISTAKING_V3_ADDRESS, IStakingV3ABI } from "../src/contracts/staking"
+
+const U64_MAX = BigInt("18446744073709551615")
+
+// Returns the single event `name` emitted by `contract` in `receipt`
+function findEvent(contract: ethers.Contract, receipt: ethers.TransactionReceipt, name: string) {
+    const events = receipt.logs
+        .filter((log) => log.address.toLowerCase() === (contract.target as string).toLowerCase())
+        .map((log) => contract.interface.parseLog(log))
+        .filter((event) => event !== null && event.name === name)
+    assert.equal(events.length, 1)
+    return events[0]!
+}
+
+describe("Test the events emitted by the precompiles", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    const walletPublicKey = ethers.hexlify(convertH160ToPublicKey(wallet.address));
+    // a wallet registered as a neuron, to set weights
+    const validatorWallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const hotkey2 = getRandomSubstrateKeypair();
+    const hotkey3 = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+    let netuid2: number;
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey2.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey3.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        await forceSetBalanceToEthAddress(api, validatorWallet.address)
+
+        netuid2 = await addNewSubnetwork(api, hotkey3, coldkey)
+        await burnedRegister(api, netuid2, convertH160ToSS58(validatorWallet.address), coldkey)
+    })
+
+    it("Subnet precompile emits NetworkRegistered and HyperparameterSet", async () => {
+        const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
+        let tx = await contract.registerNetwork(hotkey.publicKey);
+        let receipt = await tx.wait();
+
+        const registered = findEvent(contract, receipt, "NetworkRegistered")
+        netuid = Number(registered.args.netuid)
+        assert.equal(registered.args.owner, walletPublicKey)
+        assert.equal(registered.args.hotkey, ethers.hexlify(hotkey.publicKey))
+
+        const owner = await api.query.SubtensorModule.SubnetOwner.getValue(netuid)
+        assert.equal(owner, convertH160ToSS58(wallet.address))
+
+        tx = await contract.setServingRateLimit(netuid, 100);
+        receipt = await tx.wait();
+
+        const set = findEvent(contract, receipt, "HyperparameterSet")
+        assert.equal(Number(set.args.netuid), netuid)
+        assert.equal(set.args.setter, contract.interface.getFunction("setServingRateLimit")!.selector)
+        assert.equal(set.args.value, BigInt(100))
+    })
+
+    it("Neuron precompile emits NeuronRegistered", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const uid = await api.query.SubtensorModule.SubnetworkN.getValue(netuid)
+
+        const tx = await contract.burnedRegister(netuid, hotkey2.publicKey);
+        const receipt = await tx.wait();
+
+        const registered = findEvent(contract, receipt, "NeuronRegistered")
+        assert.equal(Number(registered.args.netuid), netuid)
+        assert.equal(registered.args.hotkey, ethers.hexlify(hotkey2.publicKey))
+        assert.equal(registered.args.coldkey, walletPublicKey)
+        assert.equal(Number(registered.args.uid), uid)
+        assert.ok(registered.args.burned > BigInt(0))
+    })
+
+    it("Staking precompile emits StakeAdded and StakeRemoved", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+
+        let tx = await contract.addStake(hotkey.publicKey, tao(20), netuid);
+        let receipt = await tx.wait();
+
+        const added = findEvent(contract, receipt, "StakeAdded")
+        const stake = BigInt(
+            await contract.getStake(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid)
+        )
+        assert.equal(added.args.coldkey, walletPublicKey)
+        assert.equal(added.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(Number(added.args.netuid), netuid)
+        assert.equal(added.args.tao, tao(20))
+        assert.equal(added.args.alpha, stake)
+
+        tx = await contract.removeStake(hotkey.publicKey, stake / BigInt(2), netuid);
+        receipt = await tx.wait();
+
+        const removed = findEvent(contract, receipt, "StakeRemoved")
+        assert.equal(removed.args.coldkey, walletPublicKey)
+        assert.equal(removed.args.alpha, stake / BigInt(2))
+        assert.ok(removed.args.tao > BigInt(0))
+    })
+
+    it("Staking precompile emits StakeMoved, StakeTransferred and StakeSwapped", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const stake = BigInt(
+            await contract.getStake(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid)
+        )
+        const amount = stake / BigInt(4)
+
+        let tx = await contract.moveStake(hotkey.publicKey, hotkey2.publicKey, netuid, netuid, amount);
+        let receipt = await tx.wait();
+
+        const moved = findEvent(contract, receipt, "StakeMoved")
+        assert.equal(moved.args.coldkey, walletPublicKey)
+        assert.equal(moved.args.originHotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(moved.args.destinationHotkey, ethers.hexlify(hotkey2.publicKey))
+        assert.equal(Number(moved.args.originNetuid), netuid)
+        assert.equal(Number(moved.args.destinationNetuid), netuid)
+        assert.ok(moved.args.alpha > BigInt(0) && moved.args.alpha <= amount)
+
+        tx = await contract.transferStake(coldkey.publicKey, hotkey.publicKey, netuid, netuid, amount);
+        receipt = await tx.wait();
+
+        const transferred = findEvent(contract, receipt, "StakeTransferred")
+        assert.equal(transferred.args.coldkey, walletPublicKey)
+        assert.equal(transferred.args.destinationColdkey, ethers.hexlify(coldkey.publicKey))
+        assert.equal(transferred.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(Number(transferred.args.originNetuid), netuid)
+        assert.equal(Number(transferred.args.destinationNetuid), netuid)
+        assert.ok(transferred.args.alpha > BigInt(0) && transferred.args.alpha <= amount)
+
+        tx = await contract.swapStake(hotkey.publicKey, netuid, netuid2, amount);
+        receipt = await tx.wait();
+
+        const swapped = findEvent(contract, receipt, "StakeSwapped")
+        assert.equal(swapped.args.coldkey, walletPublicKey)
+        assert.equal(swapped.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(Number(swapped.args.originNetuid), netuid)
+        assert.equal(Number(swapped.args.destinationNetuid), netuid2)
+        assert.equal(
+            swapped.args.alpha,
+            BigInt(await contract.getStake(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid2))
+        )
+    })
+
+    it("Staking precompile emits AlphaRecycled and AlphaBurned", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const stake = BigInt(
+            await contract.getStake(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid2)
+        )
+        const amount = stake / BigInt(4)
+
+        let tx = await contract.recycleAlpha(hotkey.publicKey, amount, netuid2);
+        let receipt = await tx.wait();
+
+        const recycled = findEvent(contract, receipt, "AlphaRecycled")
+        assert.equal(recycled.args.coldkey, walletPublicKey)
+        assert.equal(recycled.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(Number(recycled.args.netuid), netuid2)
+        assert.equal(recycled.args.alpha, amount)
+
+        tx = await contract.burnAlpha(hotkey.publicKey, amount, netuid2);
+        receipt = await tx.wait();
+
+        const burned = findEvent(contract, receipt, "AlphaBurned")
+        assert.equal(burned.args.coldkey, walletPublicKey)
+        assert.equal(burned.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(Number(burned.args.netuid), netuid2)
+        assert.equal(burned.args.alpha, amount)
+    })
+
+    it("Staking precompile emits AllAlphaUnstaked and AllStakeRemoved", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const publicKey = convertH160ToPublicKey(wallet.address)
+        const rootStake = BigInt(await contract.getStake(hotkey.publicKey, publicKey, 0))
+
+        let tx = await contract.unstakeAllAlpha(hotkey.publicKey);
+        let receipt = await tx.wait();
+
+        const unstaked = findEvent(contract, receipt, "AllAlphaUnstaked")
+        assert.equal(unstaked.args.coldkey, walletPublicKey)
+        assert.equal(unstaked.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(
+            unstaked.args.rootAlpha,
+            BigInt(await contract.getStake(hotkey.publicKey, publicKey, 0)) - rootStake
+        )
+
+        tx = await contract.unstakeAll(hotkey.publicKey);
+        receipt = await tx.wait();
+
+        const removed = findEvent(contract, receipt, "AllStakeRemoved")
+        assert.equal(removed.args.coldkey, walletPublicKey)
+        assert.equal(removed.args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.ok(removed.args.tao > BigInt(0))
+        assert.equal(BigInt(await contract.getStake(hotkey.publicKey, publicKey, 0)), BigInt(0))
+    })
+
+    it("Staking precompile emits ChildrenSet and ChildkeyTakeSet", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+
+        let tx = await contract.setChildren(hotkey2.publicKey, netuid, [{ proportion: U64_MAX, child: hotkey.publicKey }]);
+        let receipt = await tx.wait();
+
+        const childrenSet = findEvent(contract, receipt, "ChildrenSet")
+        assert.equal(childrenSet.args.hotkey, ethers.hexlify(hotkey2.publicKey))
+        assert.equal(Number(childrenSet.args.netuid), netuid)
+        assert.equal(childrenSet.args.count, BigInt(1))
+
+        tx = await contract.setChildkeyTake(hotkey2.publicKey, netuid, 1_000);
+        receipt = await tx.wait();
+
+        const takeSet = findEvent(contract, receipt, "ChildkeyTakeSet")
+        assert.equal(takeSet.args.hotkey, ethers.hexlify(hotkey2.publicKey))
+        assert.equal(Number(takeSet.args.netuid), netuid)
+        assert.equal(Number(takeSet.args.take), 1_000)
+    })
+
+    it("Neuron precompile emits WeightsSet and WeightsCommitted", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, validatorWallet);
+        const validatorPublicKey = ethers.hexlify(convertH160ToPublicKey(validatorWallet.address));
+        const uid = await api.query.SubtensorModule.Uids.getValue(netuid2, convertH160ToSS58(validatorWallet.address))
+        assert.notEqual(uid, undefined)
+
+        await setCommitRevealWeightsEnabled(api, netuid2, false)
+        await setWeightsSetRateLimit(api, netuid2, BigInt(0))
+        let tx = await contract.setWeights(netuid2, [uid], [2], 0);
+        let receipt = await tx.wait();
+
+        const weightsSet = findEvent(contract, receipt, "WeightsSet")
+        assert.equal(Number(weightsSet.args.netuid), netuid2)
+        assert.equal(weightsSet.args.hotkey, validatorPublicKey)
+        assert.equal(weightsSet.args.versionKey, BigInt(0))
+
+        await setCommitRevealWeightsEnabled(api, netuid2, true)
+        const commitHash = ethers.hexlify(ethers.randomBytes(32))
+        tx = await contract.commitWeights(netuid2, commitHash);
+        receipt = await tx.wait();
+
+        const committed = findEvent(contract, receipt, "WeightsCommitted")
+        assert.equal(Number(committed.args.netuid), netuid2)
+        assert.equal(committed.args.hotkey, validatorPublicKey)
+        assert.equal(committed.args.commitHash, commitHash)
+    })
+});
@@ -0,0 +1,191 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58 } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import { convertH160ToPublicKey } from "../src/address-utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, burnedRegister,
+} from "../src/subtensor"
+import { ISTAKING_V3_ADDRESS, IStakingV3ABI } from "../src/contracts/staking"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+
+const U64_MAX = BigInt("18446744073709551615")
+
+describe("Test staking precompile V3", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    // a hotkey owned by the wallet
+    const ownedHotkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+    let netuid2: number;
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+
+        netuid2 = await addNewSubnetwork(api, hotkey, coldkey)
+
+        console.log("test the case on subnets ", netuid, netuid2)
+
+        await burnedRegister(api, netuid, convertH160ToSS58(wallet.address), coldkey)
+    })
+
+    it("Can read the price, fees and simulated swaps", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const price = BigInt(await contract.getAlphaPrice(netuid))
+        assert.ok(price > BigInt(0))
+
+        const amount = tao(1)
+        const alpha = BigInt(await contract.simSwapTaoForAlpha(netuid, amount))
+        assert.ok(alpha > BigInt(0))
+        const taoBack = BigInt(await contract.simSwapAlphaForTao(netuid, alpha))
+        assert.ok(taoBack > BigInt(0) && taoBack <= amount)
+
+        const fee = BigInt(
+            await contract.getAddStakeFee(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid, amount)
+        )
+        assert.ok(fee < amount)
+    })
+
+    it("Can add and remove stake with a limit price", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const ss58Address = convertH160ToSS58(wallet.address)
+        const price = BigInt(await contract.getAlphaPrice(netuid))
+
+        const stakeBefore = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), ss58Address, netuid)
+        // allow the price to rise by half while staking
+        let tx = await contract.addStakeLimit(hotkey.publicKey, tao(20), price * BigInt(3) / BigInt(2), true, netuid)
+        await tx.wait()
+
+        const stakeAfterAdd = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), ss58Address, netuid)
+        assert.ok(stakeAfterAdd > stakeBefore)
+
+        const stake = BigInt(
+            await contract.getStake(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid)
+        )
+        // allow the price to fall by half while unstaking
+        tx = await contract.removeStakeLimit(hotkey.publicKey, stake / BigInt(2), price / BigInt(2), true, netuid)
+        await tx.wait()
+
+        const stakeAfterRemove = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), ss58Address, netuid)
+        assert.ok(stakeAfterRemove < stakeAfterAdd)
+    })
+
+    it("Can unstake all", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const tx = await contract.unstakeAll(hotkey.publicKey)
+        await tx.wait()
+
+        const stake = BigInt(
+            await contract.getStake(hotkey.publicKey, convertH160ToPublicKey(wallet.address), netuid)
+        )
+        assert.equal(stake, BigInt(0))
+    })
+
+    it("Can swap stake between subnets", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const publicKey = convertH160ToPublicKey(wallet.address)
+        let tx = await contract.addStake(hotkey.publicKey, tao(20), netuid)
+        await tx.wait()
+
+        const stake = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid))
+        tx = await contract.swapStake(hotkey.publicKey, netuid, netuid2, stake / BigInt(2))
+        await tx.wait()
+
+        const stakeAfterSwap = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid))
+        const stake2AfterSwap = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid2))
+        assert.ok(stakeAfterSwap < stake)
+        assert.ok(stake2AfterSwap > BigInt(0))
+
+        // the limit price is the price of the origin alpha in destination alpha,
+        // allow it to fall by half while swapping
+        const price = BigInt(await contract.getAlphaPrice(netuid))
+        const price2 = BigInt(await contract.getAlphaPrice(netuid2))
+        const limitPrice = price * tao(1) / price2 / BigInt(2)
+        tx = await contract.swapStakeLimit(hotkey.publicKey, netuid, netuid2, stakeAfterSwap, limitPrice, true)
+        await tx.wait()
+
+        const stakeAfterLimit = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid))
+        const stake2AfterLimit = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid2))
+        assert.ok(stakeAfterLimit < stakeAfterSwap)
+        assert.ok(stake2AfterLimit > stake2AfterSwap)
+    })
+
+    it("Can recycle and burn alpha", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const publicKey = convertH160ToPublicKey(wallet.address)
+        const stake = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid2))
+        const alphaOut = await api.query.SubtensorModule.SubnetAlphaOut.getValue(netuid2)
+
+        // recycled alpha is removed from the supply of the subnet
+        let tx = await contract.recycleAlpha(hotkey.publicKey, stake / BigInt(4), netuid2)
+        await tx.wait()
+
+        const stakeAfterRecycle = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid2))
+        assert.ok(stakeAfterRecycle < stake)
+        assert.ok(await api.query.SubtensorModule.SubnetAlphaOut.getValue(netuid2) < alphaOut)
+
+        tx = await contract.burnAlpha(hotkey.publicKey, stake / BigInt(4), netuid2)
+        await tx.wait()
+
+        const stakeAfterBurn = BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid2))
+        assert.ok(stakeAfterBurn < stakeAfterRecycle)
+    })
+
+    it("Can unstake all alpha", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const publicKey = convertH160ToPublicKey(wallet.address)
+        const rootStake = BigInt(await contract.getStake(hotkey.publicKey, publicKey, 0))
+
+        const tx = await contract.unstakeAllAlpha(hotkey.publicKey)
+        await tx.wait()
+
+        // the alpha of every subnet is staked on the root subnet instead
+        assert.equal(BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid)), BigInt(0))
+        assert.equal(BigInt(await contract.getStake(hotkey.publicKey, publicKey, netuid2)), BigInt(0))
+        assert.ok(BigInt(await contract.getStake(hotkey.publicKey, publicKey, 0)) > rootStake)
+    })
+
+    it("Can set children and childkey take", async () => {
+        const contract = new ethers.Contract(ISTAKING_V3_ADDRESS, IStakingV3ABI, wallet);
+        const ownedHotkeySs58 = convertPublicKeyToSs58(ownedHotkey.publicKey)
+
+        // only the coldkey of a hotkey can set its children and take
+        const neuronContract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        let tx = await neuronContract.burnedRegister(netuid, ownedHotkey.publicKey)
+        await tx.wait()
+        assert.equal(await api.query.SubtensorModule.Owner.getValue(ownedHotkeySs58), convertH160ToSS58(wallet.address))
+        await assert.rejects(
+            contract.setChildren(hotkey.publicKey, netuid, [{ proportion: U64_MAX, child: ownedHotkey.publicKey }])
+        )
+
+        // children are scheduled, and only set once the cooldown is over
+        tx = await contract.setChildren(ownedHotkey.publicKey, netuid, [{ proportion: U64_MAX, child: hotkey.publicKey }])
+        await tx.wait()
+        const [pending] = await api.query.SubtensorModule.PendingChildKeys.getValue(netuid, ownedHotkeySs58)
+        assert.equal(pending.length, 1)
+        assert.equal(pending[0][0], U64_MAX)
+        assert.equal(pending[0][1], convertPublicKeyToSs58(hotkey.publicKey))
+
+        // the take is capped
+        await assert.rejects(contract.setChildkeyTake(ownedHotkey.publicKey, netuid, 20_000))
+        tx = await contract.setChildkeyTake(ownedHotkey.publicKey, netuid, 1_000)
+        await tx.wait()
+        assert.equal(BigInt(await contract.getChildkeyTake(ownedHotkey.publicKey, netuid)), BigInt(1_000))
+        assert.equal(await api.query.SubtensorModule.ChildkeyTake.getValue(ownedHotkeySs58, netuid), 1_000)
+    })
+});
@@ -15,6 +15,11 @@ pub struct Cli {
 
     #[command(flatten)]
     pub eth: EthConfiguration,
+
+    /// Fetch drand pulses from this relay (e.g. `http://127.0.0.1:8080`) in the offchain worker,
+    /// instead of the endpoints set on chain.
+    #[arg(long)]
+    pub drand_relay: Option<String>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -229,7 +229,7 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(&cli.run)?;
             runner.run_node_until_exit(|config| async move {
                 let config = override_default_heap_pages(config, 60_000);
-                service::build_full(config, cli.eth, cli.sealing)
+                service::build_full(config, cli.eth, cli.sealing, cli.drand_relay)
                     .map_err(Into::into)
                     .await
             })
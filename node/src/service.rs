@@ -19,6 +19,7 @@ use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder as BlockBuilderApi;
 use sp_consensus::Error as ConsensusError;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_core::offchain::OffchainStorage;
 use sp_runtime::traits::{Block as BlockT, Header, NumberFor};
 use std::{cell::RefCell, path::Path};
 use std::{marker::PhantomData, sync::Arc, time::Duration};
@@ -350,6 +351,7 @@ pub async fn new_full<NB>(
     mut config: Configuration,
     eth_config: EthConfiguration,
     sealing: Option<Sealing>,
+    drand_relay: Option<String>,
 ) -> Result<TaskManager, ServiceError>
 where
     NumberFor<Block>: BlockNumberOps,
@@ -447,6 +449,24 @@ where
             log::debug!("Offchain worker key already exists");
         }
 
+        // Point the drand offchain worker at the local relay, or back at the on-chain endpoints
+        if let Some(mut offchain_storage) = backend.offchain_storage() {
+            match &drand_relay {
+                Some(relay) => {
+                    log::info!("Fetching drand pulses from relay {}", relay);
+                    offchain_storage.set(
+                        sp_core::offchain::STORAGE_PREFIX,
+                        pallet_drand::RELAY_ENDPOINT_KEY,
+                        relay.trim_end_matches('/').as_bytes(),
+                    );
+                }
+                None => offchain_storage.remove(
+                    sp_core::offchain::STORAGE_PREFIX,
+                    pallet_drand::RELAY_ENDPOINT_KEY,
+                ),
+            }
+        }
+
         task_manager.spawn_essential_handle().spawn(
             "offchain-workers-runner",
             None,
@@ -724,13 +744,16 @@ pub async fn build_full(
     config: Configuration,
     eth_config: EthConfiguration,
     sealing: Option<Sealing>,
+    drand_relay: Option<String>,
 ) -> Result<TaskManager, ServiceError> {
     match config.network.network_backend {
         sc_network::config::NetworkBackendType::Libp2p => {
-            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing).await
+            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing, drand_relay)
+                .await
         }
         sc_network::config::NetworkBackendType::Litep2p => {
-            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing).await
+            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing, drand_relay)
+                .await
         }
     }
 }
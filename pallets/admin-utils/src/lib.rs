@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-// extern crate alloc;
+extern crate alloc;
 
 pub use pallet::*;
 pub mod weights;
@@ -23,17 +23,20 @@ mod tests;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use alloc::{boxed::Box, vec::Vec};
+    use codec::Compact;
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::tokens::Balance;
+    use frame_support::traits::{UnfilteredDispatchable, tokens::Balance};
     use frame_support::{
-        dispatch::{DispatchResult, RawOrigin},
+        dispatch::{DispatchResult, GetDispatchInfo, RawOrigin, extract_actual_weight},
         pallet_prelude::StorageMap,
     };
     use frame_system::pallet_prelude::*;
     use pallet_evm_chain_id::{self, ChainId};
     use pallet_subtensor::utils::rate_limiting::TransactionType;
-    use sp_runtime::BoundedVec;
+    use sp_runtime::{BoundedVec, traits::SaturatedConversion};
     use substrate_fixed::types::I96F32;
+    use subtensor_macros::freeze_struct;
 
     /// The main data structure of the module.
     #[pallet::pallet]
@@ -83,6 +86,29 @@ pub mod pallet {
             /// Indicates if the precompile operation is enabled or not.
             enabled: bool,
         },
+        /// A hyperparameter change was queued behind the timelock.
+        HyperparameterChangeScheduled {
+            /// The id of the queued change.
+            id: u32,
+            /// The account that queued the change, `None` for root.
+            scheduler: Option<T::AccountId>,
+            /// The subnet the change applies to, if any.
+            netuid: Option<u16>,
+            /// The block at which the change is applied.
+            effective_block: u64,
+        },
+        /// A queued hyperparameter change was applied.
+        HyperparameterChangeApplied {
+            /// The id of the queued change.
+            id: u32,
+            /// The result of dispatching the change.
+            result: DispatchResult,
+        },
+        /// A queued hyperparameter change was cancelled.
+        HyperparameterChangeCancelled {
+            /// The id of the queued change.
+            id: u32,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -94,6 +120,48 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// Subnet hyperparameter changes must be scheduled while the timelock is enabled.
+        HyperparameterChangeNotScheduled,
+        /// The call cannot be queued behind the timelock.
+        CallCannotBeScheduled,
+        /// The subnet already has the maximum number of queued changes.
+        TooManyScheduledChanges,
+        /// No queued change exists with the given effective block and id.
+        ScheduledChangeNotFound,
+    }
+
+    /// The maximum number of changes a subnet owner can have queued on a subnet.
+    pub const MAX_SCHEDULED_CHANGES_PER_SUBNET: u32 = 8;
+
+    /// The maximum number of queued changes applied, and of effective blocks read, per block.
+    pub const MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK: u32 = 16;
+
+    /// A hyperparameter change waiting for its effective block.
+    #[freeze_struct("8dc3c367e509e107")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ScheduledHyperparameterChange<AccountId, AdminCall> {
+        /// The subnet owner that queued the change, `None` for root.
+        pub scheduler: Option<AccountId>,
+        /// The subnet the change applies to, if any.
+        pub netuid: Option<u16>,
+        /// The admin call applied at the effective block.
+        pub call: AdminCall,
+    }
+
+    /// A queued hyperparameter change as returned by the runtime API.
+    #[freeze_struct("423297a5fb02192d")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ScheduledHyperparameterChangeInfo<AccountId> {
+        /// The id of the queued change.
+        pub id: Compact<u32>,
+        /// The block at which the change is applied.
+        pub effective_block: Compact<u64>,
+        /// The subnet owner that queued the change, `None` for root.
+        pub scheduler: Option<AccountId>,
+        /// The subnet the change applies to, if any.
+        pub netuid: Option<Compact<u16>>,
+        /// The SCALE encoded admin call.
+        pub call: Vec<u8>,
     }
     /// Enum for specifying the type of precompile operation.
     #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Copy)]
@@ -108,6 +176,12 @@ pub mod pallet {
         Metagraph,
         /// Enum for neuron precompile
         Neuron,
+        /// Enum for timelock precompile
+        Timelock,
+        /// Enum for randomness precompile
+        Randomness,
+        /// Enum for commitments precompile
+        Commitments,
     }
 
     #[pallet::type_value]
@@ -127,6 +201,46 @@ pub mod pallet {
         DefaultPrecompileEnabled<T>,
     >;
 
+    #[pallet::storage]
+    /// Minimum number of blocks a hyperparameter change waits in the queue.
+    /// Zero lets subnet owners change hyperparameters directly.
+    pub type HyperparameterTimelock<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    /// Id of the next queued hyperparameter change.
+    pub type NextScheduledChangeId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    /// DMAP ( effective_block, id ) --> queued hyperparameter change
+    pub type ScheduledChanges<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Twox64Concat,
+        u32,
+        ScheduledHyperparameterChange<T::AccountId, Call<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// MAP ( netuid ) --> number of changes queued by the subnet owner
+    pub type ScheduledChangesCount<T: Config> = StorageMap<_, Twox64Concat, u16, u32, ValueQuery>;
+
+    #[pallet::storage]
+    /// Set while a queued change is dispatched, so the call passes the timelock check.
+    pub type ApplyingScheduledChange<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::storage]
+    /// The earliest effective block with queued changes carried over to later blocks.
+    pub type CarriedOverChangesFrom<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            Self::apply_due_changes(block_number.saturated_into::<u64>())
+        }
+    }
+
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -183,7 +297,7 @@ pub mod pallet {
             netuid: u16,
             serving_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_serving_rate_limit(netuid, serving_rate_limit);
             log::debug!(
@@ -203,7 +317,7 @@ pub mod pallet {
             netuid: u16,
             min_difficulty: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -228,7 +342,7 @@ pub mod pallet {
             netuid: u16,
             max_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -253,7 +367,7 @@ pub mod pallet {
             netuid: u16,
             weights_version_key: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            Self::ensure_direct_change_allowed(origin.clone(), netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -301,7 +415,7 @@ pub mod pallet {
             netuid: u16,
             weights_set_rate_limit: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -329,7 +443,7 @@ pub mod pallet {
             netuid: u16,
             adjustment_interval: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -360,7 +474,7 @@ pub mod pallet {
             netuid: u16,
             adjustment_alpha: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -384,7 +498,7 @@ pub mod pallet {
             netuid: u16,
             max_weight_limit: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -409,7 +523,7 @@ pub mod pallet {
             netuid: u16,
             immunity_period: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -434,7 +548,7 @@ pub mod pallet {
             netuid: u16,
             min_allowed_weights: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -459,7 +573,7 @@ pub mod pallet {
             netuid: u16,
             max_allowed_uids: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -483,7 +597,7 @@ pub mod pallet {
         #[pallet::call_index(16)]
         #[pallet::weight(<T as Config>::WeightInfo::sudo_set_kappa())]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: u16, kappa: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -500,7 +614,7 @@ pub mod pallet {
         #[pallet::call_index(17)]
         #[pallet::weight(<T as Config>::WeightInfo::sudo_set_rho())]
         pub fn sudo_set_rho(origin: OriginFor<T>, netuid: u16, rho: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -521,7 +635,7 @@ pub mod pallet {
             netuid: u16,
             activity_cutoff: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -558,7 +672,7 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_network_registration_allowed(
                 netuid,
@@ -586,7 +700,7 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             pallet_subtensor::Pallet::<T>::set_network_pow_registration_allowed(
                 netuid,
@@ -609,7 +723,7 @@ pub mod pallet {
             netuid: u16,
             target_registrations_per_interval: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -637,7 +751,7 @@ pub mod pallet {
             netuid: u16,
             min_burn: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -662,7 +776,7 @@ pub mod pallet {
             netuid: u16,
             max_burn: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -687,7 +801,7 @@ pub mod pallet {
             netuid: u16,
             difficulty: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -711,7 +825,7 @@ pub mod pallet {
             netuid: u16,
             max_allowed_validators: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -744,7 +858,7 @@ pub mod pallet {
             netuid: u16,
             bonds_moving_average: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -769,7 +883,7 @@ pub mod pallet {
             netuid: u16,
             bonds_penalty: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -794,7 +908,7 @@ pub mod pallet {
             netuid: u16,
             max_registrations_per_block: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -861,7 +975,7 @@ pub mod pallet {
         #[pallet::call_index(30)]
         #[pallet::weight(<T as Config>::WeightInfo::sudo_set_tempo())]
         pub fn sudo_set_tempo(origin: OriginFor<T>, netuid: u16, tempo: u16) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -981,7 +1095,7 @@ pub mod pallet {
             netuid: u16,
             rao_recycled: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -1083,7 +1197,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1111,7 +1225,7 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
             pallet_subtensor::Pallet::<T>::set_liquid_alpha_enabled(netuid, enabled);
             log::debug!(
                 "LiquidAlphaEnableToggled( netuid: {:?}, Enabled: {:?} ) ",
@@ -1130,7 +1244,7 @@ pub mod pallet {
             alpha_low: u16,
             alpha_high: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            Self::ensure_direct_change_allowed(origin.clone(), netuid)?;
             pallet_subtensor::Pallet::<T>::do_set_alpha_values(
                 origin, netuid, alpha_low, alpha_high,
             )
@@ -1282,7 +1396,7 @@ pub mod pallet {
             netuid: u16,
             interval: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1363,7 +1477,7 @@ pub mod pallet {
             netuid: u16,
             toggle: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_direct_change_allowed(origin, netuid)?;
             pallet_subtensor::Pallet::<T>::toggle_transfer(netuid, toggle)
         }
 
@@ -1466,7 +1580,7 @@ pub mod pallet {
             netuid: u16,
             ema_halving: u64,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            Self::ensure_direct_root_change_allowed(origin)?;
             pallet_subtensor::EMAPriceHalvingBlocks::<T>::set(netuid, ema_halving);
 
             log::debug!(
@@ -1476,6 +1590,390 @@ pub mod pallet {
             );
             Ok(())
         }
+
+        /// Sets the voting window of subnet hyperparameter proposals.
+        ///
+        /// Owners of governed subnets propose hyperparameter changes that alpha holders can
+        /// veto until the window ends.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `voting_period` - Number of blocks alpha holders can vote on a proposal.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(66)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_proposal_voting_period(
+            origin: OriginFor<T>,
+            voting_period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::SubnetProposalVotingPeriod::<T>::set(voting_period);
+
+            log::debug!(
+                "SubnetProposalVotingPeriod( voting_period: {:?} )",
+                voting_period
+            );
+            Ok(())
+        }
+
+        /// Sets the hyperparameter change timelock.
+        ///
+        /// Queued changes wait at least this many blocks, or one tempo of their subnet if
+        /// that is longer. While the timelock is non-zero subnet owners and root can only
+        /// change subnet hyperparameters through `schedule_hyperparameter_change`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `blocks` - The minimum delay in blocks, zero disables the timelock.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(67)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_hyperparameter_timelock(
+            origin: OriginFor<T>,
+            blocks: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            HyperparameterTimelock::<T>::set(blocks);
+
+            log::debug!("HyperparameterTimelockSet( blocks: {:?} )", blocks);
+            Ok(())
+        }
+
+        /// Queues a hyperparameter change to be applied once the timelock has passed.
+        ///
+        /// Subnet owners can queue the changes they are allowed to make on their subnet,
+        /// root can queue any admin call. The change is applied in `on_initialize` of its
+        /// effective block, or of a later block when more than
+        /// MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK changes are due.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, the subnet owner or the root account.
+        /// * `call` - The admin call to apply.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the subnet owner or the root account.
+        /// * `CallCannotBeScheduled` - If the call is not a change the caller can queue.
+        /// * `TooManyScheduledChanges` - If the subnet owner has too many queued changes.
+        #[pallet::call_index(68)]
+        #[pallet::weight((
+            Weight::from_parts(20_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(5))
+                .saturating_add(T::DbWeight::get().writes(3)),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn schedule_hyperparameter_change(
+            origin: OriginFor<T>,
+            call: Box<Call<T>>,
+        ) -> DispatchResult {
+            let scheduler = match ensure_root(origin.clone()) {
+                Ok(()) => None,
+                Err(_) => Some(ensure_signed(origin.clone())?),
+            };
+
+            let (netuid, owner_allowed) = match Self::subnet_of_call(&call) {
+                Some((netuid, owner_allowed)) => (Some(netuid), owner_allowed),
+                None => (None, false),
+            };
+            ensure!(
+                !matches!(
+                    *call,
+                    Call::schedule_hyperparameter_change { .. }
+                        | Call::cancel_scheduled_hyperparameter_change { .. }
+                        | Call::sudo_apply_scheduled_hyperparameter_change { .. }
+                ),
+                Error::<T>::CallCannotBeScheduled
+            );
+
+            if scheduler.is_some() {
+                let netuid = netuid
+                    .filter(|_| owner_allowed)
+                    .ok_or(Error::<T>::CallCannotBeScheduled)?;
+                pallet_subtensor::Pallet::<T>::ensure_ungoverned_subnet_owner_or_root(
+                    origin, netuid,
+                )?;
+                ensure!(
+                    ScheduledChangesCount::<T>::get(netuid) < MAX_SCHEDULED_CHANGES_PER_SUBNET,
+                    Error::<T>::TooManyScheduledChanges
+                );
+                ScheduledChangesCount::<T>::mutate(netuid, |count| {
+                    *count = count.saturating_add(1)
+                });
+            }
+
+            let effective_block = pallet_subtensor::Pallet::<T>::get_current_block_as_u64()
+                .saturating_add(Self::timelock_delay(netuid));
+            let id = NextScheduledChangeId::<T>::get();
+            NextScheduledChangeId::<T>::set(id.wrapping_add(1));
+
+            ScheduledChanges::<T>::insert(
+                effective_block,
+                id,
+                ScheduledHyperparameterChange {
+                    scheduler: scheduler.clone(),
+                    netuid,
+                    call: *call,
+                },
+            );
+
+            Self::deposit_event(Event::HyperparameterChangeScheduled {
+                id,
+                scheduler,
+                netuid,
+                effective_block,
+            });
+            Ok(())
+        }
+
+        /// Cancels a queued hyperparameter change.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, the account that queued the change or root.
+        /// * `effective_block` - The block at which the change would be applied.
+        /// * `id` - The id of the queued change.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller did not queue the change and is not root.
+        /// * `ScheduledChangeNotFound` - If no such change is queued.
+        #[pallet::call_index(69)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn cancel_scheduled_hyperparameter_change(
+            origin: OriginFor<T>,
+            effective_block: u64,
+            id: u32,
+        ) -> DispatchResult {
+            let change = ScheduledChanges::<T>::get(effective_block, id)
+                .ok_or(Error::<T>::ScheduledChangeNotFound)?;
+            if ensure_root(origin.clone()).is_err() {
+                let who = ensure_signed(origin)?;
+                ensure!(
+                    change.scheduler.as_ref() == Some(&who),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            Self::take_scheduled_change(effective_block, id);
+            Self::deposit_event(Event::HyperparameterChangeCancelled { id });
+            Ok(())
+        }
+
+        /// Applies a queued hyperparameter change immediately.
+        ///
+        /// This is the emergency bypass of the timelock.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `effective_block` - The block at which the change would be applied.
+        /// * `id` - The id of the queued change.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `ScheduledChangeNotFound` - If no such change is queued.
+        #[pallet::call_index(70)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_apply_scheduled_hyperparameter_change(
+            origin: OriginFor<T>,
+            effective_block: u64,
+            id: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let change = Self::take_scheduled_change(effective_block, id)
+                .ok_or(Error::<T>::ScheduledChangeNotFound)?;
+
+            Self::apply_scheduled_change(id, change);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensures the origin may change a subnet hyperparameter right away.
+        ///
+        /// Subnet owners and root can only while the timelock is disabled, or when the call
+        /// is a queued change being applied. `sudo_apply_scheduled_hyperparameter_change` is
+        /// the emergency bypass of the timelock.
+        pub fn ensure_direct_change_allowed(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_ungoverned_subnet_owner_or_root(origin, netuid)?;
+            Self::ensure_timelock_passed()
+        }
+
+        /// Ensures the origin is root and may change a root-only subnet hyperparameter right
+        /// away, see `ensure_direct_change_allowed`.
+        pub fn ensure_direct_root_change_allowed(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_timelock_passed()
+        }
+
+        fn ensure_timelock_passed() -> DispatchResult {
+            ensure!(
+                HyperparameterTimelock::<T>::get() == 0 || ApplyingScheduledChange::<T>::get(),
+                Error::<T>::HyperparameterChangeNotScheduled
+            );
+            Ok(())
+        }
+
+        /// Returns the number of blocks a change on the given subnet waits in the queue.
+        pub fn timelock_delay(netuid: Option<u16>) -> u64 {
+            let tempo = netuid
+                .map(|netuid| u64::from(pallet_subtensor::Pallet::<T>::get_tempo(netuid)))
+                .unwrap_or_default();
+            HyperparameterTimelock::<T>::get().max(tempo).max(1)
+        }
+
+        /// Returns the subnet a call changes and whether the subnet owner may make it.
+        pub fn subnet_of_call(call: &Call<T>) -> Option<(u16, bool)> {
+            match *call {
+                Call::sudo_set_serving_rate_limit { netuid, .. }
+                | Call::sudo_set_max_difficulty { netuid, .. }
+                | Call::sudo_set_weights_version_key { netuid, .. }
+                | Call::sudo_set_adjustment_alpha { netuid, .. }
+                | Call::sudo_set_max_weight_limit { netuid, .. }
+                | Call::sudo_set_immunity_period { netuid, .. }
+                | Call::sudo_set_min_allowed_weights { netuid, .. }
+                | Call::sudo_set_kappa { netuid, .. }
+                | Call::sudo_set_rho { netuid, .. }
+                | Call::sudo_set_activity_cutoff { netuid, .. }
+                | Call::sudo_set_network_registration_allowed { netuid, .. }
+                | Call::sudo_set_network_pow_registration_allowed { netuid, .. }
+                | Call::sudo_set_max_burn { netuid, .. }
+                | Call::sudo_set_bonds_moving_average { netuid, .. }
+                | Call::sudo_set_bonds_penalty { netuid, .. }
+                | Call::sudo_set_commit_reveal_weights_enabled { netuid, .. }
+                | Call::sudo_set_liquid_alpha_enabled { netuid, .. }
+                | Call::sudo_set_alpha_values { netuid, .. }
+                | Call::sudo_set_commit_reveal_weights_interval { netuid, .. }
+                | Call::sudo_set_toggle_transfer { netuid, .. } => Some((netuid, true)),
+                Call::sudo_set_min_difficulty { netuid, .. }
+                | Call::sudo_set_weights_set_rate_limit { netuid, .. }
+                | Call::sudo_set_adjustment_interval { netuid, .. }
+                | Call::sudo_set_max_allowed_uids { netuid, .. }
+                | Call::sudo_set_target_registrations_per_interval { netuid, .. }
+                | Call::sudo_set_min_burn { netuid, .. }
+                | Call::sudo_set_difficulty { netuid, .. }
+                | Call::sudo_set_max_allowed_validators { netuid, .. }
+                | Call::sudo_set_max_registrations_per_block { netuid, .. }
+                | Call::sudo_set_tempo { netuid, .. }
+                | Call::sudo_set_rao_recycled { netuid, .. }
+                | Call::sudo_set_ema_price_halving_period { netuid, .. } => Some((netuid, false)),
+                _ => None,
+            }
+        }
+
+        /// Removes a queued change, releasing its slot in the subnet owner's queue.
+        fn take_scheduled_change(
+            effective_block: u64,
+            id: u32,
+        ) -> Option<ScheduledHyperparameterChange<T::AccountId, Call<T>>> {
+            let change = ScheduledChanges::<T>::take(effective_block, id)?;
+            if let (Some(_), Some(netuid)) = (&change.scheduler, change.netuid) {
+                ScheduledChangesCount::<T>::mutate(netuid, |count| {
+                    *count = count.saturating_sub(1)
+                });
+            }
+            Some(change)
+        }
+
+        /// Dispatches a queued change with the origin that queued it, so a subnet owner's
+        /// change goes through the same owner checks and rate limits as a direct call.
+        fn apply_scheduled_change(
+            id: u32,
+            change: ScheduledHyperparameterChange<T::AccountId, Call<T>>,
+        ) -> Weight {
+            let info = change.call.get_dispatch_info();
+            let origin: OriginFor<T> = match change.scheduler {
+                Some(who) => RawOrigin::Signed(who).into(),
+                None => RawOrigin::Root.into(),
+            };
+
+            ApplyingScheduledChange::<T>::put(true);
+            let result = change.call.dispatch_bypass_filter(origin);
+            ApplyingScheduledChange::<T>::kill();
+
+            let weight =
+                extract_actual_weight(&result, &info).saturating_add(T::DbWeight::get().writes(2));
+            let result = result.map(|_| ()).map_err(|e| e.error);
+            Self::deposit_event(Event::HyperparameterChangeApplied { id, result });
+            weight
+        }
+
+        /// Applies the changes queued up to the given block, and returns the weight used.
+        ///
+        /// At most MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK changes are applied and as many
+        /// effective blocks read. The rest is carried over to the next block, starting from
+        /// the earliest effective block with changes left.
+        pub fn apply_due_changes(block_number: u64) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight = db_weight.reads_writes(1, 1);
+            let mut effective_block = CarriedOverChangesFrom::<T>::take()
+                .unwrap_or(block_number)
+                .min(block_number);
+            let mut applied: u32 = 0;
+            let mut blocks_read: u32 = 0;
+
+            loop {
+                let due: Vec<u32> = ScheduledChanges::<T>::iter_key_prefix(effective_block)
+                    .take(MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK.saturating_sub(applied) as usize)
+                    .collect();
+                blocks_read = blocks_read.saturating_add(1);
+                weight = weight.saturating_add(db_weight.reads(1));
+
+                for id in due {
+                    if let Some(change) = Self::take_scheduled_change(effective_block, id) {
+                        applied = applied.saturating_add(1);
+                        weight = weight
+                            .saturating_add(Self::apply_scheduled_change(id, change))
+                            .saturating_add(db_weight.reads_writes(2, 2));
+                    }
+                }
+
+                if applied >= MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK
+                    || blocks_read >= MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK
+                {
+                    weight = weight.saturating_add(db_weight.reads(1));
+                    if effective_block < block_number
+                        || ScheduledChanges::<T>::iter_key_prefix(effective_block)
+                            .next()
+                            .is_some()
+                    {
+                        CarriedOverChangesFrom::<T>::put(effective_block);
+                    }
+                    break;
+                }
+                if effective_block >= block_number {
+                    break;
+                }
+                effective_block = effective_block.saturating_add(1);
+            }
+            weight
+        }
+
+        /// Returns the queued hyperparameter changes ordered by effective block.
+        pub fn get_scheduled_hyperparameter_changes()
+        -> Vec<ScheduledHyperparameterChangeInfo<T::AccountId>> {
+            let mut changes: Vec<ScheduledHyperparameterChangeInfo<T::AccountId>> =
+                ScheduledChanges::<T>::iter()
+                    .map(
+                        |(effective_block, id, change)| ScheduledHyperparameterChangeInfo {
+                            id: id.into(),
+                            effective_block: effective_block.into(),
+                            scheduler: change.scheduler,
+                            netuid: change.netuid.map(Into::into),
+                            call: change.call.encode(),
+                        },
+                    )
+                    .collect();
+            changes.sort_by_key(|change| (change.effective_block.0, change.id.0));
+            changes
+        }
     }
 }
 
@@ -136,6 +136,7 @@ parameter_types! {
     pub const InitialTaoWeight: u64 = u64::MAX/10; // 10% global weight.
     pub const InitialEmaPriceHalvingPeriod: u64 = 201_600_u64; // 4 weeks
     pub const DurationOfStartCall: u64 = 7 * 24 * 60 * 60 / 12; // 7 days
+    pub const MaxTwapWindowBlocks: u64 = 7200; // 1 day
 }
 
 impl pallet_subtensor::Config for Test {
@@ -147,6 +148,8 @@ impl pallet_subtensor::Config for Test {
     type CouncilOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
+    type OnNeuronRemoved = ();
+    type IdentityInterface = ();
     type Scheduler = Scheduler;
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -201,6 +204,7 @@ impl pallet_subtensor::Config for Test {
     type InitialTaoWeight = InitialTaoWeight;
     type InitialEmaPriceHalvingPeriod = InitialEmaPriceHalvingPeriod;
     type DurationOfStartCall = DurationOfStartCall;
+    type MaxTwapWindowBlocks = MaxTwapWindowBlocks;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -316,6 +320,17 @@ impl pallet_drand::Config for Test {
     type Verifier = pallet_drand::verifier::QuicknetVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type DefaultRetentionRounds = ConstU64<201_600>;
+    type MaxPrunedPulsesPerBlock = ConstU32<100>;
+    type MaxReferenceAge = ConstU64<403_200>;
+    type OnTimelockRevealed = ();
+    type MaxTimelocksPerRound = ConstU32<64>;
+    type MaxTimelockRevealsPerBlock = ConstU32<16>;
+    type MaxTimelocksPerAccount = ConstU32<32>;
+    type MaxPendingTimelocks = ConstU32<1_024>;
+    type RevealedTimelockLifetime = ConstU64<50_400>;
+    type Currency = Balances;
+    type TimelockDeposit = ConstU64<0>;
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -397,6 +412,7 @@ pub(crate) fn run_to_block(n: u64) {
         System::set_block_number(System::block_number() + 1);
         System::on_initialize(System::block_number());
         SubtensorModule::on_initialize(System::block_number());
+        AdminUtils::on_initialize(System::block_number());
     }
 }
 
@@ -13,7 +13,11 @@ use sp_core::{Pair, U256, ed25519};
 use substrate_fixed::types::I96F32;
 
 use crate::Error;
-use crate::pallet::PrecompileEnable;
+use crate::pallet::{
+    ApplyingScheduledChange, CarriedOverChangesFrom, HyperparameterTimelock,
+    MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK, NextScheduledChangeId, PrecompileEnable,
+    ScheduledChangesCount,
+};
 use mock::*;
 
 mod mock;
@@ -610,6 +614,439 @@ fn test_sudo_set_kappa() {
     });
 }
 
+#[test]
+fn test_sudo_set_kappa_on_governed_subnet() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+        pallet_subtensor::SubnetGovernanceEnabled::<Test>::insert(netuid, true);
+        let init_value: u16 = SubtensorModule::get_kappa(netuid);
+
+        // The owner of a governed subnet must propose the change instead.
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                10
+            ),
+            SubtensorError::<Test>::SubnetGovernanceActive
+        );
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 10);
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_proposal_voting_period() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_proposal_voting_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_proposal_voting_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(
+            pallet_subtensor::SubnetProposalVotingPeriod::<Test>::get(),
+            100
+        );
+    });
+}
+
+#[test]
+fn test_hyperparameter_timelock_blocks_direct_owner_changes() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_timelock(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                100
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_timelock(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(HyperparameterTimelock::<Test>::get(), 100);
+
+        // The owner must queue the change while the timelock is enabled.
+        assert_noop!(
+            AdminUtils::sudo_set_max_weight_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                10
+            ),
+            Error::<Test>::HyperparameterChangeNotScheduled
+        );
+
+        // Root must queue its changes as well, and can apply them right away in an emergency.
+        assert_noop!(
+            AdminUtils::sudo_set_max_weight_limit(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                10
+            ),
+            Error::<Test>::HyperparameterChangeNotScheduled
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::root(), netuid, 20),
+            Error::<Test>::HyperparameterChangeNotScheduled
+        );
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Box::new(crate::Call::sudo_set_max_weight_limit {
+                netuid,
+                max_weight_limit: 10
+            })
+        ));
+        assert_ok!(AdminUtils::sudo_apply_scheduled_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            System::block_number() + 100,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), 10);
+    });
+}
+
+#[test]
+fn test_scheduled_changes_over_the_block_limit_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        HyperparameterTimelock::<Test>::set(5);
+
+        let queued = MAX_SCHEDULED_CHANGES_APPLIED_PER_BLOCK + 4;
+        let effective_block = System::block_number() + 10;
+        for kappa in 0..queued {
+            assert_ok!(AdminUtils::schedule_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                Box::new(crate::Call::sudo_set_kappa {
+                    netuid,
+                    kappa: kappa as u16
+                })
+            ));
+        }
+
+        // Only part of the due changes is applied in their effective block.
+        run_to_block(effective_block);
+        assert_eq!(AdminUtils::get_scheduled_hyperparameter_changes().len(), 4);
+        assert_eq!(CarriedOverChangesFrom::<Test>::get(), Some(effective_block));
+
+        // The rest is applied in the next block.
+        run_to_block(effective_block + 1);
+        assert!(AdminUtils::get_scheduled_hyperparameter_changes().is_empty());
+        assert_eq!(CarriedOverChangesFrom::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn test_scheduled_hyperparameter_change_applies_after_timelock() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+        HyperparameterTimelock::<Test>::set(5);
+        let init_value: u16 = SubtensorModule::get_max_weight_limit(netuid);
+
+        // Owners can only queue the changes they are allowed to make.
+        assert_noop!(
+            AdminUtils::schedule_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                Box::new(crate::Call::sudo_set_tempo { netuid, tempo: 1 })
+            ),
+            Error::<Test>::CallCannotBeScheduled
+        );
+        assert_noop!(
+            AdminUtils::schedule_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                Box::new(crate::Call::sudo_set_max_weight_limit {
+                    netuid,
+                    max_weight_limit: 123
+                })
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // The change waits one tempo since it is longer than the timelock.
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            Box::new(crate::Call::sudo_set_max_weight_limit {
+                netuid,
+                max_weight_limit: 123
+            })
+        ));
+        let effective_block = System::block_number() + 10;
+        System::assert_last_event(
+            crate::Event::<Test>::HyperparameterChangeScheduled {
+                id: 0,
+                scheduler: Some(sn_owner),
+                netuid: Some(netuid),
+                effective_block,
+            }
+            .into(),
+        );
+
+        let queued = AdminUtils::get_scheduled_hyperparameter_changes();
+        assert_eq!(queued.len(), 1);
+        let change = queued.first().expect("change is queued");
+        assert_eq!(change.effective_block.0, effective_block);
+        assert_eq!(change.netuid, Some(netuid.into()));
+
+        run_to_block(effective_block - 1);
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), init_value);
+
+        run_to_block(effective_block);
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), 123);
+        assert!(AdminUtils::get_scheduled_hyperparameter_changes().is_empty());
+        assert_eq!(ScheduledChangesCount::<Test>::get(netuid), 0);
+        System::assert_has_event(
+            crate::Event::<Test>::HyperparameterChangeApplied {
+                id: 0,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_scheduled_owner_changes_take_effect() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+        HyperparameterTimelock::<Test>::set(5);
+
+        // Alpha values need liquid alpha, so they go in a second batch after it is enabled.
+        let batches = vec![
+            vec![
+                crate::Call::sudo_set_serving_rate_limit {
+                    netuid,
+                    serving_rate_limit: 11,
+                },
+                crate::Call::sudo_set_max_difficulty {
+                    netuid,
+                    max_difficulty: 12,
+                },
+                crate::Call::sudo_set_weights_version_key {
+                    netuid,
+                    weights_version_key: 13,
+                },
+                crate::Call::sudo_set_adjustment_alpha {
+                    netuid,
+                    adjustment_alpha: 14,
+                },
+                crate::Call::sudo_set_max_weight_limit {
+                    netuid,
+                    max_weight_limit: 15,
+                },
+                crate::Call::sudo_set_immunity_period {
+                    netuid,
+                    immunity_period: 16,
+                },
+                crate::Call::sudo_set_min_allowed_weights {
+                    netuid,
+                    min_allowed_weights: 17,
+                },
+                crate::Call::sudo_set_kappa { netuid, kappa: 18 },
+            ],
+            vec![
+                crate::Call::sudo_set_rho { netuid, rho: 19 },
+                crate::Call::sudo_set_activity_cutoff {
+                    netuid,
+                    activity_cutoff: 400,
+                },
+                crate::Call::sudo_set_network_registration_allowed {
+                    netuid,
+                    registration_allowed: false,
+                },
+                crate::Call::sudo_set_network_pow_registration_allowed {
+                    netuid,
+                    registration_allowed: false,
+                },
+                crate::Call::sudo_set_max_burn {
+                    netuid,
+                    max_burn: 21,
+                },
+                crate::Call::sudo_set_bonds_moving_average {
+                    netuid,
+                    bonds_moving_average: 22,
+                },
+                crate::Call::sudo_set_bonds_penalty {
+                    netuid,
+                    bonds_penalty: 23,
+                },
+                crate::Call::sudo_set_commit_reveal_weights_enabled {
+                    netuid,
+                    enabled: true,
+                },
+            ],
+            vec![
+                crate::Call::sudo_set_liquid_alpha_enabled {
+                    netuid,
+                    enabled: true,
+                },
+                crate::Call::sudo_set_commit_reveal_weights_interval {
+                    netuid,
+                    interval: 3,
+                },
+                crate::Call::sudo_set_toggle_transfer {
+                    netuid,
+                    toggle: false,
+                },
+            ],
+            vec![crate::Call::sudo_set_alpha_values {
+                netuid,
+                alpha_low: 12_000,
+                alpha_high: 60_000,
+            }],
+        ];
+
+        let mut next_id = 0;
+        for batch in batches {
+            let effective_block = System::block_number() + 10;
+            for call in batch {
+                assert_ok!(AdminUtils::schedule_hyperparameter_change(
+                    <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                    Box::new(call)
+                ));
+            }
+
+            run_to_block(effective_block);
+            for id in next_id..NextScheduledChangeId::<Test>::get() {
+                System::assert_has_event(
+                    crate::Event::<Test>::HyperparameterChangeApplied { id, result: Ok(()) }.into(),
+                );
+            }
+            next_id = NextScheduledChangeId::<Test>::get();
+            assert!(AdminUtils::get_scheduled_hyperparameter_changes().is_empty());
+        }
+
+        assert_eq!(SubtensorModule::get_serving_rate_limit(netuid), 11);
+        assert_eq!(SubtensorModule::get_max_difficulty(netuid), 12);
+        assert_eq!(SubtensorModule::get_weights_version_key(netuid), 13);
+        assert_eq!(SubtensorModule::get_adjustment_alpha(netuid), 14);
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), 15);
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 16);
+        assert_eq!(SubtensorModule::get_min_allowed_weights(netuid), 17);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 18);
+        assert_eq!(SubtensorModule::get_rho(netuid), 19);
+        assert_eq!(SubtensorModule::get_activity_cutoff(netuid), 400);
+        assert!(!SubtensorModule::get_network_registration_allowed(netuid));
+        assert!(!SubtensorModule::get_network_pow_registration_allowed(
+            netuid
+        ));
+        assert_eq!(SubtensorModule::get_max_burn_as_u64(netuid), 21);
+        assert_eq!(SubtensorModule::get_bonds_moving_average(netuid), 22);
+        assert_eq!(SubtensorModule::get_bonds_penalty(netuid), 23);
+        assert!(SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert!(SubtensorModule::get_liquid_alpha_enabled(netuid));
+        assert_eq!(SubtensorModule::get_reveal_period(netuid), 3);
+        assert!(!pallet_subtensor::TransferToggle::<Test>::get(netuid));
+        assert_eq!(SubtensorModule::get_alpha_values(netuid), (12_000, 60_000));
+
+        // Queued owner changes go through the owner's rate limits.
+        WeightsVersionKeyRateLimit::<Test>::set(5);
+        let effective_block = System::block_number() + 10;
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            Box::new(crate::Call::sudo_set_weights_version_key {
+                netuid,
+                weights_version_key: 14,
+            })
+        ));
+        run_to_block(effective_block);
+        System::assert_has_event(
+            crate::Event::<Test>::HyperparameterChangeApplied {
+                id: next_id,
+                result: Err(SubtensorError::<Test>::TxRateLimitExceeded.into()),
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_weights_version_key(netuid), 13);
+        assert!(!ApplyingScheduledChange::<Test>::get());
+    });
+}
+
+#[test]
+fn test_cancel_and_force_apply_scheduled_hyperparameter_change() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+        HyperparameterTimelock::<Test>::set(100);
+        let effective_block = System::block_number() + 100;
+
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            Box::new(crate::Call::sudo_set_kappa { netuid, kappa: 123 })
+        ));
+        assert_noop!(
+            AdminUtils::cancel_scheduled_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                effective_block,
+                0
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::cancel_scheduled_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            effective_block,
+            0
+        ));
+        assert!(AdminUtils::get_scheduled_hyperparameter_changes().is_empty());
+        assert_noop!(
+            AdminUtils::cancel_scheduled_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                effective_block,
+                0
+            ),
+            Error::<Test>::ScheduledChangeNotFound
+        );
+
+        // Root can apply a queued change right away in an emergency.
+        assert_ok!(AdminUtils::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Box::new(crate::Call::sudo_set_tempo { netuid, tempo: 20 })
+        ));
+        assert_noop!(
+            AdminUtils::sudo_apply_scheduled_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                effective_block,
+                1
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::sudo_apply_scheduled_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            effective_block,
+            1
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 20);
+        assert!(AdminUtils::get_scheduled_hyperparameter_changes().is_empty());
+    });
+}
+
 #[test]
 fn test_sudo_set_rho() {
     new_test_ext().execute_with(|| {
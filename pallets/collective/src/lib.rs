@@ -166,6 +166,44 @@ pub struct Votes<AccountId, BlockNumber> {
     end: BlockNumber,
 }
 
+/// Stake-weighted tally of a motion.
+#[freeze_struct("ab80cd12deb33944")]
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct WeightedTally {
+    /// The summed vote weight of the members that approved the motion.
+    pub ayes: u64,
+    /// The summed vote weight of the members that rejected the motion.
+    pub nays: u64,
+    /// The total vote weight of the voting members.
+    pub total: u64,
+    /// The number of member votes the motion was proposed with as its threshold.
+    pub threshold: MemberCount,
+    /// The number of voting members the threshold is a share of.
+    pub seats: MemberCount,
+}
+
+impl WeightedTally {
+    /// The approving vote weight reaches the `threshold / seats` share of the total vote
+    /// weight.
+    pub fn is_approved(&self) -> bool {
+        self.total > 0
+            && u128::from(self.ayes).saturating_mul(u128::from(self.seats))
+                >= self.required_weight()
+    }
+
+    /// The motion cannot reach approval even if all remaining vote weight approves it.
+    pub fn is_disapproved(&self) -> bool {
+        u128::from(self.total.saturating_sub(self.nays)).saturating_mul(u128::from(self.seats))
+            < self.required_weight()
+    }
+
+    /// The total vote weight scaled by the threshold, to compare against the scaled ayes
+    /// without dividing.
+    fn required_weight(&self) -> u128 {
+        u128::from(self.threshold).saturating_mul(u128::from(self.total))
+    }
+}
+
 #[deny(missing_docs)]
 #[frame_support::pallet]
 pub mod pallet {
@@ -227,6 +265,12 @@ pub mod pallet {
 
         /// Members to expect in a vote
         type GetVotingMembers: GetVotingMembers<MemberCount>;
+
+        /// Weight of member votes, `()` counts one vote per member
+        type VoteWeight: GetVoteWeight<Self::AccountId>;
+
+        /// Weight budget for closing expired motions in `on_initialize`, zero disables auto-close
+        type MaxAutoCloseWeight: Get<Weight>;
     }
 
     #[pallet::genesis_config]
@@ -299,6 +343,26 @@ pub mod pallet {
     #[pallet::getter(fn prime)]
     pub type Prime<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
 
+    /// The total vote weight of the voting members, snapshotted when a weighted motion was
+    /// proposed. Motions without an entry are tallied one vote per member.
+    #[pallet::storage]
+    #[pallet::getter(fn total_vote_weight)]
+    pub type TotalVoteWeight<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, T::Hash, u64, OptionQuery>;
+
+    /// The weight each member voted with on a weighted motion, recorded at vote time.
+    #[pallet::storage]
+    #[pallet::getter(fn vote_weight_of)]
+    pub type VoteWeightOf<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Identity, T::Hash, Twox64Concat, T::AccountId, u64, OptionQuery>;
+
+    /// The number of failed attempts to auto-close an expired motion, and the block from which
+    /// it is attempted again.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_close_failures)]
+    pub type AutoCloseFailures<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, T::Hash, (u32, BlockNumberFor<T>), OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -361,6 +425,18 @@ pub mod pallet {
             /// Whether the proposal was rejected.
             no: MemberCount,
         },
+        /// An expired motion was closed automatically in `on_initialize`.
+        AutoClosed {
+            /// The hash of the proposal.
+            proposal_hash: T::Hash,
+        },
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::auto_close_expired_motions(n)
+        }
     }
 
     #[pallet::error]
@@ -622,7 +698,8 @@ pub mod pallet {
 
         /// Close a vote that is either approved, disapproved or whose voting period has ended.
         ///
-        /// May be called by any signed account in order to finish voting and close the proposal.
+        /// May be called by any signed account or Root in order to finish voting and close the
+        /// proposal.
         ///
         /// If called before the end of the voting period it will only close the vote if it is
         /// has enough votes to be approved or disapproved.
@@ -630,8 +707,7 @@ pub mod pallet {
         /// If called after the end of the voting period abstentions are counted as rejections
         /// unless there is a prime member set and the prime member cast an approval.
         ///
-        /// If the close operation completes successfully with disapproval, the transaction fee will
-        /// be waived. Otherwise execution of the approved operation will be charged to the caller.
+        /// If the close operation completes successfully, the transaction fee will be waived.
         ///
         /// + `proposal_weight_bound`: The maximum amount of weight consumed by executing the closed
         /// proposal.
@@ -666,7 +742,7 @@ pub mod pallet {
             proposal_weight_bound: Weight,
             #[pallet::compact] length_bound: u32,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            ensure_signed_or_root(origin)?;
 
             Self::do_close(proposal_hash, index, proposal_weight_bound, length_bound)
         }
@@ -740,6 +816,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             }
         };
         <Voting<T, I>>::insert(proposal_hash, votes);
+        if T::VoteWeight::is_weighted() {
+            TotalVoteWeight::<T, I>::insert(proposal_hash, T::VoteWeight::total_weight());
+        }
 
         Self::deposit_event(Event::Proposed {
             account: who,
@@ -790,6 +869,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             }
         }
 
+        if TotalVoteWeight::<T, I>::contains_key(proposal) {
+            VoteWeightOf::<T, I>::insert(proposal, &who, T::VoteWeight::vote_weight(&who));
+        }
+
         let yes_votes = voting.ayes.len() as MemberCount;
         let no_votes = voting.nays.len() as MemberCount;
         Self::deposit_event(Event::Voted {
@@ -821,8 +904,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         let mut no_votes = voting.nays.len() as MemberCount;
         let mut yes_votes = voting.ayes.len() as MemberCount;
         let seats = T::GetVotingMembers::get_count() as MemberCount;
-        let approved = yes_votes >= voting.threshold;
-        let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+        let tally = Self::tally_weighted_votes(&proposal_hash, &voting, seats);
+        // A weighted motion needs the member threshold as well, so that it is dispatched with an
+        // origin backed by as many members as an unweighted one.
+        let approved =
+            yes_votes >= voting.threshold && tally.as_ref().is_none_or(WeightedTally::is_approved);
+        let disapproved = seats.saturating_sub(no_votes) < voting.threshold
+            || tally.as_ref().is_some_and(WeightedTally::is_disapproved);
         // Allow (dis-)approving the proposal as soon as there are enough votes.
         if approved {
             let (proposal, len) = Self::validate_and_get_proposal(
@@ -842,7 +930,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                     T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
                         .saturating_add(proposal_weight),
                 ),
-                Pays::Yes,
+                Pays::No,
             )
                 .into());
         } else if disapproved {
@@ -878,7 +966,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             true => yes_votes = yes_votes.saturating_add(abstentions),
             false => no_votes = no_votes.saturating_add(abstentions),
         }
-        let approved = yes_votes >= voting.threshold;
+        // Abstained vote weight follows the default vote as well.
+        let tally = tally.map(|mut tally| {
+            match default {
+                true => tally.ayes = tally.total.saturating_sub(tally.nays),
+                false => tally.nays = tally.total.saturating_sub(tally.ayes),
+            }
+            tally
+        });
+        let approved =
+            yes_votes >= voting.threshold && tally.as_ref().is_none_or(WeightedTally::is_approved);
 
         if approved {
             let (proposal, len) = Self::validate_and_get_proposal(
@@ -898,7 +995,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                     T::WeightInfo::close_approved(len as u32, seats, proposal_count)
                         .saturating_add(proposal_weight),
                 ),
-                Pays::Yes,
+                Pays::No,
             )
                 .into())
         } else {
@@ -916,6 +1013,111 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         }
     }
 
+    /// Close motions whose voting period has ended, as long as the worst-case weight of each
+    /// close fits in what is left of `MaxAutoCloseWeight`. Returns the weight consumed.
+    ///
+    /// A motion that does not fit is skipped for the block, and the later ones are still
+    /// attempted. A motion that fails to close is attempted again after `2^failures` blocks.
+    pub fn auto_close_expired_motions(now: BlockNumberFor<T>) -> Weight {
+        let budget = T::MaxAutoCloseWeight::get();
+        if budget == Weight::zero() {
+            return Weight::zero();
+        }
+
+        let db_weight = T::DbWeight::get();
+        let mut weight = db_weight.reads(1);
+        for proposal_hash in Self::proposals().into_iter() {
+            // Every motion looked at is charged, whether or not it is closed.
+            if weight.saturating_add(db_weight.reads(3)).any_gt(budget) {
+                break;
+            }
+            weight = weight.saturating_add(db_weight.reads(2));
+            let Some(voting) = Self::voting(proposal_hash) else {
+                continue;
+            };
+            if voting.end > now {
+                continue;
+            }
+            let failures = match Self::auto_close_failures(proposal_hash) {
+                Some((_, retry_at)) if retry_at > now => continue,
+                Some((failures, _)) => failures,
+                None => 0,
+            };
+            weight = weight.saturating_add(db_weight.reads(1));
+            let Some(proposal) = Self::proposal_of(proposal_hash) else {
+                continue;
+            };
+
+            let proposal_weight = proposal.get_dispatch_info().weight;
+            let len = proposal.encoded_size() as u32;
+            let m = T::MaxMembers::get();
+            let p = T::MaxProposals::get();
+            let worst_case = T::WeightInfo::close_approved(len, m, p)
+                .max(T::WeightInfo::close_disapproved(m, p))
+                .saturating_add(proposal_weight);
+            if weight.saturating_add(worst_case).any_gt(budget) {
+                continue;
+            }
+
+            match Self::do_close(proposal_hash, voting.index, proposal_weight, u32::MAX) {
+                Ok(post_info) => {
+                    weight = weight.saturating_add(post_info.actual_weight.unwrap_or(worst_case));
+                    Self::deposit_event(Event::AutoClosed { proposal_hash });
+                }
+                Err(err) => {
+                    let failures = failures.saturating_add(1);
+                    let retry_at = now.saturating_add(2u32.saturating_pow(failures.min(31)).into());
+                    AutoCloseFailures::<T, I>::insert(proposal_hash, (failures, retry_at));
+                    weight = weight
+                        .saturating_add(worst_case)
+                        .saturating_add(db_weight.writes(1));
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Failed to auto-close motion {:?}: {:?}, retrying at {:?}",
+                        proposal_hash,
+                        err.error,
+                        retry_at,
+                    );
+                }
+            }
+        }
+        weight
+    }
+
+    /// Return the stake-weighted tally of a motion, or `None` if the motion does not exist or is
+    /// tallied one vote per member.
+    pub fn weighted_tally(proposal_hash: T::Hash) -> Option<WeightedTally> {
+        let voting = Self::voting(proposal_hash)?;
+        let seats = T::GetVotingMembers::get_count() as MemberCount;
+        Self::tally_weighted_votes(&proposal_hash, &voting, seats)
+    }
+
+    /// Sum the recorded vote weights of the current ayes and nays of a weighted motion.
+    ///
+    /// The total never falls below the weight that has voted, since member weights are recorded
+    /// at vote time and may have grown since the proposal snapshot.
+    fn tally_weighted_votes(
+        proposal_hash: &T::Hash,
+        voting: &Votes<T::AccountId, BlockNumberFor<T>>,
+        seats: MemberCount,
+    ) -> Option<WeightedTally> {
+        let snapshot = TotalVoteWeight::<T, I>::get(proposal_hash)?;
+        let sum = |voters: &[T::AccountId]| {
+            voters.iter().fold(0u64, |acc, who| {
+                acc.saturating_add(VoteWeightOf::<T, I>::get(proposal_hash, who).unwrap_or(0))
+            })
+        };
+        let ayes = sum(&voting.ayes);
+        let nays = sum(&voting.nays);
+        Some(WeightedTally {
+            ayes,
+            nays,
+            total: snapshot.max(ayes.saturating_add(nays)),
+            threshold: voting.threshold,
+            seats,
+        })
+    }
+
     /// Ensure that the right proposal bounds were passed and get the proposal from storage.
     ///
     /// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -990,6 +1192,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         // remove proposal and vote
         ProposalOf::<T, I>::remove(proposal_hash);
         Voting::<T, I>::remove(proposal_hash);
+        TotalVoteWeight::<T, I>::remove(proposal_hash);
+        AutoCloseFailures::<T, I>::remove(proposal_hash);
+        let _ = VoteWeightOf::<T, I>::clear_prefix(proposal_hash, u32::MAX, None);
         let num_proposals = Proposals::<T, I>::mutate(|proposals| {
             proposals.retain(|h| h != &proposal_hash);
             proposals.len().saturating_add(1) // calculate weight based on original length
@@ -1234,3 +1439,27 @@ impl GetVotingMembers<MemberCount> for () {
         0
     }
 }
+
+/// GetVoteWeight
+pub trait GetVoteWeight<AccountId> {
+    /// Check whether new motions are tallied by vote weight instead of one vote per member
+    fn is_weighted() -> bool;
+    /// Get the weight of the passed AccountId's vote
+    fn vote_weight(account: &AccountId) -> u64;
+    /// Get the total vote weight of the voting members
+    fn total_weight() -> u64;
+}
+
+impl<T> GetVoteWeight<T> for () {
+    fn is_weighted() -> bool {
+        false
+    }
+
+    fn vote_weight(_: &T) -> u64 {
+        0
+    }
+
+    fn total_weight() -> u64 {
+        0
+    }
+}
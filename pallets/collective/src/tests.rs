@@ -83,6 +83,8 @@ pub type MaxMembers = ConstU32<100>;
 parameter_types! {
     pub const MotionDuration: u64 = 3;
     pub const MaxProposals: u32 = 257;
+    pub const AutoCloseWeight: Weight = Weight::MAX;
+    pub const TinyAutoCloseWeight: Weight = Weight::from_parts(1, 1);
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -151,6 +153,8 @@ impl Config<Instance1> for Test {
     type CanPropose = CanProposeCollective;
     type CanVote = CanVoteCollective;
     type GetVotingMembers = GetCollectiveCount;
+    type VoteWeight = ();
+    type MaxAutoCloseWeight = AutoCloseWeight;
 }
 
 pub struct CanProposeCollectiveMajority;
@@ -192,6 +196,8 @@ impl Config<Instance2> for Test {
     type CanPropose = CanProposeCollectiveMajority;
     type CanVote = CanVoteCollectiveMajority;
     type GetVotingMembers = GetCollectiveMajorityCount;
+    type VoteWeight = ();
+    type MaxAutoCloseWeight = TinyAutoCloseWeight;
 }
 impl mock_democracy::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -224,6 +230,21 @@ impl Get<MemberCount> for GetDefaultCollectiveCount {
     }
 }
 
+pub struct StakeWeightDefaultCollective;
+impl GetVoteWeight<<Test as frame_system::Config>::AccountId> for StakeWeightDefaultCollective {
+    fn is_weighted() -> bool {
+        true
+    }
+    fn vote_weight(who: &<Test as frame_system::Config>::AccountId) -> u64 {
+        who.saturating_mul(10)
+    }
+    fn total_weight() -> u64 {
+        DefaultCollective::members()
+            .iter()
+            .fold(0, |acc, who| acc.saturating_add(Self::vote_weight(who)))
+    }
+}
+
 impl Config for Test {
     type RuntimeOrigin = RuntimeOrigin;
     type Proposal = RuntimeCall;
@@ -237,6 +258,8 @@ impl Config for Test {
     type CanPropose = CanProposeDefaultCollective;
     type CanVote = CanVoteDefaultCollective;
     type GetVotingMembers = GetDefaultCollectiveCount;
+    type VoteWeight = StakeWeightDefaultCollective;
+    type MaxAutoCloseWeight = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1576,6 +1599,519 @@ fn disapprove_proposal_works() {
     })
 }
 
+#[test]
+fn weighted_motion_approves_with_stake_majority() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultCollective::set_members(
+            RuntimeOrigin::root(),
+            vec![1, 2, 5],
+            None,
+            0
+        ));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(DefaultCollective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_eq!(DefaultCollective::total_vote_weight(hash), Some(80));
+
+        // Two members approving hold less than the two thirds of the stake the threshold asks.
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(1),
+            hash,
+            0,
+            true
+        ));
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            true
+        ));
+        assert_eq!(
+            DefaultCollective::weighted_tally(hash),
+            Some(WeightedTally {
+                ayes: 30,
+                nays: 0,
+                total: 80,
+                threshold: 2,
+                seats: 3
+            })
+        );
+        assert_noop!(
+            DefaultCollective::close(
+                RuntimeOrigin::root(),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            Error::<Test>::TooEarlyToCloseProposal
+        );
+
+        // Member 5 holds enough stake to approve the motion with the threshold of members.
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(1),
+            hash,
+            0,
+            false
+        ));
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(5),
+            hash,
+            0,
+            true
+        ));
+        assert_eq!(
+            DefaultCollective::weighted_tally(hash),
+            Some(WeightedTally {
+                ayes: 70,
+                nays: 10,
+                total: 80,
+                threshold: 2,
+                seats: 3
+            })
+        );
+        assert_ok!(DefaultCollective::close(
+            RuntimeOrigin::root(),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        assert!(
+            System::events().contains(&record(RuntimeEvent::DefaultCollective(
+                CollectiveEvent::Approved {
+                    proposal_hash: hash
+                }
+            )))
+        );
+
+        // The weighted tally is cleaned up with the motion.
+        assert_eq!(DefaultCollective::weighted_tally(hash), None);
+        assert_eq!(DefaultCollective::total_vote_weight(hash), None);
+        assert_eq!(DefaultCollective::vote_weight_of(hash, 5), None);
+    });
+}
+
+#[test]
+fn weighted_motion_disapproves_with_stake_majority() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultCollective::set_members(
+            RuntimeOrigin::root(),
+            vec![1, 2, 5],
+            None,
+            0
+        ));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(DefaultCollective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+
+        // A member majority approves, but the stake majority rejects.
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(1),
+            hash,
+            0,
+            true
+        ));
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            true
+        ));
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(5),
+            hash,
+            0,
+            false
+        ));
+        assert_ok!(DefaultCollective::close(
+            RuntimeOrigin::root(),
+            hash,
+            0,
+            Weight::zero(),
+            0
+        ));
+        assert_eq!(
+            System::events().last(),
+            Some(&record(RuntimeEvent::DefaultCollective(
+                CollectiveEvent::Disapproved {
+                    proposal_hash: hash
+                }
+            )))
+        );
+    });
+}
+
+#[test]
+fn weighted_motion_abstentions_follow_default_vote() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultCollective::set_members(
+            RuntimeOrigin::root(),
+            vec![1, 2, 5],
+            Some(1),
+            0
+        ));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(DefaultCollective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+
+        // The prime approves, so the abstaining stake counts as approval once the motion ends.
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(1),
+            hash,
+            0,
+            true
+        ));
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            false
+        ));
+        System::set_block_number(4);
+        assert_ok!(DefaultCollective::close(
+            RuntimeOrigin::root(),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        assert!(
+            System::events().contains(&record(RuntimeEvent::DefaultCollective(
+                CollectiveEvent::Approved {
+                    proposal_hash: hash
+                }
+            )))
+        );
+    });
+}
+
+#[test]
+fn weighted_motion_uses_proposal_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DefaultCollective::set_members(
+            RuntimeOrigin::root(),
+            vec![1, 2, 3, 4],
+            None,
+            0
+        ));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(DefaultCollective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+
+        // The threshold is 3 of 4 members, so a stake majority below three quarters is not
+        // enough to approve the motion.
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(3),
+            hash,
+            0,
+            true
+        ));
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(4),
+            hash,
+            0,
+            true
+        ));
+        assert_eq!(
+            DefaultCollective::weighted_tally(hash),
+            Some(WeightedTally {
+                ayes: 70,
+                nays: 0,
+                total: 100,
+                threshold: 3,
+                seats: 4
+            })
+        );
+        assert_noop!(
+            DefaultCollective::close(
+                RuntimeOrigin::root(),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            Error::<Test>::TooEarlyToCloseProposal
+        );
+
+        // Once three quarters of the stake can no longer approve, the motion is disapproved.
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(1),
+            hash,
+            0,
+            false
+        ));
+        assert_noop!(
+            DefaultCollective::close(
+                RuntimeOrigin::root(),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            Error::<Test>::TooEarlyToCloseProposal
+        );
+        assert_ok!(DefaultCollective::vote(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            false
+        ));
+        assert_ok!(DefaultCollective::close(
+            RuntimeOrigin::root(),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        assert_eq!(
+            System::events().last(),
+            Some(&record(RuntimeEvent::DefaultCollective(
+                CollectiveEvent::Disapproved {
+                    proposal_hash: hash
+                }
+            )))
+        );
+    });
+}
+
+#[test]
+fn unweighted_motion_has_no_weighted_tally() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+
+        assert!(Collective::voting(hash).is_some());
+        assert_eq!(Collective::weighted_tally(hash), None);
+        assert_eq!(Collective::vote_weight_of(hash, 1), None);
+    });
+}
+
+#[test]
+fn close_works_for_any_signed_account() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+
+        // An undecided motion cannot be closed early by anyone.
+        assert_noop!(
+            Collective::close(
+                RuntimeOrigin::signed(42),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            Error::<Test, Instance1>::TooEarlyToCloseProposal
+        );
+        assert_noop!(
+            Collective::close(
+                RuntimeOrigin::none(),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // Once the motion is decidable, a non-member closes it without paying fees.
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, true));
+        let close_rval: DispatchResultWithPostInfo = Collective::close(
+            RuntimeOrigin::signed(42),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len,
+        );
+        assert_eq!(close_rval.unwrap().pays_fee, Pays::No);
+        assert!(Collective::proposals().is_empty());
+        assert!(System::events().contains(&record(RuntimeEvent::Collective(
+            CollectiveEvent::Approved {
+                proposal_hash: hash
+            }
+        ))));
+    });
+}
+
+#[test]
+fn auto_close_closes_expired_motions() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        let later = make_proposal(43);
+        let later_len: u32 = later.using_encoded(|p| p.len() as u32);
+        let later_hash: H256 = later.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(later.clone()),
+            later_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(10u64).expect("convert u64 to block number.")
+        ));
+
+        // Nothing has expired yet.
+        Collective::on_initialize(3);
+        assert_eq!(*Collective::proposals(), vec![hash, later_hash]);
+
+        // The first motion expires without votes and is closed as disapproved.
+        System::set_block_number(4);
+        assert!(Collective::on_initialize(4) != Weight::zero());
+        assert_eq!(*Collective::proposals(), vec![later_hash]);
+        assert_eq!(
+            System::events()
+                .into_iter()
+                .rev()
+                .take(3)
+                .collect::<Vec<_>>(),
+            vec![
+                record(RuntimeEvent::Collective(CollectiveEvent::AutoClosed {
+                    proposal_hash: hash
+                })),
+                record(RuntimeEvent::Collective(CollectiveEvent::Disapproved {
+                    proposal_hash: hash
+                })),
+                record(RuntimeEvent::Collective(CollectiveEvent::Closed {
+                    proposal_hash: hash,
+                    yes: 0,
+                    no: 3
+                })),
+            ]
+        );
+    });
+}
+
+#[test]
+fn auto_close_skips_failing_motions_and_backs_off() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        let later = make_proposal(43);
+        let later_len: u32 = later.using_encoded(|p| p.len() as u32);
+        let later_hash: H256 = later.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(later.clone()),
+            later_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        for member in 1..=3 {
+            assert_ok!(Collective::vote(
+                RuntimeOrigin::signed(member),
+                later_hash,
+                1,
+                true
+            ));
+        }
+
+        // The system block lags behind, so the first motion is too early to close. The later
+        // motion is closed all the same.
+        Collective::on_initialize(4);
+        assert_eq!(*Collective::proposals(), vec![hash]);
+        assert_eq!(Collective::auto_close_failures(hash), Some((1, 6)));
+
+        // The failed motion is not attempted again until its back-off has passed.
+        System::set_block_number(5);
+        Collective::on_initialize(5);
+        assert_eq!(*Collective::proposals(), vec![hash]);
+        System::set_block_number(6);
+        Collective::on_initialize(6);
+        assert!(Collective::proposals().is_empty());
+        assert_eq!(Collective::auto_close_failures(hash), None);
+    });
+}
+
+#[test]
+fn auto_close_respects_weight_budget() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(CollectiveMajority::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(DefaultCollective::set_members(
+            RuntimeOrigin::root(),
+            vec![1, 2, 3],
+            None,
+            0
+        ));
+        assert_ok!(DefaultCollective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+
+        // The budget is too small to close a motion, and a zero budget disables auto-close.
+        System::set_block_number(4);
+        CollectiveMajority::on_initialize(4);
+        assert_eq!(DefaultCollective::on_initialize(4), Weight::zero());
+        assert_eq!(*CollectiveMajority::proposals(), vec![hash]);
+        assert_eq!(*DefaultCollective::proposals(), vec![hash]);
+    });
+}
+
 #[test]
 #[should_panic(expected = "Members cannot contain duplicate accounts.")]
 fn genesis_build_panics_with_duplicate_members() {
@@ -56,5 +56,64 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_commitment_schema() {
+        let netuid = 1;
+        let schema = CommitmentSchema::truncate_from(
+            (0..MAX_SCHEMA_FIELDS.min(T::MaxFields::get()))
+                .map(|i| CommitmentSchemaField {
+                    name: vec![b'a'; i as usize + 1]
+                        .try_into()
+                        .expect("name is short; qed"),
+                    kind: CommitmentFieldKind::Text,
+                    max_size: 128,
+                    optional: false,
+                })
+                .collect(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, Some(schema.clone()));
+
+        assert_eq!(CommitmentSchemas::<T>::get(netuid), Some(schema));
+    }
+
+    #[benchmark]
+    fn clear_commitment() {
+        let netuid = 1;
+        let caller: T::AccountId = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        Commitments::<T>::set_commitment(
+            RawOrigin::Signed(caller.clone()).into(),
+            netuid,
+            Box::new(create_identity_info::<T>(0)),
+        )
+        .expect("commitment is valid; qed");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), netuid);
+
+        assert_last_event::<T>(
+            Event::<T>::CommitmentCleared {
+                netuid,
+                who: caller,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_max_history_space() {
+        let netuid = 1;
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, MAX_COMMITMENT_HISTORY_SPACE);
+
+        assert_eq!(
+            MaxHistorySpace::<T>::get(netuid),
+            MAX_COMMITMENT_HISTORY_SPACE
+        );
+    }
+
     //impl_benchmark_test_suite!(Commitments, crate::tests::new_test_ext(), crate::tests::Test);
 }
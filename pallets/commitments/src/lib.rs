@@ -15,18 +15,20 @@ use subtensor_macros::freeze_struct;
 pub use types::*;
 pub use weights::WeightInfo;
 
-use ark_serialize::CanonicalDeserialize;
-use frame_support::{BoundedVec, traits::Currency};
+use frame_support::{
+    BoundedVec, ensure,
+    traits::{ConstU32, Currency, ReservableCurrency},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_drand::types::RoundNumber;
 use scale_info::prelude::collections::BTreeSet;
 use sp_runtime::SaturatedConversion;
-use sp_runtime::{Saturating, traits::Zero};
-use sp_std::{boxed::Box, vec::Vec};
-use tle::{
-    curves::drand::TinyBLS381,
-    stream_ciphers::AESGCMStreamCipherProvider,
-    tlock::{TLECiphertext, tld},
+use sp_runtime::{
+    Saturating,
+    traits::{BlakeTwo256, Hash as _, Zero},
 };
-use w3f_bls::EngineBLS;
+use sp_std::{boxed::Box, vec::Vec};
 
 type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -68,12 +70,19 @@ pub mod pallet {
         #[pallet::constant]
         type FieldDeposit: Get<BalanceOf<Self>>;
 
+        /// The amount held on deposit per byte of the encoded commitment.
+        #[pallet::constant]
+        type ByteDeposit: Get<BalanceOf<Self>>;
+
         /// The rate limit for commitments
         #[pallet::constant]
         type DefaultRateLimit: Get<BlockNumberFor<Self>>;
 
         /// Used to retreive the given subnet's tempo
         type TempoInterface: GetTempoInterface;
+
+        /// Used to check whether an account owns a subnet
+        type SubnetOwnerInterface: GetSubnetOwnerInterface<Self::AccountId>;
     }
 
     /// Used to retreive the given subnet's tempo  
@@ -82,6 +91,12 @@ pub mod pallet {
         fn get_epoch_index(netuid: u16, cur_block: u64) -> u64;
     }
 
+    /// Used to check the owner of a subnet
+    pub trait GetSubnetOwnerInterface<AccountId> {
+        /// Whether the account owns the given subnet.
+        fn is_subnet_owner(netuid: u16, who: &AccountId) -> bool;
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -108,6 +123,18 @@ pub mod pallet {
             /// The account
             who: T::AccountId,
         },
+        /// A commitment was cleared and its deposit returned
+        CommitmentCleared {
+            /// The netuid of the commitment
+            netuid: u16,
+            /// The account
+            who: T::AccountId,
+        },
+        /// The commitment schema of a subnet was set or removed
+        CommitmentSchemaSet {
+            /// The netuid of the schema
+            netuid: u16,
+        },
     }
 
     #[pallet::error]
@@ -122,6 +149,16 @@ pub mod pallet {
         SpaceLimitExceeded,
         /// Indicates that unreserve returned a leftover, which is unexpected.
         UnexpectedUnreserveLeftover,
+        /// The history space exceeds `MAX_COMMITMENT_HISTORY_SPACE`
+        HistorySpaceTooLarge,
+        /// Only the subnet owner or root can set the commitment schema
+        NotSubnetOwner,
+        /// The schema has no fields, more than `MaxFields`, or unnamed or duplicate fields
+        InvalidCommitmentSchema,
+        /// The commitment does not match the commitment schema of the subnet
+        CommitmentDoesNotMatchSchema,
+        /// The account has no commitment on the subnet
+        CommitmentNotFound,
     }
 
     #[pallet::type_value]
@@ -193,12 +230,40 @@ pub mod pallet {
     #[pallet::getter(fn max_space_per_user_per_rate_limit)]
     pub type MaxSpace<T> = StorageValue<_, u32, ValueQuery, DefaultMaxSpace>;
 
+    /// Maps netuid -> bytes of past commitments kept per account, 0 disables the history
+    #[pallet::storage]
+    pub type MaxHistorySpace<T> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+
+    /// Maps (netuid, who) -> the most recent commitments of the account, oldest first
+    #[pallet::storage]
+    pub type CommitmentHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<CommitmentHistoryEntry<BlockNumberFor<T>>, ConstU32<MAX_COMMITMENT_HISTORY>>,
+        ValueQuery,
+    >;
+
+    /// Maps netuid -> the fields every commitment on the subnet must have
+    #[pallet::storage]
+    pub type CommitmentSchemas<T> = StorageMap<_, Identity, u16, CommitmentSchema, OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Set the commitment for a given netuid
         #[pallet::call_index(0)]
         #[pallet::weight((
-            <T as pallet::Config>::WeightInfo::set_commitment(),
+            <T as pallet::Config>::WeightInfo::set_commitment()
+                .saturating_add(T::DbWeight::get().reads_writes(3, 1))
+                // Releasing and taking the references to the reveal rounds of timelocked fields.
+                .saturating_add(T::DbWeight::get().reads_writes(
+                    T::MaxFields::get().saturating_mul(3).into(),
+                    T::MaxFields::get().saturating_mul(2).into(),
+                ))
+                // The history is read and written back whole.
+                .saturating_add(Weight::from_parts(0, MAX_COMMITMENT_HISTORY_SPACE.into())),
             DispatchClass::Operational,
             Pays::No
         ))]
@@ -218,6 +283,12 @@ pub mod pallet {
                 extra_fields <= T::MaxFields::get(),
                 Error::<T>::TooManyFieldsInCommitmentInfo
             );
+            if let Some(schema) = CommitmentSchemas::<T>::get(netuid) {
+                ensure!(
+                    Self::matches_schema(&schema, &info.fields),
+                    Error::<T>::CommitmentDoesNotMatchSchema
+                );
+            }
 
             let cur_block = <frame_system::Pallet<T>>::block_number();
 
@@ -250,6 +321,7 @@ pub mod pallet {
 
             let mut id = match <CommitmentOf<T>>::get(netuid, &who) {
                 Some(mut id) => {
+                    Self::release_reveal_rounds(&id.info.fields);
                     id.info = *info.clone();
                     id.block = cur_block;
                     id
@@ -262,8 +334,7 @@ pub mod pallet {
             };
 
             let old_deposit = id.deposit;
-            let fd = <BalanceOf<T>>::from(extra_fields).saturating_mul(T::FieldDeposit::get());
-            id.deposit = T::InitialDeposit::get().saturating_add(fd);
+            id.deposit = Self::deposit_for(&id.info);
             if id.deposit > old_deposit {
                 T::Currency::reserve(&who, id.deposit.saturating_sub(old_deposit))?;
             }
@@ -275,6 +346,10 @@ pub mod pallet {
                 }
             }
 
+            Self::record_history(netuid, &who, cur_block, &id.info);
+            for reveal_round in Self::reveal_rounds(&id.info.fields) {
+                pallet_drand::Pallet::<T>::reference_round(reveal_round);
+            }
             <CommitmentOf<T>>::insert(netuid, &who, id);
             <LastCommitment<T>>::insert(netuid, &who, cur_block);
 
@@ -331,6 +406,78 @@ pub mod pallet {
             MaxSpace::<T>::set(new_limit);
             Ok(())
         }
+
+        /// Sudo-set the bytes of past commitments kept per account on a subnet
+        #[pallet::call_index(3)]
+        #[pallet::weight((
+            <T as pallet::Config>::WeightInfo::set_max_history_space(),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn set_max_history_space(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_limit: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                new_limit <= MAX_COMMITMENT_HISTORY_SPACE,
+                Error::<T>::HistorySpaceTooLarge
+            );
+            MaxHistorySpace::<T>::insert(netuid, new_limit);
+            Ok(())
+        }
+
+        /// Set the fields every commitment on a subnet must have, or remove them with `None`.
+        /// Only the subnet owner or root can change the schema.
+        #[pallet::call_index(4)]
+        #[pallet::weight((
+            <T as pallet::Config>::WeightInfo::set_commitment_schema(),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn set_commitment_schema(
+            origin: OriginFor<T>,
+            netuid: u16,
+            schema: Option<CommitmentSchema>,
+        ) -> DispatchResult {
+            if let Some(who) = ensure_signed_or_root(origin)? {
+                ensure!(
+                    T::SubnetOwnerInterface::is_subnet_owner(netuid, &who),
+                    Error::<T>::NotSubnetOwner
+                );
+            }
+
+            match schema {
+                Some(schema) => {
+                    ensure!(
+                        Self::is_valid_schema(&schema),
+                        Error::<T>::InvalidCommitmentSchema
+                    );
+                    CommitmentSchemas::<T>::insert(netuid, schema);
+                }
+                None => CommitmentSchemas::<T>::remove(netuid),
+            }
+
+            Self::deposit_event(Event::CommitmentSchemaSet { netuid });
+            Ok(())
+        }
+
+        /// Clear the commitment of the caller on a subnet and return its deposit
+        #[pallet::call_index(5)]
+        #[pallet::weight((
+            <T as pallet::Config>::WeightInfo::clear_commitment(),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn clear_commitment(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <CommitmentOf<T>>::contains_key(netuid, &who),
+                Error::<T>::CommitmentNotFound
+            );
+            Self::remove_commitment(netuid, &who)
+        }
     }
 
     #[pallet::hooks]
@@ -479,6 +626,250 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+    /// Append a commitment to the history of `who` on `netuid`, pruning the oldest entries
+    /// once the history takes more than the subnet's `MaxHistorySpace` bytes.
+    fn record_history(
+        netuid: u16,
+        who: &T::AccountId,
+        block: BlockNumberFor<T>,
+        info: &CommitmentInfo<T::MaxFields>,
+    ) {
+        let max_space = MaxHistorySpace::<T>::get(netuid);
+        if max_space == 0 {
+            CommitmentHistory::<T>::remove(netuid, who);
+            return;
+        }
+
+        CommitmentHistory::<T>::mutate(netuid, who, |history| {
+            let previous = history.last();
+            let field_hashes: Vec<[u8; 32]> = info
+                .fields
+                .iter()
+                .map(|field| BlakeTwo256::hash_of(field).0)
+                .collect();
+            let field_versions = field_hashes
+                .iter()
+                .enumerate()
+                .map(|(index, hash)| {
+                    let version = previous
+                        .and_then(|entry| entry.field_versions.get(index))
+                        .copied()
+                        .unwrap_or(0);
+                    let unchanged = previous
+                        .and_then(|entry| entry.field_hashes.get(index))
+                        .is_some_and(|previous_hash| previous_hash == hash);
+                    if unchanged {
+                        version
+                    } else {
+                        version.saturating_add(1)
+                    }
+                })
+                .collect();
+
+            let entry = CommitmentHistoryEntry {
+                block,
+                content_hash: BlakeTwo256::hash_of(info).0,
+                field_hashes,
+                field_versions,
+            };
+            if history.is_full() {
+                history.remove(0);
+            }
+            // Cannot fail, a slot was freed above.
+            let _ = history.try_push(entry);
+            Self::prune_history(history, max_space);
+        });
+    }
+
+    /// Drop the oldest entries of a history until it takes at most `max_space` bytes.
+    fn prune_history(
+        history: &mut BoundedVec<
+            CommitmentHistoryEntry<BlockNumberFor<T>>,
+            ConstU32<MAX_COMMITMENT_HISTORY>,
+        >,
+        max_space: u32,
+    ) {
+        let mut used_space: usize = 0;
+        let kept = history
+            .iter()
+            .rev()
+            .take_while(|entry| {
+                used_space = used_space.saturating_add(entry.encoded_size());
+                used_space <= max_space as usize
+            })
+            .count();
+        let mut to_remove = history.len().saturating_sub(kept);
+        history.retain(|_| {
+            let keep = to_remove == 0;
+            to_remove = to_remove.saturating_sub(1);
+            keep
+        });
+    }
+
+    /// The past commitments of `who` on `netuid` set between `from_block` and `to_block`,
+    /// inclusive, oldest first.
+    pub fn get_commitment_history(
+        netuid: u16,
+        who: &T::AccountId,
+        from_block: BlockNumberFor<T>,
+        to_block: BlockNumberFor<T>,
+    ) -> Vec<CommitmentHistoryEntry<BlockNumberFor<T>>> {
+        let mut history = CommitmentHistory::<T>::get(netuid, who);
+        // The limit may have been lowered since the history was last written.
+        Self::prune_history(&mut history, MaxHistorySpace::<T>::get(netuid));
+        history
+            .into_iter()
+            .filter(|entry| entry.block >= from_block && entry.block <= to_block)
+            .collect()
+    }
+
+    /// The deposit held for a commitment: a base amount, plus an amount per field and per byte.
+    pub fn deposit_for(info: &CommitmentInfo<T::MaxFields>) -> BalanceOf<T> {
+        let fields = <BalanceOf<T>>::from(info.fields.len() as u32);
+        let bytes = <BalanceOf<T>>::saturated_from(info.encoded_size() as u64);
+        T::InitialDeposit::get()
+            .saturating_add(fields.saturating_mul(T::FieldDeposit::get()))
+            .saturating_add(bytes.saturating_mul(T::ByteDeposit::get()))
+    }
+
+    /// The reveal rounds of the timelocked fields, which keep their pulses from being pruned.
+    fn reveal_rounds(fields: &[Data]) -> impl Iterator<Item = RoundNumber> + '_ {
+        fields.iter().filter_map(|data| match data {
+            Data::TimelockEncrypted { reveal_round, .. } => Some(*reveal_round),
+            _ => None,
+        })
+    }
+
+    fn release_reveal_rounds(fields: &[Data]) {
+        for reveal_round in Self::reveal_rounds(fields) {
+            pallet_drand::Pallet::<T>::release_round(reveal_round);
+        }
+    }
+
+    /// Remove the commitment of `who` and return its deposit. Its usage, history and reveals
+    /// are kept.
+    fn remove_commitment(netuid: u16, who: &T::AccountId) -> DispatchResult {
+        let Some(registration) = <CommitmentOf<T>>::take(netuid, who) else {
+            return Ok(());
+        };
+        Self::release_reveal_rounds(&registration.info.fields);
+        TimelockedIndex::<T>::mutate(|index| {
+            index.remove(&(netuid, who.clone()));
+        });
+
+        Self::deposit_event(Event::CommitmentCleared {
+            netuid,
+            who: who.clone(),
+        });
+        let leftover = T::Currency::unreserve(who, registration.deposit);
+        ensure!(leftover.is_zero(), Error::<T>::UnexpectedUnreserveLeftover);
+        Ok(())
+    }
+
+    /// Remove everything kept for `who` on the subnet, e.g. once its hotkey is deregistered.
+    pub fn purge_commitment(netuid: u16, who: &T::AccountId) {
+        if let Err(e) = Self::remove_commitment(netuid, who) {
+            log::warn!(
+                "Failed to return the commitment deposit of {:?} on subnet {}: {:?}",
+                who,
+                netuid,
+                e
+            );
+        }
+        <LastCommitment<T>>::remove(netuid, who);
+        <RevealedCommitments<T>>::remove(netuid, who);
+        UsedSpaceOf::<T>::remove(netuid, who);
+        CommitmentHistory::<T>::remove(netuid, who);
+    }
+
+    /// Remove every commitment on the subnet and its settings, e.g. once the subnet is removed.
+    pub fn purge_netuid(netuid: u16) {
+        let accounts: Vec<T::AccountId> = <CommitmentOf<T>>::iter_key_prefix(netuid).collect();
+        for who in accounts.iter() {
+            Self::purge_commitment(netuid, who);
+        }
+        let _ = <LastCommitment<T>>::clear_prefix(netuid, u32::MAX, None);
+        let _ = <RevealedCommitments<T>>::clear_prefix(netuid, u32::MAX, None);
+        let _ = UsedSpaceOf::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = CommitmentHistory::<T>::clear_prefix(netuid, u32::MAX, None);
+        TimelockedIndex::<T>::mutate(|index| index.retain(|(id, _)| *id != netuid));
+        CommitmentSchemas::<T>::remove(netuid);
+        MaxHistorySpace::<T>::remove(netuid);
+    }
+
+    /// A schema needs at least one field, at most `MaxFields`, each with a unique name.
+    ///
+    /// Every field of a commitment is a field of the schema, so a subnet cannot ask for more
+    /// fields than a commitment may hold.
+    fn is_valid_schema(schema: &CommitmentSchema) -> bool {
+        let mut names = BTreeSet::new();
+        !schema.is_empty()
+            && schema.len() <= T::MaxFields::get() as usize
+            && schema
+                .iter()
+                .all(|field| !field.name.is_empty() && names.insert(field.name.clone()))
+    }
+
+    fn matches_schema(schema: &CommitmentSchema, fields: &[Data]) -> bool {
+        schema.len() == fields.len()
+            && schema
+                .iter()
+                .zip(fields)
+                .all(|(field, data)| field.accepts(data))
+    }
+
+    /// The commitment of `who` on the subnet decoded with the subnet's schema, if both exist and
+    /// the commitment still matches the schema.
+    ///
+    /// Revealed timelock fields are moved out of the commitment into `RevealedCommitments`, and
+    /// the commitment is removed once nothing is left to reveal. A `TimelockEncrypted` field of
+    /// the schema that is missing from the commitment takes the next value revealed since the
+    /// commitment was set, in the order the values were revealed, and must fit its `max_size`.
+    pub fn get_decoded_commitment(
+        netuid: u16,
+        who: &T::AccountId,
+    ) -> Option<Vec<DecodedCommitmentField>> {
+        let schema = CommitmentSchemas::<T>::get(netuid)?;
+        let committed_at = LastCommitment::<T>::get(netuid, who)?.saturated_into::<u64>();
+        let fields = CommitmentOf::<T>::get(netuid, who)
+            .map(|registration| registration.info.fields.into_inner())
+            .unwrap_or_default();
+        // Reveals happen in `on_initialize`, so the ones of this commitment come after its block
+        let reveals: Vec<Vec<u8>> = RevealedCommitments::<T>::get(netuid, who)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, block)| *block > committed_at)
+            .map(|(bytes, _)| bytes)
+            .collect();
+        if schema.len() != fields.len().saturating_add(reveals.len()) {
+            return None;
+        }
+
+        let mut fields = fields.iter().peekable();
+        let mut reveals = reveals.into_iter();
+        schema
+            .iter()
+            .map(|field| {
+                let revealed = field.kind == CommitmentFieldKind::TimelockEncrypted
+                    && !reveals.as_slice().is_empty()
+                    && !matches!(fields.peek(), Some(Data::TimelockEncrypted { .. }));
+                let value = if revealed {
+                    let bytes = reveals.next()?;
+                    if bytes.len() > field.max_size as usize {
+                        return None;
+                    }
+                    CommitmentFieldValue::Revealed(bytes)
+                } else {
+                    field.decode(fields.next()?)?
+                };
+                Some(DecodedCommitmentField {
+                    name: field.name.to_vec(),
+                    value,
+                })
+            })
+            .collect()
+    }
+
     pub fn reveal_timelocked_commitments() -> DispatchResult {
         let index = TimelockedIndex::<T>::get();
         for (netuid, who) in index.clone() {
@@ -499,64 +890,26 @@ impl<T: Config> Pallet<T> {
                         encrypted,
                         reveal_round,
                     } => {
-                        let pulse = match pallet_drand::Pulses::<T>::get(reveal_round) {
-                            Some(p) => p,
-                            None => {
-                                remain_fields.push(Data::TimelockEncrypted {
-                                    encrypted,
-                                    reveal_round,
-                                });
+                        if !pallet_drand::Pulses::<T>::contains_key(reveal_round) {
+                            remain_fields.push(Data::TimelockEncrypted {
+                                encrypted,
+                                reveal_round,
+                            });
+                            continue;
+                        }
+                        pallet_drand::Pallet::<T>::release_round(reveal_round);
+
+                        let decrypted_bytes = match pallet_drand::Pallet::<T>::decrypt_timelock(
+                            &encrypted,
+                            reveal_round,
+                        ) {
+                            Ok(bytes) => bytes,
+                            Err(e) => {
+                                log::warn!("Failed to decrypt timelock for {:?}: {:?}", who, e);
                                 continue;
                             }
                         };
 
-                        let signature_bytes = pulse
-                            .signature
-                            .strip_prefix(b"0x")
-                            .unwrap_or(&pulse.signature);
-                        let sig_reader = &mut &signature_bytes[..];
-                        let sig =
-                            <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(
-                                sig_reader,
-                            )
-                            .map_err(|e| {
-                                log::warn!(
-                                    "Failed to deserialize drand signature for {:?}: {:?}",
-                                    who,
-                                    e
-                                )
-                            })
-                            .ok();
-
-                        let Some(sig) = sig else {
-                            log::warn!("No sig after deserialization");
-                            continue;
-                        };
-
-                        let reader = &mut &encrypted[..];
-                        let commit = TLECiphertext::<TinyBLS381>::deserialize_compressed(reader)
-                            .map_err(|e| {
-                                log::warn!(
-                                    "Failed to deserialize TLECiphertext for {:?}: {:?}",
-                                    who,
-                                    e
-                                )
-                            })
-                            .ok();
-
-                        let Some(commit) = commit else {
-                            log::warn!("No commit after deserialization");
-                            continue;
-                        };
-
-                        let decrypted_bytes: Vec<u8> =
-                            tld::<TinyBLS381, AESGCMStreamCipherProvider>(commit, sig)
-                                .map_err(|e| {
-                                    log::warn!("Failed to decrypt timelock for {:?}: {:?}", who, e)
-                                })
-                                .ok()
-                                .unwrap_or_default();
-
                         if decrypted_bytes.is_empty() {
                             log::warn!("Bytes were decrypted for {:?} but they are empty", who);
                             continue;
@@ -2,6 +2,7 @@ use crate as pallet_commitments;
 use frame_support::{
     derive_impl,
     pallet_prelude::{Get, TypeInfo},
+    parameter_types,
     traits::{ConstU32, ConstU64},
 };
 use sp_core::H256;
@@ -92,6 +93,10 @@ impl pallet_commitments::CanCommit<u64> for TestCanCommit {
     }
 }
 
+parameter_types! {
+    pub static ByteDeposit: u64 = 0;
+}
+
 impl pallet_commitments::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -100,8 +105,18 @@ impl pallet_commitments::Config for Test {
     type CanCommit = TestCanCommit;
     type FieldDeposit = ConstU64<0>;
     type InitialDeposit = ConstU64<0>;
+    type ByteDeposit = ByteDeposit;
     type DefaultRateLimit = ConstU64<0>;
     type TempoInterface = MockTempoInterface;
+    type SubnetOwnerInterface = MockSubnetOwnerInterface;
+}
+
+/// Account 1 owns every subnet.
+pub struct MockSubnetOwnerInterface;
+impl pallet_commitments::GetSubnetOwnerInterface<u64> for MockSubnetOwnerInterface {
+    fn is_subnet_owner(_netuid: u16, who: &u64) -> bool {
+        *who == 1
+    }
 }
 
 pub struct MockTempoInterface;
@@ -123,6 +138,17 @@ impl pallet_drand::Config for Test {
     type Verifier = pallet_drand::verifier::QuicknetVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type DefaultRetentionRounds = ConstU64<201_600>;
+    type MaxPrunedPulsesPerBlock = ConstU32<100>;
+    type MaxReferenceAge = ConstU64<403_200>;
+    type OnTimelockRevealed = ();
+    type MaxTimelocksPerRound = ConstU32<64>;
+    type MaxTimelockRevealsPerBlock = ConstU32<16>;
+    type MaxTimelocksPerAccount = ConstU32<32>;
+    type MaxPendingTimelocks = ConstU32<1_024>;
+    type RevealedTimelockLifetime = ConstU64<50_400>;
+    type Currency = Balances;
+    type TimelockDeposit = ConstU64<0>;
 }
 
 pub mod test_crypto {
@@ -196,12 +222,16 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 }
 
 use super::*;
-use crate::{EngineBLS, MAX_TIMELOCK_COMMITMENT_SIZE_BYTES, TinyBLS381};
-use ark_serialize::CanonicalSerialize;
+use crate::MAX_TIMELOCK_COMMITMENT_SIZE_BYTES;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use frame_support::BoundedVec;
 use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
 use sha2::Digest;
-use tle::{ibe::fullident::Identity, stream_ciphers::AESGCMStreamCipherProvider, tlock::tle};
+use tle::{
+    curves::drand::TinyBLS381, ibe::fullident::Identity,
+    stream_ciphers::AESGCMStreamCipherProvider, tlock::tle,
+};
+use w3f_bls::EngineBLS;
 
 // Drand Quicknet public key and signature for round=1000:
 pub const DRAND_QUICKNET_PUBKEY_HEX: &str = "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6\
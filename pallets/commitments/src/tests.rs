@@ -3,11 +3,14 @@ use sp_std::prelude::*;
 
 #[cfg(test)]
 use crate::{
-    CommitmentInfo, CommitmentOf, Config, Data, Error, Event, MaxSpace, Pallet, RateLimit,
-    Registration, RevealedCommitments, TimelockedIndex, UsedSpaceOf,
+    CommitmentFieldKind, CommitmentFieldValue, CommitmentHistory, CommitmentInfo, CommitmentOf,
+    CommitmentSchema, CommitmentSchemaField, CommitmentSchemas, Config, Data,
+    DecodedCommitmentField, Error, Event, MAX_COMMITMENT_HISTORY_SPACE,
+    MAX_TIMELOCK_COMMITMENT_SIZE_BYTES, MaxHistorySpace, MaxSpace, Pallet, RateLimit, Registration,
+    RevealedCommitments, TimelockedIndex, UsedSpaceOf,
     mock::{
-        Balances, DRAND_QUICKNET_SIG_2000_HEX, DRAND_QUICKNET_SIG_HEX, RuntimeEvent, RuntimeOrigin,
-        Test, TestMaxFields, insert_drand_pulse, new_test_ext, produce_ciphertext,
+        Balances, ByteDeposit, DRAND_QUICKNET_SIG_2000_HEX, DRAND_QUICKNET_SIG_HEX, RuntimeEvent,
+        RuntimeOrigin, Test, TestMaxFields, insert_drand_pulse, new_test_ext, produce_ciphertext,
     },
 };
 use frame_support::pallet_prelude::Hooks;
@@ -226,6 +229,112 @@ fn set_commitment_updates_deposit() {
     });
 }
 
+#[test]
+fn set_max_history_space_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(MaxHistorySpace::<Test>::get(1), 0);
+
+        assert_ok!(Pallet::<Test>::set_max_history_space(
+            RuntimeOrigin::root(),
+            1,
+            500
+        ));
+        assert_eq!(MaxHistorySpace::<Test>::get(1), 500);
+
+        assert_noop!(
+            Pallet::<Test>::set_max_history_space(RuntimeOrigin::signed(1), 1, 300),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pallet::<Test>::set_max_history_space(
+                RuntimeOrigin::root(),
+                1,
+                MAX_COMMITMENT_HISTORY_SPACE + 1
+            ),
+            Error::<Test>::HistorySpaceTooLarge
+        );
+    });
+}
+
+#[test]
+fn commitment_history_versions_fields_and_prunes() {
+    new_test_ext().execute_with(|| {
+        // Each entry with two fields takes 114 bytes, so two of them fit.
+        assert_ok!(Pallet::<Test>::set_max_history_space(
+            RuntimeOrigin::root(),
+            1,
+            250
+        ));
+        let info = |first: u8, second: u8| {
+            Box::new(CommitmentInfo {
+                fields: BoundedVec::try_from(vec![
+                    Data::Raw(vec![first].try_into().expect("Expected not to panic")),
+                    Data::Raw(vec![second].try_into().expect("Expected not to panic")),
+                ])
+                .expect("Expected not to panic"),
+            })
+        };
+
+        for (block, first, second) in [(1, 1, 1), (2, 2, 1), (3, 2, 2)] {
+            System::<Test>::set_block_number(block);
+            assert_ok!(Pallet::<Test>::set_commitment(
+                RuntimeOrigin::signed(1),
+                1,
+                info(first, second)
+            ));
+        }
+
+        // Only the two most recent commitments are kept.
+        let history = CommitmentHistory::<Test>::get(1, 1);
+        assert_eq!(history.len(), 2);
+        let blocks: Vec<u64> = history.iter().map(|entry| entry.block).collect();
+        assert_eq!(blocks, vec![2, 3]);
+
+        // Each field keeps its own version, bumped only when its content changes.
+        let versions: Vec<Vec<u32>> = history
+            .iter()
+            .map(|entry| entry.field_versions.clone())
+            .collect();
+        assert_eq!(versions, vec![vec![2, 1], vec![2, 2]]);
+        assert_eq!(
+            history.first().map(|entry| entry.field_hashes.first()),
+            history.last().map(|entry| entry.field_hashes.first())
+        );
+        assert_eq!(
+            history.last().map(|entry| entry.content_hash),
+            Some(sp_io::hashing::blake2_256(&info(2, 2).encode()))
+        );
+
+        // Range queries are inclusive.
+        assert_eq!(
+            Pallet::<Test>::get_commitment_history(1, &1, 3, 10).len(),
+            1
+        );
+        assert_eq!(Pallet::<Test>::get_commitment_history(1, &1, 0, 2).len(), 1);
+        assert!(Pallet::<Test>::get_commitment_history(1, &1, 4, 10).is_empty());
+
+        // Lowering the space hides the oldest entries until the next write prunes them.
+        assert_ok!(Pallet::<Test>::set_max_history_space(
+            RuntimeOrigin::root(),
+            1,
+            114
+        ));
+        let blocks: Vec<u64> = Pallet::<Test>::get_commitment_history(1, &1, 0, 10)
+            .iter()
+            .map(|entry| entry.block)
+            .collect();
+        assert_eq!(blocks, vec![3]);
+
+        // Other subnets keep no history by default.
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(1),
+            2,
+            info(1, 1)
+        ));
+        assert!(CommitmentHistory::<Test>::get(2, 1).is_empty());
+    });
+}
+
 #[test]
 fn set_rate_limit_works() {
     new_test_ext().execute_with(|| {
@@ -490,6 +599,8 @@ fn reveal_timelocked_commitment_single_field_entry_is_removed_after_reveal() {
             netuid,
             Box::new(info_outer)
         ));
+        // The pulse of the reveal round is kept until the field is revealed
+        assert_eq!(pallet_drand::RoundReferences::<Test>::get(reveal_round), 1);
 
         let drand_signature_bytes = hex::decode(DRAND_QUICKNET_SIG_HEX)
             .expect("Must decode DRAND_QUICKNET_SIG_HEX successfully");
@@ -497,6 +608,9 @@ fn reveal_timelocked_commitment_single_field_entry_is_removed_after_reveal() {
 
         System::<Test>::set_block_number(9999);
         assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+        assert!(!pallet_drand::RoundReferences::<Test>::contains_key(
+            reveal_round
+        ));
 
         let revealed =
             RevealedCommitments::<Test>::get(netuid, who).expect("Expected to find revealed data");
@@ -1791,3 +1905,375 @@ fn usage_respects_minimum_of_100_bytes() {
         );
     });
 }
+
+fn schema_field(name: &[u8], kind: CommitmentFieldKind, optional: bool) -> CommitmentSchemaField {
+    CommitmentSchemaField {
+        name: name.to_vec().try_into().expect("Expected not to panic"),
+        kind,
+        max_size: 64,
+        optional,
+    }
+}
+
+fn model_schema() -> CommitmentSchema {
+    vec![
+        schema_field(b"model_url", CommitmentFieldKind::Url, false),
+        schema_field(b"model_hash", CommitmentFieldKind::Hash, false),
+        schema_field(b"version", CommitmentFieldKind::U64, true),
+    ]
+    .try_into()
+    .expect("Expected not to panic")
+}
+
+fn raw(bytes: &[u8]) -> Data {
+    Data::Raw(bytes.to_vec().try_into().expect("Expected not to panic"))
+}
+
+#[test]
+fn set_commitment_schema_checks_origin_and_schema() {
+    new_test_ext().execute_with(|| {
+        // Account 1 owns every subnet in the mock
+        assert_noop!(
+            Pallet::<Test>::set_commitment_schema(
+                RuntimeOrigin::signed(2),
+                1,
+                Some(model_schema())
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        let duplicate: CommitmentSchema = vec![
+            schema_field(b"model_url", CommitmentFieldKind::Url, false),
+            schema_field(b"model_url", CommitmentFieldKind::Text, false),
+        ]
+        .try_into()
+        .expect("Expected not to panic");
+        for invalid in [
+            duplicate,
+            CommitmentSchema::default(),
+            vec![schema_field(b"", CommitmentFieldKind::Bytes, false)]
+                .try_into()
+                .expect("Expected not to panic"),
+        ] {
+            assert_noop!(
+                Pallet::<Test>::set_commitment_schema(RuntimeOrigin::signed(1), 1, Some(invalid)),
+                Error::<Test>::InvalidCommitmentSchema
+            );
+        }
+
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RuntimeOrigin::signed(1),
+            1,
+            Some(model_schema())
+        ));
+        assert_eq!(CommitmentSchemas::<Test>::get(1), Some(model_schema()));
+        System::<Test>::assert_last_event(RuntimeEvent::Commitments(Event::CommitmentSchemaSet {
+            netuid: 1,
+        }));
+
+        // Root can remove it
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RawOrigin::Root.into(),
+            1,
+            None
+        ));
+        assert_eq!(CommitmentSchemas::<Test>::get(1), None);
+    });
+}
+
+#[test]
+fn set_commitment_is_validated_against_schema() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RawOrigin::Root.into(),
+            1,
+            Some(model_schema())
+        ));
+
+        let commit = |fields: Vec<Data>| {
+            Pallet::<Test>::set_commitment(
+                RuntimeOrigin::signed(2),
+                1,
+                Box::new(CommitmentInfo {
+                    fields: BoundedVec::try_from(fields).expect("Expected not to panic"),
+                }),
+            )
+        };
+
+        for invalid in [
+            // Missing field
+            vec![raw(b"https://example.com/model"), Data::Sha256([1; 32])],
+            // Not a url
+            vec![raw(b"ftp://example.com"), Data::Sha256([1; 32]), Data::None],
+            // Not a hash
+            vec![raw(b"https://example.com/model"), raw(&[1; 32]), Data::None],
+            // Not a u64
+            vec![
+                raw(b"https://example.com/model"),
+                Data::Sha256([1; 32]),
+                raw(&[1; 4]),
+            ],
+            // Required field left empty
+            vec![Data::None, Data::Sha256([1; 32]), Data::None],
+            // Longer than the max size
+            vec![raw(&[b'a'; 65]), Data::Sha256([1; 32]), Data::None],
+        ] {
+            assert_noop!(commit(invalid), Error::<Test>::CommitmentDoesNotMatchSchema);
+        }
+
+        assert_ok!(commit(vec![
+            raw(b"https://example.com/model"),
+            Data::Keccak256([1; 32]),
+            raw(&7u64.encode()),
+        ]));
+        assert_eq!(
+            Pallet::<Test>::get_decoded_commitment(1, &2),
+            Some(vec![
+                DecodedCommitmentField {
+                    name: b"model_url".to_vec(),
+                    value: CommitmentFieldValue::Url(b"https://example.com/model".to_vec()),
+                },
+                DecodedCommitmentField {
+                    name: b"model_hash".to_vec(),
+                    value: CommitmentFieldValue::Hash([1; 32]),
+                },
+                DecodedCommitmentField {
+                    name: b"version".to_vec(),
+                    value: CommitmentFieldValue::U64(7),
+                },
+            ])
+        );
+
+        // Subnets without a schema accept any commitment and are not decoded
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(2),
+            2,
+            Box::new(CommitmentInfo {
+                fields: BoundedVec::try_from(vec![raw(b"anything")])
+                    .expect("Expected not to panic"),
+            }),
+        ));
+        assert_eq!(Pallet::<Test>::get_decoded_commitment(2, &2), None);
+    });
+}
+
+#[test]
+fn decoded_commitment_includes_revealed_timelock_fields() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        let who = 2;
+        let reveal_round = 1000;
+        let timelock_field = CommitmentSchemaField {
+            max_size: MAX_TIMELOCK_COMMITMENT_SIZE_BYTES,
+            ..schema_field(b"weights", CommitmentFieldKind::TimelockEncrypted, false)
+        };
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RawOrigin::Root.into(),
+            netuid,
+            Some(
+                vec![timelock_field]
+                    .try_into()
+                    .expect("Expected not to panic")
+            )
+        ));
+
+        let plaintext = b"revealed weights".to_vec();
+        System::<Test>::set_block_number(1);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            Box::new(CommitmentInfo {
+                fields: BoundedVec::try_from(vec![Data::TimelockEncrypted {
+                    encrypted: produce_ciphertext(&plaintext, reveal_round),
+                    reveal_round,
+                }])
+                .expect("Expected not to panic"),
+            }),
+        ));
+        assert_eq!(
+            Pallet::<Test>::get_decoded_commitment(netuid, &who),
+            Some(vec![DecodedCommitmentField {
+                name: b"weights".to_vec(),
+                value: CommitmentFieldValue::TimelockEncrypted { reveal_round },
+            }])
+        );
+
+        let drand_signature_bytes =
+            hex::decode(DRAND_QUICKNET_SIG_HEX).expect("Expected not to panic");
+        insert_drand_pulse(reveal_round, &drand_signature_bytes);
+        System::<Test>::set_block_number(2);
+        assert_ok!(Pallet::<Test>::reveal_timelocked_commitments());
+
+        // The commitment is gone once revealed, but it is still decoded from its reveal
+        assert!(CommitmentOf::<Test>::get(netuid, who).is_none());
+        assert_eq!(
+            Pallet::<Test>::get_decoded_commitment(netuid, &who),
+            Some(vec![DecodedCommitmentField {
+                name: b"weights".to_vec(),
+                value: CommitmentFieldValue::Revealed(plaintext),
+            }])
+        );
+
+        // Reveals of an older commitment are not part of a newer one
+        System::<Test>::set_block_number(3);
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            netuid,
+            Box::new(CommitmentInfo {
+                fields: BoundedVec::try_from(vec![Data::TimelockEncrypted {
+                    encrypted: produce_ciphertext(b"next weights", reveal_round + 1),
+                    reveal_round: reveal_round + 1,
+                }])
+                .expect("Expected not to panic"),
+            }),
+        ));
+        assert_eq!(
+            Pallet::<Test>::get_decoded_commitment(netuid, &who),
+            Some(vec![DecodedCommitmentField {
+                name: b"weights".to_vec(),
+                value: CommitmentFieldValue::TimelockEncrypted {
+                    reveal_round: reveal_round + 1
+                },
+            }])
+        );
+    });
+}
+
+#[test]
+fn deposit_is_proportional_to_size_and_refunded() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        ByteDeposit::set(10);
+        let who = 2;
+        Balances::make_free_balance_be(&who, 1_000_000);
+
+        let info = |fields: Vec<Data>| {
+            Box::new(CommitmentInfo {
+                fields: BoundedVec::try_from(fields).expect("Expected not to panic"),
+            })
+        };
+        let large = info(vec![raw(&[1; 64]), raw(&[2; 64])]);
+        let small = info(vec![raw(&[1; 8])]);
+
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            1,
+            large.clone()
+        ));
+        let large_deposit = 10 * large.encoded_size() as u64;
+        assert_eq!(Pallet::<Test>::deposit_for(&large), large_deposit);
+        assert_eq!(
+            CommitmentOf::<Test>::get(1, who)
+                .expect("Expected not to panic")
+                .deposit,
+            large_deposit
+        );
+        assert_eq!(Balances::reserved_balance(who), large_deposit);
+
+        // Overwriting with a smaller commitment refunds the difference
+        assert_ok!(Pallet::<Test>::set_commitment(
+            RuntimeOrigin::signed(who),
+            1,
+            small.clone()
+        ));
+        let small_deposit = 10 * small.encoded_size() as u64;
+        assert!(small_deposit < large_deposit);
+        assert_eq!(Balances::reserved_balance(who), small_deposit);
+
+        // Clearing refunds the rest
+        assert_ok!(Pallet::<Test>::clear_commitment(
+            RuntimeOrigin::signed(who),
+            1
+        ));
+        assert_eq!(Balances::reserved_balance(who), 0);
+        assert_eq!(CommitmentOf::<Test>::get(1, who), None);
+        System::<Test>::assert_last_event(RuntimeEvent::Commitments(Event::CommitmentCleared {
+            netuid: 1,
+            who,
+        }));
+
+        assert_noop!(
+            Pallet::<Test>::clear_commitment(RuntimeOrigin::signed(who), 1),
+            Error::<Test>::CommitmentNotFound
+        );
+
+        // The deposit has to be affordable
+        Balances::make_free_balance_be(&3, 10);
+        assert!(Pallet::<Test>::set_commitment(RuntimeOrigin::signed(3), 1, large).is_err());
+    });
+}
+
+#[test]
+fn purge_removes_commitments_and_refunds_deposits() {
+    new_test_ext().execute_with(|| {
+        System::<Test>::set_block_number(1);
+        ByteDeposit::set(10);
+        assert_ok!(Pallet::<Test>::set_commitment_schema(
+            RawOrigin::Root.into(),
+            2,
+            Some(model_schema())
+        ));
+        assert_ok!(Pallet::<Test>::set_max_history_space(
+            RawOrigin::Root.into(),
+            2,
+            1_000
+        ));
+
+        let commit = |netuid: u16, who: u64, fields: Vec<Data>| {
+            Pallet::<Test>::set_commitment(
+                RuntimeOrigin::signed(who),
+                netuid,
+                Box::new(CommitmentInfo {
+                    fields: BoundedVec::try_from(fields).expect("Expected not to panic"),
+                }),
+            )
+        };
+        let model = || {
+            vec![
+                raw(b"https://example.com/model"),
+                Data::Sha256([1; 32]),
+                Data::None,
+            ]
+        };
+        for who in [2, 3] {
+            Balances::make_free_balance_be(&who, 1_000_000);
+            assert_ok!(commit(1, who, vec![raw(b"one")]));
+            assert_ok!(commit(2, who, model()));
+        }
+
+        // Deregistering a hotkey removes its commitment on that subnet only
+        Pallet::<Test>::purge_commitment(1, &2);
+        assert_eq!(CommitmentOf::<Test>::get(1, 2), None);
+        assert!(UsedSpaceOf::<Test>::get(1, 2).is_none());
+        assert!(CommitmentOf::<Test>::get(2, 2).is_some());
+        assert_eq!(
+            Balances::reserved_balance(2),
+            CommitmentOf::<Test>::get(2, 2)
+                .expect("Expected not to panic")
+                .deposit
+        );
+        System::<Test>::assert_last_event(RuntimeEvent::Commitments(Event::CommitmentCleared {
+            netuid: 1,
+            who: 2,
+        }));
+
+        // Removing a subnet removes every commitment and setting on it
+        assert!(!CommitmentHistory::<Test>::get(2, 3).is_empty());
+        Pallet::<Test>::purge_netuid(2);
+        for who in [2, 3] {
+            assert_eq!(CommitmentOf::<Test>::get(2, who), None);
+            assert!(UsedSpaceOf::<Test>::get(2, who).is_none());
+            assert!(CommitmentHistory::<Test>::get(2, who).is_empty());
+        }
+        assert_eq!(CommitmentSchemas::<Test>::get(2), None);
+        assert_eq!(MaxHistorySpace::<Test>::get(2), 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(
+            Balances::reserved_balance(3),
+            CommitmentOf::<Test>::get(1, 3)
+                .expect("Expected not to panic")
+                .deposit
+        );
+    });
+}
@@ -358,6 +358,143 @@ pub struct RevealedData<Balance, MaxFields: Get<u32>, BlockNumber> {
     pub deposit: Balance,
 }
 
+/// Upper bound on the number of past commitments kept per account.
+pub const MAX_COMMITMENT_HISTORY: u32 = 256;
+
+/// Upper bound on the bytes of history a subnet may keep per account.
+pub const MAX_COMMITMENT_HISTORY_SPACE: u32 = 16 * 1024;
+
+/// A commitment made by an account, as kept in the commitment history of a subnet.
+#[freeze_struct("a1803f162e9fd04c")]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, Debug)]
+pub struct CommitmentHistoryEntry<BlockNumber> {
+    /// The block at which the commitment was set.
+    pub block: BlockNumber,
+    /// The Blake2-256 hash of the encoded commitment.
+    pub content_hash: [u8; 32],
+    /// The Blake2-256 hash of each encoded field, in order.
+    pub field_hashes: Vec<[u8; 32]>,
+    /// The version of each field, bumped every time the content of the field changes.
+    pub field_versions: Vec<u32>,
+}
+
+/// Maximum number of fields in a commitment schema.
+pub const MAX_SCHEMA_FIELDS: u32 = 16;
+/// Maximum length of the name of a commitment schema field.
+pub const MAX_SCHEMA_FIELD_NAME_LENGTH: u32 = 32;
+
+/// The type of a commitment schema field, and the `Data` variants it accepts.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+pub enum CommitmentFieldKind {
+    /// Any bytes, stored as `Data::Raw`.
+    Bytes,
+    /// UTF-8 text, stored as `Data::Raw`.
+    Text,
+    /// An `http://` or `https://` url, stored as `Data::Raw`.
+    Url,
+    /// A SCALE encoded `u64`, stored as `Data::Raw`.
+    U64,
+    /// A 32-byte hash, stored as any of the hash variants of `Data`.
+    Hash,
+    /// A timelock-encrypted value, stored as `Data::TimelockEncrypted`.
+    TimelockEncrypted,
+}
+
+/// A named, typed field of a commitment schema.
+#[freeze_struct("dc72d2336dbec334")]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+pub struct CommitmentSchemaField {
+    /// The name of the field, e.g. `model_url`.
+    pub name: BoundedVec<u8, ConstU32<MAX_SCHEMA_FIELD_NAME_LENGTH>>,
+    /// The type of the field.
+    pub kind: CommitmentFieldKind,
+    /// The maximum size of the field in bytes.
+    pub max_size: u32,
+    /// Whether the field may be `Data::None`.
+    pub optional: bool,
+}
+
+/// The fields a subnet expects in every commitment, in order.
+pub type CommitmentSchema = BoundedVec<CommitmentSchemaField, ConstU32<MAX_SCHEMA_FIELDS>>;
+
+impl CommitmentSchemaField {
+    /// Whether `data` is a valid value of this field.
+    pub fn accepts(&self, data: &Data) -> bool {
+        if data.len_for_rate_limit() > u64::from(self.max_size) {
+            return false;
+        }
+        match (self.kind, data) {
+            (_, Data::None) => self.optional,
+            (CommitmentFieldKind::Bytes, Data::Raw(_)) => true,
+            (CommitmentFieldKind::Text, Data::Raw(bytes)) => core::str::from_utf8(bytes).is_ok(),
+            (CommitmentFieldKind::Url, Data::Raw(bytes)) => core::str::from_utf8(bytes)
+                .is_ok_and(|url| url.starts_with("http://") || url.starts_with("https://")),
+            (CommitmentFieldKind::U64, Data::Raw(bytes)) => bytes.len() == 8,
+            (
+                CommitmentFieldKind::Hash,
+                Data::BlakeTwo256(_) | Data::Sha256(_) | Data::Keccak256(_) | Data::ShaThree256(_),
+            ) => true,
+            (CommitmentFieldKind::TimelockEncrypted, Data::TimelockEncrypted { .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// The value of `data` as this field, if it is valid.
+    pub fn decode(&self, data: &Data) -> Option<CommitmentFieldValue> {
+        if !self.accepts(data) {
+            return None;
+        }
+        Some(match data {
+            Data::None => CommitmentFieldValue::None,
+            Data::Raw(bytes) => match self.kind {
+                CommitmentFieldKind::Text => CommitmentFieldValue::Text(bytes.to_vec()),
+                CommitmentFieldKind::Url => CommitmentFieldValue::Url(bytes.to_vec()),
+                CommitmentFieldKind::U64 => {
+                    CommitmentFieldValue::U64(u64::decode(&mut &bytes[..]).ok()?)
+                }
+                _ => CommitmentFieldValue::Bytes(bytes.to_vec()),
+            },
+            Data::BlakeTwo256(hash)
+            | Data::Sha256(hash)
+            | Data::Keccak256(hash)
+            | Data::ShaThree256(hash) => CommitmentFieldValue::Hash(*hash),
+            Data::TimelockEncrypted { reveal_round, .. } => {
+                CommitmentFieldValue::TimelockEncrypted {
+                    reveal_round: *reveal_round,
+                }
+            }
+        })
+    }
+}
+
+/// The value of a commitment field, decoded with the schema of its subnet.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, Debug)]
+pub enum CommitmentFieldValue {
+    /// An optional field left empty.
+    None,
+    Bytes(Vec<u8>),
+    Text(Vec<u8>),
+    Url(Vec<u8>),
+    U64(u64),
+    Hash([u8; 32]),
+    /// A value not revealed yet.
+    TimelockEncrypted {
+        reveal_round: u64,
+    },
+    /// The decrypted bytes of a revealed timelock-encrypted value.
+    Revealed(Vec<u8>),
+}
+
+/// A commitment field with the name given by the schema of its subnet.
+#[freeze_struct("efab26b625e9ee80")]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, Debug)]
+pub struct DecodedCommitmentField {
+    /// The name of the field in the schema.
+    pub name: Vec<u8>,
+    /// The value of the field.
+    pub value: CommitmentFieldValue,
+}
+
 /// Tracks how much “space” each (netuid, who) has used within the current RateLimit block-window.
 #[freeze_struct("1f23fb50f96326e4")]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo)]
@@ -31,6 +31,9 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_commitment() -> Weight;
 	fn set_rate_limit() -> Weight;
+	fn set_commitment_schema() -> Weight;
+	fn clear_commitment() -> Weight;
+	fn set_max_history_space() -> Weight;
 }
 
 /// Weights for `pallet_commitments` using the Substrate node and recommended hardware.
@@ -54,6 +57,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(10_000_000, 2000)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}	
+	/// Storage: SubtensorModule SubnetOwner (r:1 w:0)
+	/// Proof Skipped: SubtensorModule SubnetOwner (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Commitments CommitmentSchemas (r:0 w:1)
+	/// Proof Skipped: Commitments CommitmentSchemas (max_values: None, max_size: None, mode: Measured)
+	fn set_commitment_schema() -> Weight {
+		Weight::from_parts(14_000_000, 3500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Commitments CommitmentOf (r:1 w:1)
+	/// Proof Skipped: Commitments CommitmentOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Commitments TimelockedIndex (r:1 w:1)
+	/// Proof Skipped: Commitments TimelockedIndex (max_values: Some(1), max_size: None, mode: Measured)
+	fn clear_commitment() -> Weight {
+		Weight::from_parts(22_000_000, 6344)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Commitments MaxHistorySpace (r:0 w:1)
+	// TODO should be replaced by benchmarked weights
+	fn set_max_history_space() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -77,4 +104,32 @@ impl WeightInfo for () {
 		Weight::from_parts(10_000_000, 2000)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}	
-}
\ No newline at end of file
+
+	/// Storage: SubtensorModule SubnetOwner (r:1 w:0)
+	/// Proof Skipped: SubtensorModule SubnetOwner (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Commitments CommitmentSchemas (r:0 w:1)
+	/// Proof Skipped: Commitments CommitmentSchemas (max_values: None, max_size: None, mode: Measured)
+	fn set_commitment_schema() -> Weight {
+		Weight::from_parts(14_000_000, 3500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+
+	/// Storage: Commitments CommitmentOf (r:1 w:1)
+	/// Proof Skipped: Commitments CommitmentOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Commitments TimelockedIndex (r:1 w:1)
+	/// Proof Skipped: Commitments TimelockedIndex (max_values: Some(1), max_size: None, mode: Measured)
+	fn clear_commitment() -> Weight {
+		Weight::from_parts(22_000_000, 6344)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: Commitments MaxHistorySpace (r:0 w:1)
+	// TODO should be replaced by benchmarked weights
+	fn set_max_history_space() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
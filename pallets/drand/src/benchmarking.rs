@@ -21,8 +21,8 @@ use super::*;
 use crate::Pallet as Drand;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
 
-pub const DRAND_PULSE: &str = "{\"round\":1000,\"randomness\":\"fe290beca10872ef2fb164d2aa4442de4566183ec51c56ff3cd603d930e54fdd\",\"signature\":\"b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39\"}";
 pub const DRAND_INFO_RESPONSE: &str = "{\"public_key\":\"83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a\",\"period\":3,\"genesis_time\":1692803367,\"hash\":\"52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971\",\"groupHash\":\"f477d5c89f21a17c863a7f937c6a6d15859414d2be09cd448d4279af331c5d3e\",\"schemeID\":\"bls-unchained-g1-rfc9380\",\"metadata\":{\"beaconID\":\"quicknet\"}}";
 
 #[benchmarks(
@@ -52,31 +52,112 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn write_pulse() {
-        // Deserialize the beacon info and pulse
-        let info: BeaconInfoResponse = serde_json::from_str(DRAND_INFO_RESPONSE).unwrap();
-        let config = info.try_into_beacon_config().unwrap();
-        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
-        let p: Pulse = u_p.try_into_pulse().unwrap();
+    fn set_retention_rounds() {
+        #[extrinsic_call]
+        set_retention_rounds(RawOrigin::Root, 1_000);
+
+        assert_eq!(RetentionRounds::<T>::get(), 1_000);
+    }
+
+    #[benchmark]
+    fn set_beacon_endpoints() {
+        let endpoints = BeaconEndpoints::truncate_from(
+            (0..MAX_BEACON_ENDPOINTS)
+                .map(|_| {
+                    BeaconEndpoint::truncate_from(vec![b'a'; MAX_BEACON_ENDPOINT_LENGTH as usize])
+                })
+                .collect(),
+        );
+
+        #[extrinsic_call]
+        set_beacon_endpoints(RawOrigin::Root, endpoints.clone());
+
+        assert_eq!(BeaconEndpointList::<T>::get(), endpoints);
+    }
+
+    #[benchmark]
+    fn write_pulse(p: Linear<1, { MAX_PULSES_TO_FETCH as u32 }>) {
+        // Sign the pulses with a known key, as a single real pulse cannot fill a batch
+        let secret = 1_234_567;
+        BeaconConfig::<T>::put(utils::make_quicknet_beacon(secret));
+        let pulses: Vec<Pulse> = (1..=u64::from(p))
+            .map(|round| utils::make_quicknet_pulse(secret, round))
+            .collect();
 
         let block_number = 1u32.into();
         let alice = sp_keyring::Sr25519Keyring::Alice.public();
 
-        // Set the beacon configuration
-        BeaconConfig::<T>::put(config);
-
-        // Create PulsesPayload with a vector of pulses
         let pulses_payload = PulsesPayload {
             block_number,
-            pulses: vec![p.clone()], // Wrap the pulse in a vector
+            pulses: pulses.clone(),
+            previous_signature: None,
             public: alice.into(),
         };
 
         #[extrinsic_call]
         write_pulse(RawOrigin::None, pulses_payload.clone(), None);
 
-        // Check that the pulse has been stored
-        assert_eq!(Pulses::<T>::get(p.round), Some(p));
+        // Check that the pulses have been stored
+        assert_eq!(LastStoredRound::<T>::get(), u64::from(p));
+    }
+
+    #[benchmark]
+    fn submit_timelock() {
+        let caller: T::AccountId = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let ciphertext = TimelockCiphertext::truncate_from(utils::make_timelock_ciphertext(
+            1_234_567,
+            1,
+            &[7u8; 256],
+        ));
+
+        #[extrinsic_call]
+        submit_timelock(RawOrigin::Signed(caller.clone()), ciphertext, 1);
+
+        assert!(Timelocks::<T>::contains_key(0));
+        assert_eq!(TimelocksOf::<T>::get(&caller).into_inner(), vec![0]);
+    }
+
+    #[benchmark]
+    fn remove_timelock() {
+        let caller: T::AccountId = whitelisted_caller();
+        let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let ciphertext = TimelockCiphertext::truncate_from(utils::make_timelock_ciphertext(
+            1_234_567,
+            1,
+            &[7u8; 256],
+        ));
+        Drand::<T>::submit_timelock(RawOrigin::Signed(caller.clone()).into(), ciphertext, 1)
+            .unwrap();
+
+        #[extrinsic_call]
+        remove_timelock(RawOrigin::Signed(caller.clone()), 0);
+
+        assert!(!Timelocks::<T>::contains_key(0));
+        assert!(TimelocksOf::<T>::get(&caller).is_empty());
+    }
+
+    #[benchmark]
+    fn reveal_timelock() {
+        let secret = 1_234_567;
+        let caller: T::AccountId = whitelisted_caller();
+        let ciphertext = TimelockCiphertext::truncate_from(utils::make_timelock_ciphertext(
+            secret,
+            1,
+            &[7u8; MAX_TIMELOCK_LENGTH as usize / 2],
+        ));
+        let id = Drand::<T>::register_timelock(caller, ciphertext, 1, true, 0u32.into()).unwrap();
+        Pulses::<T>::insert(1, utils::make_quicknet_pulse(secret, 1));
+
+        #[block]
+        {
+            Drand::<T>::reveal_timelock(id);
+        }
+
+        assert!(matches!(
+            RevealedTimelocks::<T>::get(id).map(|revealed| revealed.outcome),
+            Some(TimelockOutcome::Revealed(_))
+        ));
     }
 
     impl_benchmark_test_suite!(Drand, crate::mock::new_test_ext(), crate::mock::Test);
@@ -26,6 +26,10 @@
 //! signed transaction to encode them in the runtime. The runtime uses the optimized arkworks host
 //! functions to efficiently verify the pulse.
 //!
+//! The beacon and the http endpoints serving it are set by root, so the pallet can follow other
+//! drand chains, e.g. the chained default network. A node can point its offchain worker at a
+//! local relay by writing its url under [`RELAY_ENDPOINT_KEY`] in the persistent offchain storage.
+//!
 //! Run `cargo doc --package pallet-drand --open` to view this pallet's documentation.
 
 // We make sure this pallet uses `no_std` for compiling to Wasm.
@@ -40,7 +44,10 @@ extern crate alloc;
 
 use alloc::{format, string::String, vec, vec::Vec};
 use codec::Encode;
-use frame_support::{pallet_prelude::*, traits::Randomness};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, Randomness, ReservableCurrency},
+};
 use frame_system::{
     offchain::{
         AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
@@ -58,10 +65,12 @@ use sp_runtime::{
 };
 
 pub mod bls12_381;
+pub mod timelock;
 pub mod types;
 pub mod utils;
 pub mod verifier;
 
+use timelock::*;
 use types::*;
 use verifier::Verifier;
 
@@ -76,7 +85,7 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
-/// the main drand api endpoint
+/// the default drand api endpoints
 const ENDPOINTS: [&str; 5] = [
     "https://api.drand.sh",
     "https://api2.drand.sh",
@@ -90,7 +99,9 @@ const ENDPOINTS: [&str; 5] = [
 pub const QUICKNET_CHAIN_HASH: &str =
     "52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971";
 
-const CHAIN_HASH: &str = QUICKNET_CHAIN_HASH;
+/// the persistent offchain storage key of a node-local relay, which then replaces the on-chain
+/// endpoints for that node
+pub const RELAY_ENDPOINT_KEY: &[u8] = b"drand::relay_endpoint";
 
 pub const MAX_PULSES_TO_FETCH: u64 = 50;
 
@@ -148,6 +159,9 @@ impl<T: SigningTypes> SignedPayload<T> for PulsesPayload<T::Public, BlockNumberF
     }
 }
 
+type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -176,6 +190,38 @@ pub mod pallet {
         /// complete.
         #[pallet::constant]
         type HttpFetchTimeout: Get<u64>;
+        /// The number of most recent rounds kept in `Pulses` until root changes it.
+        #[pallet::constant]
+        type DefaultRetentionRounds: Get<RoundNumber>;
+        /// The maximum number of rounds pruned from `Pulses` in a single block.
+        #[pallet::constant]
+        type MaxPrunedPulsesPerBlock: Get<u32>;
+        /// The number of rounds behind the last stored round after which a reference no longer
+        /// keeps a pulse from being pruned.
+        #[pallet::constant]
+        type MaxReferenceAge: Get<RoundNumber>;
+        /// Handles the outcome of every revealed timelock.
+        type OnTimelockRevealed: OnTimelockRevealed<Self::AccountId>;
+        /// The maximum number of timelocks queued under a single round.
+        #[pallet::constant]
+        type MaxTimelocksPerRound: Get<u32>;
+        /// The maximum number of timelocks revealed in a single block.
+        #[pallet::constant]
+        type MaxTimelockRevealsPerBlock: Get<u32>;
+        /// The maximum number of timelocks an account holds through `submit_timelock`.
+        #[pallet::constant]
+        type MaxTimelocksPerAccount: Get<u32>;
+        /// The maximum number of timelocks waiting to be revealed.
+        #[pallet::constant]
+        type MaxPendingTimelocks: Get<u32>;
+        /// The number of blocks a revealed timelock is kept before it is removed.
+        #[pallet::constant]
+        type RevealedTimelockLifetime: Get<BlockNumberFor<Self>>;
+        /// The currency the timelock deposits are reserved in.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// The deposit reserved for each timelock submitted with `submit_timelock`.
+        #[pallet::constant]
+        type TimelockDeposit: Get<BalanceOf<Self>>;
     }
 
     /// the drand beacon configuration
@@ -214,12 +260,113 @@ pub mod pallet {
         }
     }
 
+    #[pallet::type_value]
+    pub fn DefaultBeaconEndpoints<T: Config>() -> BeaconEndpoints {
+        BeaconEndpoints::truncate_from(
+            ENDPOINTS
+                .iter()
+                .map(|endpoint| BeaconEndpoint::truncate_from(endpoint.as_bytes().to_vec()))
+                .collect(),
+        )
+    }
+
+    /// the http endpoints serving the configured beacon
+    #[pallet::storage]
+    pub type BeaconEndpointList<T: Config> =
+        StorageValue<_, BeaconEndpoints, ValueQuery, DefaultBeaconEndpoints<T>>;
+
     /// map round number to pulse
     #[pallet::storage]
     pub type Pulses<T: Config> = StorageMap<_, Blake2_128Concat, RoundNumber, Pulse, OptionQuery>;
 
     #[pallet::storage]
-    pub(super) type LastStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+    pub type LastStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// The oldest round that has not been pruned from `Pulses`.
+    #[pallet::storage]
+    pub type OldestStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// The first and last rounds left in `Pulses` by a beacon replaced with `set_beacon_config`,
+    /// pruned before the rounds of the current beacon.
+    #[pallet::storage]
+    pub type StalePulseRounds<T: Config> = StorageValue<_, (RoundNumber, RoundNumber), OptionQuery>;
+
+    /// The number of references to each round still needed outside this pallet, e.g. by
+    /// pending timelock commits, which keep its pulse from being pruned.
+    #[pallet::storage]
+    pub type RoundReferences<T: Config> = StorageMap<_, Twox64Concat, RoundNumber, u32, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultRetentionRounds<T: Config>() -> RoundNumber {
+        T::DefaultRetentionRounds::get()
+    }
+
+    /// The number of most recent rounds kept in `Pulses`.
+    #[pallet::storage]
+    pub type RetentionRounds<T: Config> =
+        StorageValue<_, RoundNumber, ValueQuery, DefaultRetentionRounds<T>>;
+
+    /// The id of the next registered timelock.
+    #[pallet::storage]
+    pub type NextTimelockId<T: Config> = StorageValue<_, TimelockId, ValueQuery>;
+
+    /// Timelocks waiting for the pulse of their reveal round.
+    #[pallet::storage]
+    pub type Timelocks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        TimelockId,
+        PendingTimelock<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// The number of entries in `Timelocks`.
+    #[pallet::storage]
+    pub type PendingTimelockCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Pending timelocks by the round they are revealed at.
+    #[pallet::storage]
+    pub type TimelockQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RoundNumber,
+        BoundedVec<TimelockId, T::MaxTimelocksPerRound>,
+        ValueQuery,
+    >;
+
+    /// The next round whose queued timelocks are revealed, zero before the first registration.
+    #[pallet::storage]
+    pub type TimelockCursor<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// Revealed timelocks that were registered to keep their outcome.
+    #[pallet::storage]
+    pub type RevealedTimelocks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        TimelockId,
+        RevealedTimelock<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Revealed timelocks by the block they are removed at.
+    #[pallet::storage]
+    pub type RevealedTimelockExpiry<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<TimelockId, T::MaxTimelockRevealsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The pending and revealed timelocks an account submitted.
+    #[pallet::storage]
+    pub type TimelocksOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<TimelockId, T::MaxTimelocksPerAccount>,
+        ValueQuery,
+    >;
 
     /// Defines the block when next unsigned transaction will be accepted.
     ///
@@ -233,10 +380,37 @@ pub mod pallet {
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         BeaconConfigChanged,
+        /// The http endpoints serving the beacon were changed.
+        BeaconEndpointsChanged,
         /// Successfully set a new pulse(s).
         NewPulse {
             rounds: Vec<RoundNumber>,
         },
+        /// A timelock was registered to be revealed at `reveal_round`.
+        TimelockRegistered {
+            id: TimelockId,
+            owner: T::AccountId,
+            reveal_round: RoundNumber,
+        },
+        /// A timelock was decrypted.
+        TimelockRevealed {
+            id: TimelockId,
+            owner: T::AccountId,
+        },
+        /// A timelock could not be decrypted at its reveal round.
+        TimelockFailed {
+            id: TimelockId,
+            owner: T::AccountId,
+            error: TimelockError,
+        },
+        /// A timelock was removed by its owner.
+        TimelockRemoved {
+            id: TimelockId,
+        },
+        /// A revealed timelock was removed after `RevealedTimelockLifetime` blocks.
+        TimelockExpired {
+            id: TimelockId,
+        },
     }
 
     #[pallet::error]
@@ -253,10 +427,30 @@ pub mod pallet {
         InvalidRoundNumber,
         /// the pulse could not be verified
         PulseVerificationError,
+        /// the retention must keep at least one round
+        InvalidRetention,
+        /// at least one beacon endpoint is required
+        NoBeaconEndpoints,
+        /// the round already has the maximum number of queued timelocks
+        TooManyTimelocksInRound,
+        /// the account already holds the maximum number of timelocks
+        TooManyTimelocks,
+        /// the timelock does not exist
+        TimelockNotFound,
+        /// only the owner of a timelock can remove it
+        NotTimelockOwner,
+        /// the maximum number of timelocks are waiting to be revealed
+        TooManyPendingTimelocks,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            Self::process_timelocks()
+                .saturating_add(Self::expire_revealed_timelocks(block_number))
+                .saturating_add(Self::prune_pulses())
+        }
+
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             log::debug!("Drand OCW working on block: {:?}", block_number);
             if let Err(e) = Self::fetch_drand_pulse_and_send_unsigned(block_number) {
@@ -324,10 +518,31 @@ pub mod pallet {
 
             let mut last_stored_round = LastStoredRound::<T>::get();
             let mut new_rounds = Vec::new();
+            let tracking_started = OldestStoredRound::<T>::get() > 0;
+            // chained beacons sign over the signature of the previous round
+            let mut previous_signature = pulses_payload
+                .previous_signature
+                .clone()
+                .map(|signature| signature.into_inner())
+                .unwrap_or_default();
+
+            // Several pulses are verified at once, and are rejected together if any is invalid
+            let batch_verified = pulses_payload.pulses.len() > 1;
+            if batch_verified {
+                let is_verified = T::Verifier::verify_batch(
+                    config.clone(),
+                    &pulses_payload.pulses,
+                    &previous_signature,
+                )
+                .map_err(|_| Error::<T>::PulseVerificationError)?;
+                ensure!(is_verified, Error::<T>::PulseVerificationError);
+            }
 
             for pulse in &pulses_payload.pulses {
-                let is_verified = T::Verifier::verify(config.clone(), pulse.clone())
-                    .map_err(|_| Error::<T>::PulseVerificationError)?;
+                let is_verified = batch_verified
+                    || T::Verifier::verify(config.clone(), pulse.clone(), &previous_signature)
+                        .map_err(|_| Error::<T>::PulseVerificationError)?;
+                previous_signature = pulse.signature.clone().into_inner();
 
                 if is_verified {
                     ensure!(
@@ -349,6 +564,13 @@ pub mod pallet {
             // Update LastStoredRound storage
             LastStoredRound::<T>::put(last_stored_round);
 
+            // Start pruning from the first round ever stored
+            if !tracking_started {
+                if let Some(first_round) = new_rounds.first() {
+                    OldestStoredRound::<T>::put(first_round);
+                }
+            }
+
             // Update the next unsigned block number
             let current_block = frame_system::Pallet::<T>::block_number();
             <NextUnsignedAt<T>>::put(current_block.saturating_add(One::one()));
@@ -376,6 +598,22 @@ pub mod pallet {
             ensure_root(origin)?;
             BeaconConfig::<T>::put(config_payload.config);
 
+            // The rounds of the new beacon restart from its latest pulse, and the pulses of the
+            // previous beacon are pruned in the following blocks.
+            let last_stored_round = LastStoredRound::<T>::take();
+            let oldest_stored_round = OldestStoredRound::<T>::take();
+            if last_stored_round > 0 {
+                let first_round = match oldest_stored_round {
+                    0 => last_stored_round,
+                    oldest_stored_round => oldest_stored_round,
+                };
+                let stale_rounds = match StalePulseRounds::<T>::get() {
+                    Some((first, last)) => (first.min(first_round), last.max(last_stored_round)),
+                    None => (first_round, last_stored_round),
+                };
+                StalePulseRounds::<T>::put(stale_rounds);
+            }
+
             // now increment the block number at which we expect next unsigned transaction.
             let current_block = frame_system::Pallet::<T>::block_number();
             <NextUnsignedAt<T>>::put(current_block.saturating_add(One::one()));
@@ -383,10 +621,389 @@ pub mod pallet {
             Self::deposit_event(Event::BeaconConfigChanged {});
             Ok(())
         }
+
+        /// allows the root user to set how many of the most recent rounds are kept
+        ///
+        /// * `origin`: the root user
+        /// * `rounds`: the number of rounds to keep, at least one
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_retention_rounds())]
+        pub fn set_retention_rounds(origin: OriginFor<T>, rounds: RoundNumber) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(rounds > 0, Error::<T>::InvalidRetention);
+            RetentionRounds::<T>::put(rounds);
+            Ok(())
+        }
+
+        /// allows the root user to set the http endpoints serving the beacon, e.g. when switching
+        /// to another drand chain with `set_beacon_config`
+        ///
+        /// * `origin`: the root user
+        /// * `endpoints`: the base urls of the drand http api, queried concurrently
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_beacon_endpoints())]
+        pub fn set_beacon_endpoints(
+            origin: OriginFor<T>,
+            endpoints: BeaconEndpoints,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(!endpoints.is_empty(), Error::<T>::NoBeaconEndpoints);
+            BeaconEndpointList::<T>::put(endpoints);
+
+            Self::deposit_event(Event::BeaconEndpointsChanged);
+            Ok(())
+        }
+
+        /// submit a ciphertext timelock encrypted to a quicknet round, revealed on chain once
+        /// the pulse of that round is stored
+        ///
+        /// * `origin`: the owner of the timelock
+        /// * `ciphertext`: a serialized `TLECiphertext<TinyBLS381>`
+        /// * `reveal_round`: the round the ciphertext was encrypted to
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::submit_timelock())]
+        pub fn submit_timelock(
+            origin: OriginFor<T>,
+            ciphertext: TimelockCiphertext,
+            reveal_round: RoundNumber,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let deposit = T::TimelockDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            let id = Self::register_timelock(who.clone(), ciphertext, reveal_round, true, deposit)?;
+            TimelocksOf::<T>::try_mutate(&who, |ids| ids.try_push(id))
+                .map_err(|_| Error::<T>::TooManyTimelocks)?;
+            Ok(())
+        }
+
+        /// remove a submitted timelock, pending or revealed
+        ///
+        /// * `origin`: the owner of the timelock
+        /// * `id`: the timelock to remove
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_timelock())]
+        pub fn remove_timelock(origin: OriginFor<T>, id: TimelockId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let deposit = if let Some(pending) = Timelocks::<T>::get(id) {
+                ensure!(pending.owner == who, Error::<T>::NotTimelockOwner);
+                Timelocks::<T>::remove(id);
+                PendingTimelockCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                TimelockQueue::<T>::mutate(pending.queued_round, |ids| {
+                    ids.retain(|queued| *queued != id)
+                });
+                pending.deposit
+            } else {
+                let revealed =
+                    RevealedTimelocks::<T>::get(id).ok_or(Error::<T>::TimelockNotFound)?;
+                ensure!(revealed.owner == who, Error::<T>::NotTimelockOwner);
+                RevealedTimelocks::<T>::remove(id);
+                revealed.deposit
+            };
+
+            T::Currency::unreserve(&who, deposit);
+            TimelocksOf::<T>::mutate(&who, |ids| ids.retain(|owned| *owned != id));
+            Self::deposit_event(Event::TimelockRemoved { id });
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Keep the pulse of `round` from being pruned until the reference is released. Rounds
+    /// that were already pruned are not tracked.
+    pub fn reference_round(round: RoundNumber) -> Weight {
+        let db_weight = T::DbWeight::get();
+        if round < OldestStoredRound::<T>::get() {
+            return db_weight.reads(1);
+        }
+        RoundReferences::<T>::mutate(round, |count| *count = count.saturating_add(1));
+        db_weight.reads_writes(2, 1)
+    }
+
+    /// Release a reference taken with `reference_round`.
+    pub fn release_round(round: RoundNumber) -> Weight {
+        RoundReferences::<T>::mutate_exists(round, |count| {
+            *count = count
+                .map(|count| count.saturating_sub(1))
+                .filter(|count| *count > 0);
+        });
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Remove the pulses older than the retention window, oldest first, stopping at the oldest
+    /// round still referenced and after `MaxPrunedPulsesPerBlock` rounds. References to rounds
+    /// more than `MaxReferenceAge` rounds old are expired.
+    pub fn prune_pulses() -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut weight = db_weight.reads(5);
+
+        let last_stored_round = LastStoredRound::<T>::get();
+        let oldest_stored_round = OldestStoredRound::<T>::get();
+        if let Some((first_round, last_round)) = StalePulseRounds::<T>::get() {
+            return weight.saturating_add(Self::prune_stale_pulses(
+                first_round,
+                last_round,
+                oldest_stored_round,
+                last_stored_round,
+            ));
+        }
+        if last_stored_round == 0 || oldest_stored_round == 0 {
+            return weight;
+        }
+
+        // Keep the last `RetentionRounds` rounds, up to and including the last stored one.
+        let mut prune_before = last_stored_round
+            .saturating_sub(RetentionRounds::<T>::get())
+            .saturating_add(1);
+
+        // Queued timelocks are decrypted with the pulses from the cursor onwards.
+        let timelock_cursor = TimelockCursor::<T>::get();
+        if timelock_cursor > 0 {
+            prune_before = prune_before.min(timelock_cursor);
+        }
+
+        let prune_until = prune_before
+            .min(oldest_stored_round.saturating_add(T::MaxPrunedPulsesPerBlock::get().into()));
+        let expired_before = last_stored_round.saturating_sub(T::MaxReferenceAge::get());
+
+        let mut round = oldest_stored_round;
+        while round < prune_until {
+            weight = weight.saturating_add(db_weight.reads(1));
+            if RoundReferences::<T>::contains_key(round) {
+                if round >= expired_before {
+                    break;
+                }
+                RoundReferences::<T>::remove(round);
+                weight = weight.saturating_add(db_weight.writes(1));
+            }
+            Pulses::<T>::remove(round);
+            weight = weight.saturating_add(db_weight.writes(1));
+            round = round.saturating_add(1);
+        }
+
+        if round > oldest_stored_round {
+            OldestStoredRound::<T>::put(round);
+            weight = weight.saturating_add(db_weight.writes(1));
+        }
+        weight
+    }
+
+    /// Remove up to `MaxPrunedPulsesPerBlock` pulses of a replaced beacon, from `first_round`
+    /// to `last_round`, keeping the rounds stored by the current beacon since.
+    fn prune_stale_pulses(
+        first_round: RoundNumber,
+        last_round: RoundNumber,
+        oldest_stored_round: RoundNumber,
+        last_stored_round: RoundNumber,
+    ) -> Weight {
+        let prune_until = last_round.min(
+            first_round
+                .saturating_add(T::MaxPrunedPulsesPerBlock::get().into())
+                .saturating_sub(1),
+        );
+        for round in first_round..=prune_until {
+            let is_current = oldest_stored_round > 0
+                && (oldest_stored_round..=last_stored_round).contains(&round);
+            if !is_current {
+                Pulses::<T>::remove(round);
+            }
+        }
+
+        if prune_until >= last_round {
+            StalePulseRounds::<T>::kill();
+        } else {
+            StalePulseRounds::<T>::put((prune_until.saturating_add(1), last_round));
+        }
+        T::DbWeight::get().writes(prune_until.saturating_sub(first_round).saturating_add(2))
+    }
+
+    /// Register a ciphertext to be decrypted once the pulse of `reveal_round` is stored. The
+    /// outcome is passed to `T::OnTimelockRevealed` and, with `keep_reveal`, stored in
+    /// `RevealedTimelocks` until the owner removes it or it expires. The `deposit` reserved
+    /// from the owner is returned once the timelock is no longer stored.
+    ///
+    /// Timelocked commitments and CRv3 weight commits are still revealed by their own pallets.
+    pub fn register_timelock(
+        owner: T::AccountId,
+        ciphertext: TimelockCiphertext,
+        reveal_round: RoundNumber,
+        keep_reveal: bool,
+        deposit: BalanceOf<T>,
+    ) -> Result<TimelockId, DispatchError> {
+        let pending_count = PendingTimelockCount::<T>::get();
+        ensure!(
+            pending_count < T::MaxPendingTimelocks::get(),
+            Error::<T>::TooManyPendingTimelocks
+        );
+
+        let mut cursor = TimelockCursor::<T>::get();
+        if cursor == 0 {
+            cursor = match LastStoredRound::<T>::get() {
+                0 => reveal_round,
+                last_stored_round => last_stored_round,
+            };
+            TimelockCursor::<T>::put(cursor);
+        }
+
+        // Rounds before the cursor were already processed, so late timelocks go to the cursor.
+        let queued_round = reveal_round.max(cursor);
+        let id = NextTimelockId::<T>::get();
+        TimelockQueue::<T>::try_mutate(queued_round, |ids| ids.try_push(id))
+            .map_err(|_| Error::<T>::TooManyTimelocksInRound)?;
+        NextTimelockId::<T>::put(id.saturating_add(1));
+
+        Timelocks::<T>::insert(
+            id,
+            PendingTimelock {
+                owner: owner.clone(),
+                ciphertext,
+                reveal_round,
+                queued_round,
+                keep_reveal,
+                deposit,
+            },
+        );
+        PendingTimelockCount::<T>::put(pending_count.saturating_add(1));
+        Self::deposit_event(Event::TimelockRegistered {
+            id,
+            owner,
+            reveal_round,
+        });
+        Ok(id)
+    }
+
+    /// Decrypt a serialized `TLECiphertext<TinyBLS381>` with the stored pulse of `reveal_round`.
+    pub fn decrypt_timelock(
+        ciphertext: &[u8],
+        reveal_round: RoundNumber,
+    ) -> Result<Vec<u8>, TimelockError> {
+        let pulse = Pulses::<T>::get(reveal_round).ok_or(TimelockError::MissingPulse)?;
+        timelock::decrypt(ciphertext, &pulse.signature)
+    }
+
+    /// Reveal the timelocks queued from the cursor up to the last stored round, oldest first,
+    /// stopping after `MaxTimelockRevealsPerBlock` reveals or `MAX_PULSES_TO_FETCH` rounds.
+    pub fn process_timelocks() -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut weight = db_weight.reads(1);
+
+        let mut cursor = TimelockCursor::<T>::get();
+        if cursor == 0 {
+            return weight;
+        }
+
+        let last_stored_round = LastStoredRound::<T>::get();
+        let last_round = last_stored_round.min(cursor.saturating_add(MAX_PULSES_TO_FETCH));
+        let mut budget = T::MaxTimelockRevealsPerBlock::get();
+        weight = weight.saturating_add(db_weight.reads(1));
+
+        while cursor <= last_round && budget > 0 {
+            let mut ids = TimelockQueue::<T>::take(cursor).into_inner();
+            weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+
+            let remaining = ids.split_off(ids.len().min(budget as usize));
+            for id in ids {
+                budget = budget.saturating_sub(1);
+                weight = weight.saturating_add(Self::reveal_timelock(id));
+            }
+
+            if !remaining.is_empty() {
+                // The budget ran out, the rest of this round is revealed in the next block.
+                TimelockQueue::<T>::insert(cursor, BoundedVec::truncate_from(remaining));
+                break;
+            }
+            cursor = cursor.saturating_add(1);
+        }
+
+        TimelockCursor::<T>::put(cursor);
+        weight.saturating_add(db_weight.writes(1))
+    }
+
+    /// Decrypt a pending timelock, report the outcome and keep it if requested.
+    fn reveal_timelock(id: TimelockId) -> Weight {
+        let Some(pending) = Timelocks::<T>::take(id) else {
+            return T::DbWeight::get().reads(1);
+        };
+        PendingTimelockCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+        let outcome = Self::decrypt_timelock(&pending.ciphertext, pending.reveal_round)
+            .and_then(|payload| {
+                TimelockPayload::try_from(payload).map_err(|_| TimelockError::DecryptionFailed)
+            })
+            .map_or_else(TimelockOutcome::Failed, TimelockOutcome::Revealed);
+
+        match &outcome {
+            TimelockOutcome::Revealed(_) => Self::deposit_event(Event::TimelockRevealed {
+                id,
+                owner: pending.owner.clone(),
+            }),
+            TimelockOutcome::Failed(error) => {
+                log::warn!(
+                    "Drand: Failed to reveal timelock {} for round {}: {:?}",
+                    id,
+                    pending.reveal_round,
+                    error
+                );
+                Self::deposit_event(Event::TimelockFailed {
+                    id,
+                    owner: pending.owner.clone(),
+                    error: *error,
+                });
+            }
+        }
+
+        let handler_weight =
+            T::OnTimelockRevealed::on_timelock_revealed(id, &pending.owner, &outcome);
+        if pending.keep_reveal {
+            let revealed_at = frame_system::Pallet::<T>::block_number();
+            let expires_at = revealed_at.saturating_add(T::RevealedTimelockLifetime::get());
+            if RevealedTimelockExpiry::<T>::try_mutate(expires_at, |ids| ids.try_push(id)).is_ok() {
+                RevealedTimelocks::<T>::insert(
+                    id,
+                    RevealedTimelock {
+                        owner: pending.owner,
+                        reveal_round: pending.reveal_round,
+                        revealed_at,
+                        outcome,
+                        deposit: pending.deposit,
+                    },
+                );
+            } else {
+                // Only reachable when more than `MaxTimelockRevealsPerBlock` are revealed.
+                Self::forget_timelock(id, &pending.owner, pending.deposit);
+            }
+        } else {
+            T::Currency::unreserve(&pending.owner, pending.deposit);
+        }
+
+        T::WeightInfo::reveal_timelock().saturating_add(handler_weight)
+    }
+
+    /// Remove the revealed timelocks that expire at `block_number` and return their deposits.
+    pub fn expire_revealed_timelocks(block_number: BlockNumberFor<T>) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut weight = db_weight.reads_writes(1, 1);
+
+        for id in RevealedTimelockExpiry::<T>::take(block_number) {
+            weight = weight.saturating_add(db_weight.reads(1));
+            // Removed by its owner in the meantime
+            let Some(revealed) = RevealedTimelocks::<T>::take(id) else {
+                continue;
+            };
+            Self::forget_timelock(id, &revealed.owner, revealed.deposit);
+            Self::deposit_event(Event::TimelockExpired { id });
+            weight = weight.saturating_add(db_weight.reads_writes(2, 3));
+        }
+        weight
+    }
+
+    /// Return the deposit of a timelock that is no longer stored and drop it from its owner's.
+    fn forget_timelock(id: TimelockId, owner: &T::AccountId, deposit: BalanceOf<T>) {
+        T::Currency::unreserve(owner, deposit);
+        TimelocksOf::<T>::mutate(owner, |ids| ids.retain(|owned| *owned != id));
+    }
+
     /// fetch the latest public pulse from the configured drand beacon
     /// then send a signed transaction to include it on-chain
     fn fetch_drand_pulse_and_send_unsigned(
@@ -418,6 +1035,7 @@ impl<T: Config> Pallet<T> {
                 MAX_PULSES_TO_FETCH,
             );
             let mut pulses = Vec::new();
+            let mut previous_signature = None;
 
             for round in (last_stored_round.saturating_add(1))
                 ..=(last_stored_round.saturating_add(rounds_to_fetch))
@@ -427,6 +1045,11 @@ impl<T: Config> Pallet<T> {
                 let pulse = unbounded_pulse
                     .try_into_pulse()
                     .map_err(|_| "Drand: Received pulse contains invalid data")?;
+                if pulses.is_empty() {
+                    previous_signature = unbounded_pulse
+                        .try_into_previous_signature()
+                        .map_err(|_| "Drand: Received pulse contains invalid data")?;
+                }
                 pulses.push(pulse);
             }
 
@@ -436,6 +1059,7 @@ impl<T: Config> Pallet<T> {
                 |account| PulsesPayload {
                     block_number,
                     pulses: pulses.clone(),
+                    previous_signature: previous_signature.clone(),
                     public: account.public.clone(),
                 },
                 |pulses_payload, signature| Call::write_pulse {
@@ -464,20 +1088,42 @@ impl<T: Config> Pallet<T> {
     }
 
     fn fetch_drand_by_round(round: RoundNumber) -> Result<DrandResponseBody, &'static str> {
-        let relative_path = format!("/{}/public/{}", CHAIN_HASH, round);
+        let relative_path = format!("/{}/public/{}", Self::chain_hash(), round);
         Self::fetch_and_decode_from_any_endpoint(&relative_path)
     }
 
     fn fetch_drand_latest() -> Result<DrandResponseBody, &'static str> {
-        let relative_path = format!("/{}/public/latest", CHAIN_HASH);
+        let relative_path = format!("/{}/public/latest", Self::chain_hash());
         Self::fetch_and_decode_from_any_endpoint(&relative_path)
     }
 
+    /// the hex encoded hash of the configured beacon chain
+    fn chain_hash() -> String {
+        hex::encode(BeaconConfig::<T>::get().hash)
+    }
+
+    /// the endpoints to query: the node-local relay if one is set, else the on-chain endpoints
+    fn beacon_endpoints() -> Vec<String> {
+        if let Some(relay) = sp_io::offchain::local_storage_get(
+            sp_core::offchain::StorageKind::PERSISTENT,
+            RELAY_ENDPOINT_KEY,
+        ) {
+            if !relay.is_empty() {
+                return vec![String::from_utf8_lossy(&relay).into_owned()];
+            }
+        }
+
+        BeaconEndpointList::<T>::get()
+            .iter()
+            .map(|endpoint| String::from_utf8_lossy(endpoint).into_owned())
+            .collect()
+    }
+
     /// Try to fetch from multiple endpoints simultaneously and return the first successfully decoded JSON response.
     fn fetch_and_decode_from_any_endpoint(
         relative_path: &str,
     ) -> Result<DrandResponseBody, &'static str> {
-        let uris: Vec<String> = ENDPOINTS
+        let uris: Vec<String> = Self::beacon_endpoints()
             .iter()
             .map(|e| format!("{}{}", e, relative_path))
             .collect();
@@ -569,11 +1215,22 @@ impl<T: Config> Pallet<T> {
     /// get the randomness at a specific block height
     /// returns [0u8;32] if it does not exist
     pub fn random_at(round: RoundNumber) -> [u8; 32] {
-        let pulse = Pulses::<T>::get(round).unwrap_or_default();
-        let rand = pulse.randomness.clone();
-        let bounded_rand: [u8; 32] = rand.into_inner().try_into().unwrap_or([0u8; 32]);
+        Pulses::<T>::get(round)
+            .map(|pulse| pulse.verified_randomness())
+            .unwrap_or([0u8; 32])
+    }
+
+    /// The round and randomness of `round`, or of the last stored round if `None`, if its pulse
+    /// is still stored.
+    pub fn randomness_of(round: Option<RoundNumber>) -> Option<(RoundNumber, [u8; 32])> {
+        let pulse = Self::pulse_of(round)?;
+        Some((pulse.round, pulse.verified_randomness()))
+    }
 
-        bounded_rand
+    /// The pulse of `round`, or of the last stored round if `None`, e.g. to verify its randomness
+    /// off-chain against the public key in `BeaconConfig`.
+    pub fn pulse_of(round: Option<RoundNumber>) -> Option<Pulse> {
+        Pulses::<T>::get(round.unwrap_or_else(LastStoredRound::<T>::get))
     }
 
     fn validate_signature_and_parameters(
@@ -650,7 +1307,7 @@ impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
 
         let mut entropy = T::Hash::default();
         if let Some(pulse) = Pulses::<T>::get(last_stored_round) {
-            entropy = (subject, block_number_minus_one, pulse.randomness.clone())
+            entropy = (subject, block_number_minus_one, pulse.verified_randomness())
                 .using_encoded(T::Hashing::hash);
         }
 
@@ -21,6 +21,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system = 1,
         Drand: pallet_drand_bridge = 2,
+        Balances: pallet_balances = 3,
     }
 );
 
@@ -42,7 +43,7 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -51,6 +52,13 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u64;
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+}
+
 type Extrinsic = TestXt<RuntimeCall, ()>;
 type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
 
@@ -83,6 +91,19 @@ where
 
 parameter_types! {
     pub const UnsignedPriority: u64 = 1 << 20;
+    pub static TimelockOutcomes: Vec<(TimelockId, TimelockOutcome)> = Vec::new();
+}
+
+pub struct TestTimelockHandler;
+impl<AccountId> OnTimelockRevealed<AccountId> for TestTimelockHandler {
+    fn on_timelock_revealed(
+        id: TimelockId,
+        _owner: &AccountId,
+        outcome: &TimelockOutcome,
+    ) -> frame_support::weights::Weight {
+        TimelockOutcomes::mutate(|outcomes| outcomes.push((id, outcome.clone())));
+        frame_support::weights::Weight::zero()
+    }
 }
 
 impl pallet_drand_bridge::Config for Test {
@@ -92,13 +113,32 @@ impl pallet_drand_bridge::Config for Test {
     type Verifier = QuicknetVerifier;
     type UnsignedPriority = UnsignedPriority;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type DefaultRetentionRounds = ConstU64<10>;
+    type MaxPrunedPulsesPerBlock = frame_support::traits::ConstU32<5>;
+    type MaxReferenceAge = ConstU64<20>;
+    type OnTimelockRevealed = TestTimelockHandler;
+    type MaxTimelocksPerRound = frame_support::traits::ConstU32<4>;
+    type MaxTimelockRevealsPerBlock = frame_support::traits::ConstU32<2>;
+    type MaxTimelocksPerAccount = frame_support::traits::ConstU32<3>;
+    type MaxPendingTimelocks = frame_support::traits::ConstU32<6>;
+    type RevealedTimelockLifetime = ConstU64<5>;
+    type Currency = Balances;
+    type TimelockDeposit = ConstU64<10>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (sp_keyring::Sr25519Keyring::Alice.public(), 100),
+            (sp_keyring::Sr25519Keyring::Bob.public(), 100),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
 
     let mut ext = sp_io::TestExternalities::new(t);
     let keystore = MemoryKeystore::new();
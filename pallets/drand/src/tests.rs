@@ -15,18 +15,29 @@
  */
 
 use crate::{
-    BeaconConfig, BeaconConfigurationPayload, BeaconInfoResponse, Call, DrandResponseBody,
-    ENDPOINTS, Error, Pulse, Pulses, PulsesPayload, QUICKNET_CHAIN_HASH, mock::*,
+    BeaconConfig, BeaconConfiguration, BeaconConfigurationPayload, BeaconEndpoint,
+    BeaconEndpointList, BeaconEndpoints, BeaconInfoResponse, BoundedHash, Call, DrandResponseBody,
+    ENDPOINTS, Error, Event, LastStoredRound, OldestStoredRound, OpaquePublicKey, OpaqueSignature,
+    PendingTimelockCount, Pulse, Pulses, PulsesPayload, QUICKNET_CHAIN_HASH, RELAY_ENDPOINT_KEY,
+    RetentionRounds, RevealedTimelockExpiry, RevealedTimelocks, RoundReferences, StalePulseRounds,
+    TimelockCursor, TimelockQueue, Timelocks, TimelocksOf,
+    mock::*,
+    timelock::{TimelockCiphertext, TimelockError, TimelockOutcome, TimelockPayload},
+    utils::{
+        make_chained_beacon, make_chained_pulse, make_quicknet_beacon, make_quicknet_pulse,
+        make_timelock_ciphertext,
+    },
+    verifier::{CHAINED_SCHEME_ID, ChainedVerifier, QuicknetVerifier, SchemeVerifier, Verifier},
 };
 use codec::Encode;
 use frame_support::{
-    assert_noop, assert_ok,
+    BoundedVec, assert_noop, assert_ok,
     pallet_prelude::{InvalidTransaction, TransactionSource},
 };
 use frame_system::RawOrigin;
 use sp_runtime::{
     offchain::{
-        OffchainWorkerExt,
+        OffchainDbExt, OffchainWorkerExt, StorageKind,
         testing::{PendingRequest, TestOffchainExt},
     },
     traits::ValidateUnsigned,
@@ -38,6 +49,13 @@ pub const ROUND_NUMBER: u64 = 1000;
 // Quicknet parameters
 pub const DRAND_PULSE: &str = "{\"round\":1000,\"randomness\":\"fe290beca10872ef2fb164d2aa4442de4566183ec51c56ff3cd603d930e54fdd\",\"signature\":\"b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39\"}";
 pub const DRAND_INFO_RESPONSE: &str = "{\"public_key\":\"83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a\",\"period\":3,\"genesis_time\":1692803367,\"hash\":\"52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971\",\"groupHash\":\"f477d5c89f21a17c863a7f937c6a6d15859414d2be09cd448d4279af331c5d3e\",\"schemeID\":\"bls-unchained-g1-rfc9380\",\"metadata\":{\"beaconID\":\"quicknet\"}}";
+// Round 72785 of drand's default chained network
+const DEFAULT_NETWORK_PUBLIC_KEY: &str = "868f005eb8e6e4ca0a47c8a77ceaa5309a47978a7c71bc5cce96366b5d7a569937c529eeda66c7293784a9402801af31";
+const DEFAULT_NETWORK_ROUND: u64 = 72785;
+const DEFAULT_NETWORK_PREVIOUS_SIGNATURE: &str = "a609e19a03c2fcc559e8dae14900aaefe517cb55c840f6e69bc8e4f66c8d18e8a609685d9917efbfb0c37f058c2de88f13d297c7e19e0ab24813079efe57a182554ff054c7638153f9b26a60e7111f71a0ff63d9571704905d3ca6df0b031747";
+const DEFAULT_NETWORK_SIGNATURE: &str = "82f5d3d2de4db19d40a6980e8aa37842a0e55d1df06bd68bddc8d60002e8e959eb9cfa368b3c1b77d18f02a54fe047b80f0989315f83b12a74fd8679c4f12aae86eaf6ab5690b34f1fddd50ee3cc6f6cdf59e95526d5a5d82aaa84fa6f181e42";
+const DEFAULT_NETWORK_RANDOMNESS: &str =
+    "8b676484b5fb1f37f9ec5c413d7d29883504e5b669f604a1ce68b3388e9ae3d9";
 const INVALID_JSON: &str = r#"{"round":1000,"randomness":"not base64??","signature":}"#;
 
 #[test]
@@ -69,6 +87,7 @@ fn it_can_submit_valid_pulse_when_beacon_config_exists() {
 
         let pulses_payload = PulsesPayload {
             pulses: vec![p.clone()],
+            previous_signature: None,
             block_number,
             public: alice.public(),
         };
@@ -84,6 +103,7 @@ fn it_can_submit_valid_pulse_when_beacon_config_exists() {
         let pulse = Pulses::<Test>::get(ROUND_NUMBER);
         assert!(pulse.is_some());
         assert_eq!(pulse, Some(p));
+        assert_eq!(OldestStoredRound::<Test>::get(), ROUND_NUMBER);
     });
 }
 
@@ -117,6 +137,7 @@ fn it_rejects_invalid_pulse_due_to_bad_signature() {
         // Prepare the pulses payload
         let pulses_payload = PulsesPayload {
             pulses: vec![p.clone()],
+            previous_signature: None,
             block_number,
             public: alice.public(),
         };
@@ -163,6 +184,7 @@ fn it_rejects_pulses_with_non_incremental_round_numbers() {
         let p: Pulse = u_p.try_into_pulse().unwrap();
         let pulses_payload = PulsesPayload {
             pulses: vec![p.clone()],
+            previous_signature: None,
             block_number,
             public: alice.public(),
         };
@@ -274,6 +296,7 @@ fn test_validate_unsigned_write_pulse() {
         let pulses_payload = PulsesPayload {
             block_number,
             pulses: vec![],
+            previous_signature: None,
             public: alice.public(),
         };
         let signature = alice.sign(&pulses_payload.encode());
@@ -299,6 +322,7 @@ fn test_not_validate_unsigned_write_pulse_with_bad_proof() {
         let pulses_payload = PulsesPayload {
             block_number,
             pulses: vec![],
+            previous_signature: None,
             public: alice.public(),
         };
 
@@ -325,6 +349,7 @@ fn test_not_validate_unsigned_write_pulse_with_no_payload_signature() {
         let pulses_payload = PulsesPayload {
             block_number,
             pulses: vec![],
+            previous_signature: None,
             public: alice.public(),
         };
 
@@ -350,6 +375,7 @@ fn can_execute_and_handle_valid_http_responses() {
 
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -395,6 +421,7 @@ fn validate_unsigned_rejects_future_block_number() {
         let pulses_payload = PulsesPayload {
             block_number: future_block_number,
             pulses: vec![],
+            previous_signature: None,
             public: alice.public(),
         };
         let signature = alice.sign(&pulses_payload.encode());
@@ -415,6 +442,7 @@ fn validate_unsigned_rejects_future_block_number() {
 fn test_all_endpoints_fail() {
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -447,6 +475,7 @@ fn test_eventual_success() {
 
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -518,6 +547,7 @@ fn test_invalid_json_then_success() {
 
     let (offchain, state) = TestOffchainExt::new();
     let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
     t.register_extension(OffchainWorkerExt::new(offchain));
 
     {
@@ -549,3 +579,872 @@ fn test_invalid_json_then_success() {
         assert_eq!(actual, expected_pulse);
     });
 }
+
+fn store_pulses(first_round: u64, last_round: u64) {
+    let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+    let p: Pulse = u_p.try_into_pulse().unwrap();
+    for round in first_round..=last_round {
+        Pulses::<Test>::insert(round, Pulse { round, ..p.clone() });
+    }
+    OldestStoredRound::<Test>::put(first_round);
+    LastStoredRound::<Test>::put(last_round);
+}
+
+#[test]
+fn prune_pulses_keeps_the_retention_window() {
+    new_test_ext().execute_with(|| {
+        // 13 rounds stored, 10 retained, up to 5 pruned per block
+        store_pulses(1, 13);
+
+        Drand::prune_pulses();
+        for round in 1..=3 {
+            assert!(Pulses::<Test>::get(round).is_none());
+        }
+        for round in 4..=13 {
+            assert!(Pulses::<Test>::get(round).is_some());
+        }
+        assert_eq!(OldestStoredRound::<Test>::get(), 4);
+
+        // Nothing left to prune
+        Drand::prune_pulses();
+        assert_eq!(OldestStoredRound::<Test>::get(), 4);
+    });
+}
+
+#[test]
+fn prune_pulses_is_capped_per_block() {
+    new_test_ext().execute_with(|| {
+        store_pulses(1, 30);
+
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(5).is_none());
+        assert!(Pulses::<Test>::get(6).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 6);
+
+        Drand::prune_pulses();
+        Drand::prune_pulses();
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(20).is_none());
+        assert!(Pulses::<Test>::get(21).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 21);
+    });
+}
+
+#[test]
+fn prune_pulses_stops_at_referenced_round() {
+    new_test_ext().execute_with(|| {
+        store_pulses(1, 13);
+        Drand::reference_round(2);
+        Drand::reference_round(2);
+
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(1).is_none());
+        assert!(Pulses::<Test>::get(2).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 2);
+
+        // Every reference has to be released
+        Drand::release_round(2);
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(2).is_some());
+
+        Drand::release_round(2);
+        assert!(!RoundReferences::<Test>::contains_key(2));
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(3).is_none());
+        assert_eq!(OldestStoredRound::<Test>::get(), 4);
+    });
+}
+
+#[test]
+fn prune_pulses_ignores_and_expires_stale_references() {
+    new_test_ext().execute_with(|| {
+        store_pulses(5, 17);
+
+        // A round that was already pruned is not tracked and does not hold back pruning
+        Drand::reference_round(3);
+        assert!(!RoundReferences::<Test>::contains_key(3));
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(7).is_none());
+        assert_eq!(OldestStoredRound::<Test>::get(), 8);
+        Drand::release_round(3);
+
+        // A reference that is never released expires after `MaxReferenceAge` rounds
+        Drand::reference_round(8);
+        Drand::prune_pulses();
+        assert_eq!(OldestStoredRound::<Test>::get(), 8);
+
+        store_pulses(8, 28);
+        Drand::prune_pulses();
+        assert_eq!(OldestStoredRound::<Test>::get(), 8);
+
+        store_pulses(8, 29);
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(8).is_none());
+        assert!(!RoundReferences::<Test>::contains_key(8));
+        assert_eq!(OldestStoredRound::<Test>::get(), 13);
+    });
+}
+
+#[test]
+fn prune_pulses_does_nothing_before_tracking_starts() {
+    new_test_ext().execute_with(|| {
+        store_pulses(1, 13);
+        OldestStoredRound::<Test>::kill();
+
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(1).is_some());
+    });
+}
+
+#[test]
+fn set_retention_rounds_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(RetentionRounds::<Test>::get(), 10);
+        assert_noop!(
+            Drand::set_retention_rounds(
+                RuntimeOrigin::signed(sp_keyring::Sr25519Keyring::Alice.public()),
+                5
+            ),
+            sp_runtime::DispatchError::BadOrigin,
+        );
+        assert_noop!(
+            Drand::set_retention_rounds(RuntimeOrigin::root(), 0),
+            Error::<Test>::InvalidRetention,
+        );
+        assert_ok!(Drand::set_retention_rounds(RuntimeOrigin::root(), 5));
+        assert_eq!(RetentionRounds::<Test>::get(), 5);
+
+        store_pulses(1, 13);
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(8).is_none());
+        assert!(Pulses::<Test>::get(9).is_some());
+    });
+}
+
+#[test]
+fn set_beacon_config_restarts_rounds_for_the_new_beacon() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice;
+        let block_number = 100_000_000;
+        System::set_block_number(block_number);
+        store_pulses(1, 13);
+
+        assert_ok!(Drand::set_beacon_config(
+            RuntimeOrigin::root(),
+            BeaconConfigurationPayload {
+                block_number,
+                config: make_quicknet_beacon(1_234_567),
+                public: alice.public(),
+            },
+            None
+        ));
+        assert_eq!(LastStoredRound::<Test>::get(), 0);
+        assert_eq!(OldestStoredRound::<Test>::get(), 0);
+        assert_eq!(StalePulseRounds::<Test>::get(), Some((1, 13)));
+
+        // The new beacon is behind the rounds of the previous one
+        let pulses: Vec<Pulse> = (5..=6)
+            .map(|round| make_quicknet_pulse(1_234_567, round))
+            .collect();
+        assert_ok!(Drand::write_pulse(
+            RuntimeOrigin::none(),
+            PulsesPayload {
+                block_number,
+                pulses: pulses.clone(),
+                previous_signature: None,
+                public: alice.public(),
+            },
+            None
+        ));
+        assert_eq!(OldestStoredRound::<Test>::get(), 5);
+        assert_eq!(LastStoredRound::<Test>::get(), 6);
+
+        // The pulses of the previous beacon are pruned, 5 per block, keeping the new ones
+        Drand::prune_pulses();
+        assert_eq!(StalePulseRounds::<Test>::get(), Some((6, 13)));
+        Drand::prune_pulses();
+        Drand::prune_pulses();
+        assert_eq!(StalePulseRounds::<Test>::get(), None);
+        for round in (1..=4).chain(7..=13) {
+            assert!(Pulses::<Test>::get(round).is_none());
+        }
+        for pulse in pulses {
+            assert_eq!(Pulses::<Test>::get(pulse.round), Some(pulse));
+        }
+        assert_eq!(OldestStoredRound::<Test>::get(), 5);
+    });
+}
+
+#[test]
+fn set_beacon_endpoints_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(BeaconEndpointList::<Test>::get().len(), ENDPOINTS.len());
+
+        let endpoints = BeaconEndpoints::truncate_from(vec![BeaconEndpoint::truncate_from(
+            b"http://127.0.0.1:8080".to_vec(),
+        )]);
+        assert_noop!(
+            Drand::set_beacon_endpoints(
+                RuntimeOrigin::signed(sp_keyring::Sr25519Keyring::Alice.public()),
+                endpoints.clone()
+            ),
+            sp_runtime::DispatchError::BadOrigin,
+        );
+        assert_noop!(
+            Drand::set_beacon_endpoints(RuntimeOrigin::root(), BeaconEndpoints::default()),
+            Error::<Test>::NoBeaconEndpoints,
+        );
+        assert_ok!(Drand::set_beacon_endpoints(
+            RuntimeOrigin::root(),
+            endpoints.clone()
+        ));
+        assert_eq!(BeaconEndpointList::<Test>::get(), endpoints);
+        System::assert_last_event(RuntimeEvent::Drand(Event::BeaconEndpointsChanged));
+    });
+}
+
+#[test]
+fn fetches_from_configured_endpoints_and_chain() {
+    let expected_pulse: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+    let chain_hash = [1u8; 32];
+
+    let (offchain, state) = TestOffchainExt::new();
+    let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
+    t.register_extension(OffchainWorkerExt::new(offchain));
+
+    state.write().expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: format!(
+            "http://beacon.local/{}/public/1000",
+            hex::encode(chain_hash)
+        ),
+        response: Some(DRAND_PULSE.as_bytes().to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+
+    t.execute_with(|| {
+        BeaconConfig::<Test>::mutate(|config| {
+            config.hash = BoundedHash::truncate_from(chain_hash.to_vec());
+        });
+        BeaconEndpointList::<Test>::put(BeaconEndpoints::truncate_from(vec![
+            BeaconEndpoint::truncate_from(b"http://beacon.local".to_vec()),
+        ]));
+
+        let actual = Drand::fetch_drand_by_round(1000u64).unwrap();
+        assert_eq!(actual, expected_pulse);
+    });
+}
+
+#[test]
+fn fetches_from_local_relay_when_set() {
+    let expected_pulse: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+
+    let (offchain, state) = TestOffchainExt::new();
+    let mut t = sp_io::TestExternalities::default();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
+    t.register_extension(OffchainWorkerExt::new(offchain));
+
+    state.write().expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: format!("http://127.0.0.1:8080/{}/public/1000", QUICKNET_CHAIN_HASH),
+        response: Some(DRAND_PULSE.as_bytes().to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+
+    t.execute_with(|| {
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            RELAY_ENDPOINT_KEY,
+            b"http://127.0.0.1:8080",
+        );
+
+        let actual = Drand::fetch_drand_by_round(1000u64).unwrap();
+        assert_eq!(actual, expected_pulse);
+    });
+}
+
+#[test]
+fn scheme_verifier_dispatches_on_scheme_id() {
+    new_test_ext().execute_with(|| {
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let p: Pulse = u_p.try_into_pulse().unwrap();
+        let config = BeaconConfig::<Test>::get();
+
+        assert_eq!(
+            SchemeVerifier::verify(config.clone(), p.clone(), &[]),
+            Ok(true)
+        );
+
+        let unknown = BeaconConfiguration {
+            scheme_id: BoundedHash::truncate_from(b"bls-unknown".to_vec()),
+            ..config
+        };
+        assert!(SchemeVerifier::verify(unknown, p, &[]).is_err());
+    });
+}
+
+#[test]
+fn chained_verifier_checks_previous_signature() {
+    let previous_signature = vec![7u8; 96];
+    let config = make_chained_beacon(1_234_567);
+    let pulse = make_chained_pulse(1_234_567, ROUND_NUMBER, &previous_signature);
+
+    assert_eq!(
+        ChainedVerifier::verify(config.clone(), pulse.clone(), &previous_signature),
+        Ok(true)
+    );
+    assert_eq!(
+        SchemeVerifier::verify(config.clone(), pulse.clone(), &previous_signature),
+        Ok(true)
+    );
+    assert_eq!(
+        ChainedVerifier::verify(config.clone(), pulse.clone(), &[8u8; 96]),
+        Ok(false)
+    );
+    assert!(ChainedVerifier::verify(config, pulse, &[]).is_err());
+}
+
+#[test]
+fn chained_verifier_verifies_default_network_pulse() {
+    let config = BeaconConfiguration {
+        public_key: OpaquePublicKey::truncate_from(
+            hex::decode(DEFAULT_NETWORK_PUBLIC_KEY).unwrap(),
+        ),
+        scheme_id: BoundedHash::truncate_from(CHAINED_SCHEME_ID.to_vec()),
+        ..Default::default()
+    };
+    let previous_signature = hex::decode(DEFAULT_NETWORK_PREVIOUS_SIGNATURE).unwrap();
+    let pulse = Pulse {
+        round: DEFAULT_NETWORK_ROUND,
+        randomness: BoundedVec::truncate_from(hex::decode(DEFAULT_NETWORK_RANDOMNESS).unwrap()),
+        signature: OpaqueSignature::truncate_from(hex::decode(DEFAULT_NETWORK_SIGNATURE).unwrap()),
+    };
+
+    assert_eq!(
+        ChainedVerifier::verify(config.clone(), pulse.clone(), &previous_signature),
+        Ok(true)
+    );
+    assert_eq!(
+        SchemeVerifier::verify_batch(config.clone(), &[pulse.clone()], &previous_signature),
+        Ok(true)
+    );
+    // Another round or previous signature gives another message
+    let next_round = Pulse {
+        round: 72786,
+        ..pulse.clone()
+    };
+    assert_eq!(
+        ChainedVerifier::verify(config.clone(), next_round, &previous_signature),
+        Ok(false)
+    );
+    assert_eq!(
+        ChainedVerifier::verify(config, pulse, &[8u8; 96]),
+        Ok(false)
+    );
+}
+
+#[test]
+fn quicknet_verifier_verifies_batches() {
+    let config = make_quicknet_beacon(1_234_567);
+    let mut pulses: Vec<Pulse> = (1..=5)
+        .map(|round| make_quicknet_pulse(1_234_567, round))
+        .collect();
+
+    assert_eq!(
+        QuicknetVerifier::verify_batch(config.clone(), &pulses, &[]),
+        Ok(true)
+    );
+    assert_eq!(
+        SchemeVerifier::verify_batch(config.clone(), &pulses, &[]),
+        Ok(true)
+    );
+
+    // A pulse signed by another key fails the whole batch
+    if let Some(pulse) = pulses.get_mut(2) {
+        *pulse = make_quicknet_pulse(7_654_321, 3);
+    }
+    assert_eq!(
+        QuicknetVerifier::verify_batch(config, &pulses, &[]),
+        Ok(false)
+    );
+}
+
+#[test]
+fn chained_verifier_verifies_batches() {
+    let config = make_chained_beacon(1_234_567);
+    let first_previous_signature = vec![7u8; 96];
+    let mut previous_signature = first_previous_signature.clone();
+    let mut pulses = Vec::new();
+    for round in 1..=5 {
+        let pulse = make_chained_pulse(1_234_567, round, &previous_signature);
+        previous_signature = pulse.signature.clone().into_inner();
+        pulses.push(pulse);
+    }
+
+    assert_eq!(
+        ChainedVerifier::verify_batch(config.clone(), &pulses, &first_previous_signature),
+        Ok(true)
+    );
+    // The default implementation agrees with the aggregate check
+    assert_eq!(
+        SequentialChainedVerifier::verify_batch(config.clone(), &pulses, &first_previous_signature),
+        Ok(true)
+    );
+    assert_eq!(
+        ChainedVerifier::verify_batch(config.clone(), &pulses, &[8u8; 96]),
+        Ok(false)
+    );
+
+    // Pulses out of order break the chain
+    pulses.swap(1, 2);
+    assert_eq!(
+        ChainedVerifier::verify_batch(config, &pulses, &first_previous_signature),
+        Ok(false)
+    );
+}
+
+/// verifies each pulse in turn with the chained verifier, through the default `verify_batch`
+struct SequentialChainedVerifier;
+impl Verifier for SequentialChainedVerifier {
+    fn verify(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        ChainedVerifier::verify(beacon_config, pulse, previous_signature)
+    }
+}
+
+#[test]
+fn write_pulse_verifies_batches() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice;
+        let block_number = 100_000_000;
+        System::set_block_number(block_number);
+        BeaconConfig::<Test>::put(make_quicknet_beacon(1_234_567));
+
+        let mut pulses: Vec<Pulse> = (1..=3)
+            .map(|round| make_quicknet_pulse(1_234_567, round))
+            .collect();
+
+        // One bad pulse rejects the whole batch
+        if let Some(pulse) = pulses.get_mut(1) {
+            *pulse = make_quicknet_pulse(7_654_321, 2);
+        }
+        let pulses_payload = PulsesPayload {
+            block_number,
+            pulses: pulses.clone(),
+            previous_signature: None,
+            public: alice.public(),
+        };
+        assert_noop!(
+            Drand::write_pulse(RuntimeOrigin::none(), pulses_payload, None),
+            Error::<Test>::PulseVerificationError
+        );
+
+        if let Some(pulse) = pulses.get_mut(1) {
+            *pulse = make_quicknet_pulse(1_234_567, 2);
+        }
+        let pulses_payload = PulsesPayload {
+            block_number,
+            pulses: pulses.clone(),
+            previous_signature: None,
+            public: alice.public(),
+        };
+        assert_ok!(Drand::write_pulse(
+            RuntimeOrigin::none(),
+            pulses_payload,
+            None
+        ));
+        for pulse in pulses {
+            assert_eq!(Pulses::<Test>::get(pulse.round), Some(pulse));
+        }
+        assert_eq!(LastStoredRound::<Test>::get(), 3);
+    });
+}
+
+const TIMELOCK_SECRET: u64 = 1_234_567;
+
+fn timelock_ciphertext(round: u64, plaintext: &[u8]) -> TimelockCiphertext {
+    TimelockCiphertext::truncate_from(make_timelock_ciphertext(TIMELOCK_SECRET, round, plaintext))
+}
+
+fn store_timelock_pulse(round: u64) {
+    Pulses::<Test>::insert(round, make_quicknet_pulse(TIMELOCK_SECRET, round));
+    LastStoredRound::<Test>::put(round);
+}
+
+#[test]
+fn submit_timelock_reveals_at_its_round() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+
+        assert_ok!(Drand::submit_timelock(
+            RuntimeOrigin::signed(alice),
+            timelock_ciphertext(5, b"hello"),
+            5
+        ));
+        System::assert_last_event(
+            Event::TimelockRegistered {
+                id: 0,
+                owner: alice,
+                reveal_round: 5,
+            }
+            .into(),
+        );
+        assert_eq!(TimelocksOf::<Test>::get(alice).into_inner(), vec![0]);
+        assert_eq!(TimelockCursor::<Test>::get(), 5);
+        assert_eq!(Balances::reserved_balance(alice), 10);
+
+        // Nothing is revealed before the pulse of the round is stored
+        store_timelock_pulse(4);
+        Drand::process_timelocks();
+        assert!(Timelocks::<Test>::contains_key(0));
+        assert_eq!(TimelockCursor::<Test>::get(), 5);
+
+        store_timelock_pulse(5);
+        Drand::process_timelocks();
+        assert!(!Timelocks::<Test>::contains_key(0));
+        assert_eq!(TimelockCursor::<Test>::get(), 6);
+        System::assert_last_event(
+            Event::TimelockRevealed {
+                id: 0,
+                owner: alice,
+            }
+            .into(),
+        );
+
+        let expected = TimelockOutcome::Revealed(TimelockPayload::truncate_from(b"hello".to_vec()));
+        let revealed = RevealedTimelocks::<Test>::get(0).unwrap();
+        assert_eq!(revealed.owner, alice);
+        assert_eq!(revealed.reveal_round, 5);
+        assert_eq!(revealed.revealed_at, 1);
+        assert_eq!(revealed.outcome, expected);
+        assert_eq!(revealed.deposit, 10);
+        assert_eq!(TimelockOutcomes::get(), vec![(0, expected)]);
+    });
+}
+
+#[test]
+fn late_timelocks_are_queued_at_the_cursor() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        store_timelock_pulse(8);
+
+        // The cursor starts at the last stored round, which is processed in the next block
+        assert_ok!(Drand::register_timelock(
+            alice,
+            timelock_ciphertext(8, b"now"),
+            8,
+            false,
+            0
+        ));
+        assert_eq!(TimelockCursor::<Test>::get(), 8);
+        Drand::process_timelocks();
+        assert_eq!(TimelockCursor::<Test>::get(), 9);
+
+        // A round that was already processed is revealed with the next one
+        assert_ok!(Drand::register_timelock(
+            alice,
+            timelock_ciphertext(8, b"late"),
+            8,
+            false,
+            0
+        ));
+        assert_eq!(Timelocks::<Test>::get(1).unwrap().queued_round, 9);
+        assert_eq!(TimelockQueue::<Test>::get(9).into_inner(), vec![1]);
+
+        store_timelock_pulse(9);
+        Drand::process_timelocks();
+        assert_eq!(
+            TimelockOutcomes::get(),
+            vec![
+                (
+                    0,
+                    TimelockOutcome::Revealed(TimelockPayload::truncate_from(b"now".to_vec()))
+                ),
+                (
+                    1,
+                    TimelockOutcome::Revealed(TimelockPayload::truncate_from(b"late".to_vec()))
+                ),
+            ]
+        );
+        // Neither was kept
+        assert!(RevealedTimelocks::<Test>::iter().next().is_none());
+    });
+}
+
+#[test]
+fn timelock_failures_are_reported() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+
+        assert_ok!(Drand::submit_timelock(
+            RuntimeOrigin::signed(alice),
+            TimelockCiphertext::truncate_from(vec![1, 2, 3]),
+            3
+        ));
+        // Encrypted to another round
+        assert_ok!(Drand::submit_timelock(
+            RuntimeOrigin::signed(alice),
+            timelock_ciphertext(2, b"wrong round"),
+            3
+        ));
+
+        store_timelock_pulse(3);
+        Drand::process_timelocks();
+        System::assert_has_event(
+            Event::TimelockFailed {
+                id: 0,
+                owner: alice,
+                error: TimelockError::InvalidCiphertext,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::TimelockFailed {
+                id: 1,
+                owner: alice,
+                error: TimelockError::DecryptionFailed,
+            }
+            .into(),
+        );
+        assert_eq!(
+            RevealedTimelocks::<Test>::get(1).unwrap().outcome,
+            TimelockOutcome::Failed(TimelockError::DecryptionFailed)
+        );
+        assert_eq!(
+            Drand::decrypt_timelock(&timelock_ciphertext(4, b"early"), 4),
+            Err(TimelockError::MissingPulse)
+        );
+    });
+}
+
+#[test]
+fn process_timelocks_is_capped_per_block() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        for _ in 0..4 {
+            assert_ok!(Drand::register_timelock(
+                alice,
+                timelock_ciphertext(1, b"capped"),
+                1,
+                false,
+                0
+            ));
+        }
+        assert_noop!(
+            Drand::register_timelock(alice, timelock_ciphertext(1, b"capped"), 1, false, 0),
+            Error::<Test>::TooManyTimelocksInRound
+        );
+
+        // Up to 2 reveals per block, the rest of the round waits for the next block
+        store_timelock_pulse(1);
+        Drand::process_timelocks();
+        assert_eq!(TimelockOutcomes::get().len(), 2);
+        assert_eq!(TimelockQueue::<Test>::get(1).into_inner(), vec![2, 3]);
+        assert_eq!(TimelockCursor::<Test>::get(), 1);
+
+        Drand::process_timelocks();
+        assert_eq!(TimelockOutcomes::get().len(), 4);
+        assert!(TimelockQueue::<Test>::get(1).is_empty());
+        assert_eq!(TimelockCursor::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn remove_timelock_works() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        let bob = sp_keyring::Sr25519Keyring::Bob.public();
+        for round in 1..=3 {
+            assert_ok!(Drand::submit_timelock(
+                RuntimeOrigin::signed(alice),
+                timelock_ciphertext(round, b"removed"),
+                round
+            ));
+        }
+        assert_noop!(
+            Drand::submit_timelock(
+                RuntimeOrigin::signed(alice),
+                timelock_ciphertext(4, b"removed"),
+                4
+            ),
+            Error::<Test>::TooManyTimelocks
+        );
+
+        assert_noop!(
+            Drand::remove_timelock(RuntimeOrigin::signed(bob), 0),
+            Error::<Test>::NotTimelockOwner
+        );
+        assert_noop!(
+            Drand::remove_timelock(RuntimeOrigin::signed(alice), 3),
+            Error::<Test>::TimelockNotFound
+        );
+
+        // A pending timelock leaves the queue and returns its deposit
+        assert_eq!(Balances::reserved_balance(alice), 30);
+        assert_ok!(Drand::remove_timelock(RuntimeOrigin::signed(alice), 1));
+        assert!(!Timelocks::<Test>::contains_key(1));
+        assert!(TimelockQueue::<Test>::get(2).is_empty());
+        assert_eq!(Balances::reserved_balance(alice), 20);
+        assert_eq!(PendingTimelockCount::<Test>::get(), 2);
+
+        // A revealed timelock is removed too
+        store_timelock_pulse(1);
+        Drand::process_timelocks();
+        assert!(RevealedTimelocks::<Test>::contains_key(0));
+        assert_ok!(Drand::remove_timelock(RuntimeOrigin::signed(alice), 0));
+        assert!(!RevealedTimelocks::<Test>::contains_key(0));
+        assert_eq!(Balances::reserved_balance(alice), 10);
+
+        assert_eq!(TimelocksOf::<Test>::get(alice).into_inner(), vec![2]);
+    });
+}
+
+#[test]
+fn submit_timelock_requires_the_deposit() {
+    new_test_ext().execute_with(|| {
+        let charlie = sp_keyring::Sr25519Keyring::Charlie.public();
+        assert!(
+            Drand::submit_timelock(
+                RuntimeOrigin::signed(charlie),
+                timelock_ciphertext(1, b"unfunded"),
+                1
+            )
+            .is_err()
+        );
+        assert!(Timelocks::<Test>::iter().next().is_none());
+    });
+}
+
+#[test]
+fn revealed_timelocks_expire_and_return_their_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        for _ in 0..2 {
+            assert_ok!(Drand::submit_timelock(
+                RuntimeOrigin::signed(alice),
+                timelock_ciphertext(1, b"expiring"),
+                1
+            ));
+        }
+        store_timelock_pulse(1);
+        Drand::process_timelocks();
+        assert_eq!(
+            RevealedTimelockExpiry::<Test>::get(6).into_inner(),
+            vec![0, 1]
+        );
+
+        // One is removed by its owner before it expires
+        assert_ok!(Drand::remove_timelock(RuntimeOrigin::signed(alice), 1));
+
+        Drand::expire_revealed_timelocks(5);
+        assert!(RevealedTimelocks::<Test>::contains_key(0));
+
+        Drand::expire_revealed_timelocks(6);
+        assert!(!RevealedTimelocks::<Test>::contains_key(0));
+        assert!(RevealedTimelockExpiry::<Test>::get(6).is_empty());
+        assert!(TimelocksOf::<Test>::get(alice).is_empty());
+        assert_eq!(Balances::reserved_balance(alice), 0);
+        System::assert_last_event(Event::TimelockExpired { id: 0 }.into());
+    });
+}
+
+#[test]
+fn pending_timelocks_are_capped() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        for round in [1, 1, 1, 2, 2, 2] {
+            assert_ok!(Drand::register_timelock(
+                alice,
+                timelock_ciphertext(round, b"backlog"),
+                round,
+                false,
+                0
+            ));
+        }
+        assert_noop!(
+            Drand::register_timelock(alice, timelock_ciphertext(3, b"backlog"), 3, false, 0),
+            Error::<Test>::TooManyPendingTimelocks
+        );
+
+        // Revealing frees up room
+        store_timelock_pulse(1);
+        Drand::process_timelocks();
+        assert_eq!(PendingTimelockCount::<Test>::get(), 4);
+        assert_ok!(Drand::register_timelock(
+            alice,
+            timelock_ciphertext(3, b"backlog"),
+            3,
+            false,
+            0
+        ));
+    });
+}
+
+#[test]
+fn prune_pulses_stops_at_timelock_cursor() {
+    new_test_ext().execute_with(|| {
+        store_pulses(1, 13);
+        TimelockCursor::<Test>::put(2);
+
+        Drand::prune_pulses();
+        assert!(Pulses::<Test>::get(1).is_none());
+        assert!(Pulses::<Test>::get(2).is_some());
+        assert_eq!(OldestStoredRound::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn randomness_of_reads_given_or_latest_round() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Drand::randomness_of(None), None);
+
+        store_pulses(3, 5);
+        let pulse = Pulses::<Test>::get(5).unwrap();
+        let randomness: [u8; 32] = pulse.randomness.clone().into_inner().try_into().unwrap();
+
+        assert_eq!(Drand::pulse_of(None), Some(pulse.clone()));
+        assert_eq!(Drand::randomness_of(None), Some((5, randomness)));
+        assert_eq!(
+            Drand::randomness_of(Some(4)).map(|(round, _)| round),
+            Some(4)
+        );
+        assert_eq!(Drand::randomness_of(Some(6)), None);
+    });
+}
+
+#[test]
+fn randomness_is_derived_from_the_signature() {
+    new_test_ext().execute_with(|| {
+        store_pulses(5, 5);
+        let pulse = Pulses::<Test>::get(5).unwrap();
+        let randomness: [u8; 32] = pulse.randomness.clone().into_inner().try_into().unwrap();
+
+        // The submitted randomness is not covered by the signature, so it is ignored
+        Pulses::<Test>::insert(
+            5,
+            Pulse {
+                randomness: BoundedVec::truncate_from(vec![0u8; 32]),
+                ..pulse
+            },
+        );
+        assert_eq!(Drand::randomness_of(Some(5)), Some((5, randomness)));
+        assert_eq!(Drand::random_at(5), randomness);
+
+        Pulses::<Test>::mutate(5, |pulse| {
+            if let Some(pulse) = pulse {
+                pulse.randomness = BoundedVec::truncate_from(vec![1u8; 8]);
+            }
+        });
+        assert_eq!(Drand::randomness_of(None), Some((5, randomness)));
+    });
+}
@@ -0,0 +1,133 @@
+/*
+ * Copyright 2024 by Ideal Labs, LLC
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Timelock encryption against drand quicknet rounds.
+//!
+//! A ciphertext is registered with the round it was encrypted to. Once that round's pulse is
+//! stored the pallet decrypts it in `on_initialize`, within a per-block budget, and reports the
+//! outcome to [`OnTimelockRevealed`].
+
+use alloc::vec::Vec;
+use ark_serialize::CanonicalDeserialize;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::*;
+use subtensor_macros::freeze_struct;
+use tle::{
+    curves::drand::TinyBLS381,
+    stream_ciphers::AESGCMStreamCipherProvider,
+    tlock::{TLECiphertext, tld},
+};
+use w3f_bls::EngineBLS;
+
+use crate::types::RoundNumber;
+
+/// the identifier of a registered timelock
+pub type TimelockId = u64;
+
+/// the maximum length of a timelock ciphertext and of its decrypted payload
+pub const MAX_TIMELOCK_LENGTH: u32 = 4096;
+/// a serialized `TLECiphertext<TinyBLS381>`
+pub type TimelockCiphertext = BoundedVec<u8, ConstU32<MAX_TIMELOCK_LENGTH>>;
+/// a decrypted timelock payload
+pub type TimelockPayload = BoundedVec<u8, ConstU32<MAX_TIMELOCK_LENGTH>>;
+
+/// why a timelock could not be decrypted
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TimelockError {
+    /// the ciphertext is not a valid `TLECiphertext`
+    InvalidCiphertext,
+    /// the pulse of the reveal round is not stored
+    MissingPulse,
+    /// the stored pulse signature could not be deserialized
+    InvalidSignature,
+    /// the ciphertext was not encrypted to the reveal round
+    DecryptionFailed,
+}
+
+/// the result of revealing a timelock
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TimelockOutcome {
+    Revealed(TimelockPayload),
+    Failed(TimelockError),
+}
+
+/// a timelock waiting for the pulse of its reveal round
+#[freeze_struct("b13d9de5674d71c1")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTimelock<AccountId, Balance> {
+    pub owner: AccountId,
+    pub ciphertext: TimelockCiphertext,
+    pub reveal_round: RoundNumber,
+    /// the round it is queued under, later than `reveal_round` if registered after it passed
+    pub queued_round: RoundNumber,
+    /// whether the outcome is stored in `RevealedTimelocks` once revealed
+    pub keep_reveal: bool,
+    /// the deposit reserved from the owner
+    pub deposit: Balance,
+}
+
+/// a revealed timelock, kept until its owner removes it or it expires
+#[freeze_struct("228fdc074f2d4478")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RevealedTimelock<AccountId, Balance, BlockNumber> {
+    pub owner: AccountId,
+    pub reveal_round: RoundNumber,
+    pub revealed_at: BlockNumber,
+    pub outcome: TimelockOutcome,
+    /// the deposit reserved from the owner
+    pub deposit: Balance,
+}
+
+/// Handles revealed timelocks, e.g. in the pallet that registered them.
+pub trait OnTimelockRevealed<AccountId> {
+    /// Called once per timelock with its outcome, returning the weight consumed.
+    fn on_timelock_revealed(id: TimelockId, owner: &AccountId, outcome: &TimelockOutcome)
+    -> Weight;
+}
+
+impl<AccountId> OnTimelockRevealed<AccountId> for () {
+    fn on_timelock_revealed(_: TimelockId, _: &AccountId, _: &TimelockOutcome) -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId, A: OnTimelockRevealed<AccountId>, B: OnTimelockRevealed<AccountId>>
+    OnTimelockRevealed<AccountId> for (A, B)
+{
+    fn on_timelock_revealed(
+        id: TimelockId,
+        owner: &AccountId,
+        outcome: &TimelockOutcome,
+    ) -> Weight {
+        A::on_timelock_revealed(id, owner, outcome)
+            .saturating_add(B::on_timelock_revealed(id, owner, outcome))
+    }
+}
+
+/// decrypt a serialized `TLECiphertext<TinyBLS381>` with a quicknet pulse signature, with or
+/// without its `0x` prefix
+pub fn decrypt(ciphertext: &[u8], signature: &[u8]) -> Result<Vec<u8>, TimelockError> {
+    let commit = TLECiphertext::<TinyBLS381>::deserialize_compressed(&mut &ciphertext[..])
+        .map_err(|_| TimelockError::InvalidCiphertext)?;
+
+    let signature = signature.strip_prefix(b"0x").unwrap_or(signature);
+    let sig =
+        <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(&mut &signature[..])
+            .map_err(|_| TimelockError::InvalidSignature)?;
+
+    tld::<TinyBLS381, AESGCMStreamCipherProvider>(commit, sig)
+        .map_err(|_| TimelockError::DecryptionFailed)
+}
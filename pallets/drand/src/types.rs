@@ -18,6 +18,7 @@ use alloc::{string::String, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::*;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use subtensor_macros::freeze_struct;
 
 /// Represents an opaque public key used in drand's quicknet
@@ -27,6 +28,17 @@ pub type OpaquePublicKey = BoundedVec<u8, ConstU32<96>>;
 pub type BoundedHash = BoundedVec<u8, ConstU32<32>>;
 /// the round number to track rounds of the beacon
 pub type RoundNumber = u64;
+/// an opaque BLS signature, in G1 for quicknet and in G2 for chained beacons
+pub type OpaqueSignature = BoundedVec<u8, ConstU32<144>>;
+
+/// the maximum number of http endpoints serving the beacon
+pub const MAX_BEACON_ENDPOINTS: u32 = 8;
+/// the maximum length of a beacon http endpoint
+pub const MAX_BEACON_ENDPOINT_LENGTH: u32 = 128;
+/// a base url serving the drand http api, e.g. `https://api.drand.sh`
+pub type BeaconEndpoint = BoundedVec<u8, ConstU32<MAX_BEACON_ENDPOINT_LENGTH>>;
+/// the http endpoints serving the beacon, queried concurrently
+pub type BeaconEndpoints = BoundedVec<BeaconEndpoint, ConstU32<MAX_BEACON_ENDPOINTS>>;
 
 /// the expected response body from the drand api endpoint `api.drand.sh/{chainId}/info`
 #[freeze_struct("f9e09b3273fe00cd")]
@@ -84,7 +96,7 @@ impl BeaconInfoResponse {
 
 /// a pulse from the drand beacon
 /// the expected response body from the drand api endpoint `api.drand.sh/{chainId}/public/latest`
-#[freeze_struct("a3fed2c99a0638bf")]
+#[freeze_struct("66e64a4cd2d8fee4")]
 #[derive(Debug, Decode, Default, PartialEq, Encode, Serialize, Deserialize)]
 pub struct DrandResponseBody {
     /// the randomness round number
@@ -97,9 +109,22 @@ pub struct DrandResponseBody {
     // TODO: use Signature (https://github.com/ideal-lab5/pallet-drand/issues/2)
     #[serde(with = "hex::serde")]
     pub signature: Vec<u8>,
+    /// BLS sig for the previous round, only served by chained beacons
+    #[serde(default, with = "hex::serde")]
+    pub previous_signature: Vec<u8>,
 }
 
 impl DrandResponseBody {
+    /// the signature of the previous round, if the beacon is chained
+    pub fn try_into_previous_signature(&self) -> Result<Option<OpaqueSignature>, String> {
+        if self.previous_signature.is_empty() {
+            return Ok(None);
+        }
+        OpaqueSignature::try_from(self.previous_signature.clone())
+            .map(Some)
+            .map_err(|_| "Failed to convert previous_signature".into())
+    }
+
     pub fn try_into_pulse(&self) -> Result<Pulse, String> {
         // TODO:  update these bounded vecs
         let bounded_randomness = BoundedVec::<u8, ConstU32<32>>::try_from(self.randomness.clone())
@@ -194,12 +219,22 @@ pub struct Pulse {
     pub signature: BoundedVec<u8, ConstU32<144>>,
 }
 
+impl Pulse {
+    /// The randomness of the pulse derived from its verified signature, rather than the
+    /// unverified `randomness` it was submitted with.
+    pub fn verified_randomness(&self) -> [u8; 32] {
+        Sha256::digest(self.signature.as_slice()).into()
+    }
+}
+
 /// Payload used by to hold the pulse
 /// data required to submit a transaction.
-#[freeze_struct("4a9f01b1d8fbbe89")]
+#[freeze_struct("81a6c3a7c29fe4f8")]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct PulsesPayload<Public, BlockNumber> {
     pub block_number: BlockNumber,
     pub pulses: Vec<Pulse>,
+    /// the signature of the round before the first pulse, needed to verify chained beacons
+    pub previous_signature: Option<OpaqueSignature>,
     pub public: Public,
 }
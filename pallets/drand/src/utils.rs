@@ -16,11 +16,23 @@
 
 #![allow(dead_code)]
 
-use crate::verifier::ArkScale;
-use ark_ec::AffineRepr;
+use crate::{
+    message,
+    types::{
+        BeaconConfiguration, BoundedHash, OpaquePublicKey, OpaqueSignature, Pulse, RoundNumber,
+    },
+    verifier::{ArkScale, CHAINED_SCHEME_ID, QUICKNET_SCHEME_ID},
+};
+use ark_ec::{AffineRepr, CurveGroup, hashing::HashToCurve};
 use ark_scale::hazmat::ArkScaleProjective;
-use ark_serialize::{CanonicalSerialize, Compress};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress};
 use ark_std::{UniformRand, test_rng, vec, vec::Vec};
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+use tle::{
+    curves::drand::TinyBLS381, ibe::fullident::Identity,
+    stream_ciphers::AESGCMStreamCipherProvider, tlock::tle,
+};
+use w3f_bls::engine::EngineBLS;
 pub type ScalarFieldFor<AffineT> = <AffineT as AffineRepr>::ScalarField;
 
 // `words_count` is the scalar length in words, with 1 word assumed to be 64 bits.
@@ -79,3 +91,82 @@ pub fn serialize_argument(argument: impl CanonicalSerialize) -> Vec<u8> {
         .unwrap_or_default();
     buf
 }
+
+fn serialize_compressed(point: impl CanonicalSerialize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing a curve point succeeds");
+    bytes
+}
+
+/// A beacon signing like quicknet, with `secret` as its secret key.
+pub fn make_quicknet_beacon(secret: u64) -> BeaconConfiguration {
+    let public_key =
+        (ark_bls12_381::G2Affine::generator() * ark_bls12_381::Fr::from(secret)).into_affine();
+    BeaconConfiguration {
+        public_key: OpaquePublicKey::truncate_from(serialize_compressed(public_key)),
+        scheme_id: BoundedHash::truncate_from(QUICKNET_SCHEME_ID.to_vec()),
+        ..Default::default()
+    }
+}
+
+/// The pulse of `round` signed by the beacon of [`make_quicknet_beacon`].
+pub fn make_quicknet_pulse(secret: u64, round: RoundNumber) -> Pulse {
+    let message_on_curve = <TinyBLS381 as EngineBLS>::hash_to_curve_map()
+        .hash(&message(round, &[]))
+        .expect("hashing to G1 succeeds");
+    let signature = (message_on_curve * ark_bls12_381::Fr::from(secret)).into_affine();
+    Pulse {
+        round,
+        signature: OpaqueSignature::truncate_from(serialize_compressed(signature)),
+        ..Default::default()
+    }
+}
+
+/// A beacon signing like drand's default chained network, with `secret` as its secret key.
+pub fn make_chained_beacon(secret: u64) -> BeaconConfiguration {
+    let public_key =
+        (ark_bls12_381::G1Affine::generator() * ark_bls12_381::Fr::from(secret)).into_affine();
+    BeaconConfiguration {
+        public_key: OpaquePublicKey::truncate_from(serialize_compressed(public_key)),
+        scheme_id: BoundedHash::truncate_from(CHAINED_SCHEME_ID.to_vec()),
+        ..Default::default()
+    }
+}
+
+/// The pulse of `round` signed by the beacon of [`make_chained_beacon`], chained to
+/// `previous_signature`.
+pub fn make_chained_pulse(secret: u64, round: RoundNumber, previous_signature: &[u8]) -> Pulse {
+    let message_on_curve = crate::verifier::hash_to_g2(&message(round, previous_signature))
+        .expect("hashing to G2 succeeds");
+    let message_on_curve = ark_bls12_381::G2Affine::deserialize_compressed(
+        serialize_compressed(message_on_curve).as_slice(),
+    )
+    .expect("points on G2 are compatible");
+    let signature = (message_on_curve * ark_bls12_381::Fr::from(secret)).into_affine();
+    Pulse {
+        round,
+        signature: OpaqueSignature::truncate_from(serialize_compressed(signature)),
+        ..Default::default()
+    }
+}
+
+/// `plaintext` timelock encrypted to `round` of the beacon of [`make_quicknet_beacon`].
+pub fn make_timelock_ciphertext(secret: u64, round: RoundNumber, plaintext: &[u8]) -> Vec<u8> {
+    let public_key = <TinyBLS381 as EngineBLS>::PublicKeyGroup::deserialize_compressed(
+        make_quicknet_beacon(secret).public_key.as_slice(),
+    )
+    .expect("the beacon public key is in G2");
+    let identity = Identity::new(b"", vec![message(round, &[])]);
+
+    let ciphertext = tle::<TinyBLS381, AESGCMStreamCipherProvider, ChaCha20Rng>(
+        public_key,
+        [2u8; 32],
+        plaintext,
+        identity,
+        ChaCha20Rng::seed_from_u64(round),
+    )
+    .expect("encrypting to a round succeeds");
+    serialize_compressed(ciphertext)
+}
@@ -23,7 +23,11 @@ use crate::{
     types::{BeaconConfiguration, Pulse, RoundNumber},
 };
 use alloc::{format, string::String, vec::Vec};
-use ark_ec::{AffineRepr, hashing::HashToCurve};
+use ark_ec::{
+    AffineRepr, CurveGroup, VariableBaseMSM,
+    hashing::{HashToCurve, curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher},
+};
+use ark_ff::{PrimeField, field_hashers::DefaultFieldHasher};
 use ark_serialize::CanonicalSerialize;
 use codec::Decode;
 use sha2::{Digest, Sha256};
@@ -34,6 +38,15 @@ use w3f_bls::engine::EngineBLS;
 const USAGE: ark_scale::Usage = ark_scale::WIRE;
 pub type ArkScale<T> = ark_scale::ArkScale<T, USAGE>;
 
+/// the scheme id of quicknet, see [`QuicknetVerifier`]
+pub const QUICKNET_SCHEME_ID: &[u8] = b"bls-unchained-g1-rfc9380";
+
+/// the scheme id of drand's default chained network, see [`ChainedVerifier`]
+pub const CHAINED_SCHEME_ID: &[u8] = b"pedersen-bls-chained";
+
+/// the domain separation tag used by chained beacons to hash messages to G2
+const CHAINED_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
 /// construct a message (e.g. signed by drand)
 fn message(current_round: RoundNumber, prev_sig: &[u8]) -> Vec<u8> {
     let mut hasher = Sha256::default();
@@ -45,7 +58,52 @@ fn message(current_round: RoundNumber, prev_sig: &[u8]) -> Vec<u8> {
 /// something to verify beacon pulses
 pub trait Verifier {
     /// verify the given pulse using beacon_config
-    fn verify(beacon_config: BeaconConfiguration, pulse: Pulse) -> Result<bool, String>;
+    /// chained beacons sign over `previous_signature`, the signature of the round before `pulse`
+    fn verify(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        previous_signature: &[u8],
+    ) -> Result<bool, String>;
+
+    /// verify consecutive pulses at once, outputting true only if every pulse is valid
+    /// by default each pulse is verified in turn
+    fn verify_batch(
+        beacon_config: BeaconConfiguration,
+        pulses: &[Pulse],
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        let mut previous_signature = previous_signature.to_vec();
+        for pulse in pulses {
+            if !Self::verify(beacon_config.clone(), pulse.clone(), &previous_signature)? {
+                return Ok(false);
+            }
+            previous_signature = pulse.signature.clone().into_inner();
+        }
+        Ok(true)
+    }
+}
+
+/// the coefficients of a random linear combination of the pulses in a batch
+///
+/// they are derived from every signature in the batch, so a forged signature cannot be chosen to
+/// cancel out in the combination
+fn batch_coefficients<F: PrimeField>(pulses: &[Pulse], previous_signature: &[u8]) -> Vec<F> {
+    let mut hasher = Sha256::default();
+    hasher.update(previous_signature);
+    for pulse in pulses {
+        hasher.update(pulse.round.to_be_bytes());
+        hasher.update(pulse.signature.as_slice());
+    }
+    let seed = hasher.finalize();
+
+    (0..pulses.len() as u64)
+        .map(|index| {
+            let mut hasher = Sha256::default();
+            hasher.update(seed);
+            hasher.update(index.to_be_bytes());
+            F::from_le_bytes_mod_order(&hasher.finalize())
+        })
+        .collect()
 }
 
 /// A verifier to check values received from quicknet. It outputs true if valid, false otherwise
@@ -64,7 +122,11 @@ pub trait Verifier {
 pub struct QuicknetVerifier;
 
 impl Verifier for QuicknetVerifier {
-    fn verify(beacon_config: BeaconConfiguration, pulse: Pulse) -> Result<bool, String> {
+    fn verify(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        _previous_signature: &[u8],
+    ) -> Result<bool, String> {
         // decode public key (pk)
         let pk =
             ArkScale::<G2AffineOpt>::decode(&mut beacon_config.public_key.into_inner().as_slice())
@@ -77,35 +139,243 @@ impl Verifier for QuicknetVerifier {
 
         // m = sha256({} || {round})
         let message = message(pulse.round, &[]);
-        let hasher = <TinyBLS381 as EngineBLS>::hash_to_curve_map();
         // H(m) \in G1
-        let message_hash = hasher
-            .hash(&message)
-            .map_err(|e| format!("Failed to hash message: {}", e))?;
-
-        let mut bytes = Vec::new();
-        message_hash
-            .serialize_compressed(&mut bytes)
-            .map_err(|e| format!("Failed to serialize message hash: {}", e))?;
-
-        let message_on_curve = ArkScale::<G1AffineOpt>::decode(&mut &bytes[..])
-            .map_err(|e| format!("Failed to decode message on curve: {}", e))?;
+        let message_on_curve = hash_to_g1(&message)?;
 
         let g2 = G2AffineOpt::generator();
 
         Ok(bls12_381::fast_pairing_opt(
             signature.0,
             g2,
-            message_on_curve.0,
+            message_on_curve,
+            pk.0,
+        ))
+    }
+
+    /// Checks the random linear combination of the batch with a single multi-pairing:
+    ///   $e(\sum_i r_i sig_i, g_2) == e(\sum_i r_i msg_on_curve_i, pk)$
+    fn verify_batch(
+        beacon_config: BeaconConfiguration,
+        pulses: &[Pulse],
+        _previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        // decode public key (pk)
+        let pk =
+            ArkScale::<G2AffineOpt>::decode(&mut beacon_config.public_key.into_inner().as_slice())
+                .map_err(|e| format!("Failed to decode public key: {}", e))?;
+
+        let mut signatures = Vec::with_capacity(pulses.len());
+        let mut messages_on_curve = Vec::with_capacity(pulses.len());
+        for pulse in pulses {
+            let signature = ArkScale::<G1AffineOpt>::decode(
+                &mut pulse.signature.clone().into_inner().as_slice(),
+            )
+            .map_err(|e| format!("Failed to decode signature: {}", e))?;
+            signatures.push(signature.0);
+            messages_on_curve.push(hash_to_g1(&message(pulse.round, &[]))?);
+        }
+
+        let coefficients = batch_coefficients(pulses, &[]);
+        let signature_sum = <G1AffineOpt as AffineRepr>::Group::msm(&signatures, &coefficients)
+            .map_err(|_| String::from("Failed to combine signatures"))?;
+        let message_sum =
+            <G1AffineOpt as AffineRepr>::Group::msm(&messages_on_curve, &coefficients)
+                .map_err(|_| String::from("Failed to combine messages"))?;
+
+        Ok(bls12_381::fast_pairing_opt(
+            signature_sum.into_affine(),
+            G2AffineOpt::generator(),
+            message_sum.into_affine(),
             pk.0,
         ))
     }
 }
 
+/// hash a message to G1 the way quicknet does
+pub fn hash_to_g1(message: &[u8]) -> Result<G1AffineOpt, String> {
+    let hasher = <TinyBLS381 as EngineBLS>::hash_to_curve_map();
+    let message_hash = hasher
+        .hash(message)
+        .map_err(|e| format!("Failed to hash message: {}", e))?;
+
+    let mut bytes = Vec::new();
+    message_hash
+        .serialize_compressed(&mut bytes)
+        .map_err(|e| format!("Failed to serialize message hash: {}", e))?;
+
+    ArkScale::<G1AffineOpt>::decode(&mut &bytes[..])
+        .map(|point| point.0)
+        .map_err(|e| format!("Failed to decode message on curve: {}", e))
+}
+
+/// A verifier to check values received from drand's default network. It outputs true if valid,
+/// false otherwise
+///
+/// The default network operates in a chained mode, so messages contain the previous signature and
+/// the round number. in addition, public keys are in G1 and signatures are in G2
+///
+/// Values are valid if the pairing equality holds:
+///   $e(g_1, sig) == e(pk, msg_on_curve)$
+/// where $sig \in \mathbb{G}_2$ is the signature
+///       $g_1 \in \mathbb{G}_1$ is a generator
+///       $msg_on_curve \in \mathbb{G}_2$ is a hash of the message that drand signed
+/// (hash(previous_signature || round_number))       $pk \in \mathbb{G}_1$ is the public key,
+/// read from the input public parameters
+pub struct ChainedVerifier;
+
+impl Verifier for ChainedVerifier {
+    fn verify(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        if previous_signature.is_empty() {
+            return Err("Missing previous signature".into());
+        }
+
+        // decode public key (pk)
+        let pk =
+            ArkScale::<G1AffineOpt>::decode(&mut beacon_config.public_key.into_inner().as_slice())
+                .map_err(|e| format!("Failed to decode public key: {}", e))?;
+
+        // decode signature (sigma)
+        let signature =
+            ArkScale::<G2AffineOpt>::decode(&mut pulse.signature.into_inner().as_slice())
+                .map_err(|e| format!("Failed to decode signature: {}", e))?;
+
+        // m = sha256({previous_signature} || {round})
+        let message = message(pulse.round, previous_signature);
+        // H(m) \in G2
+        let message_on_curve = hash_to_g2(&message)?;
+
+        let g1 = G1AffineOpt::generator();
+
+        Ok(bls12_381::fast_pairing_opt(
+            g1,
+            signature.0,
+            pk.0,
+            message_on_curve,
+        ))
+    }
+
+    /// Checks the random linear combination of the batch with a single multi-pairing:
+    ///   $e(g_1, \sum_i r_i sig_i) == e(pk, \sum_i r_i msg_on_curve_i)$
+    /// where each message is built from the signature of the previous pulse
+    fn verify_batch(
+        beacon_config: BeaconConfiguration,
+        pulses: &[Pulse],
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        if previous_signature.is_empty() {
+            return Err("Missing previous signature".into());
+        }
+
+        // decode public key (pk)
+        let pk =
+            ArkScale::<G1AffineOpt>::decode(&mut beacon_config.public_key.into_inner().as_slice())
+                .map_err(|e| format!("Failed to decode public key: {}", e))?;
+
+        let mut signatures = Vec::with_capacity(pulses.len());
+        let mut messages_on_curve = Vec::with_capacity(pulses.len());
+        let mut previous = previous_signature;
+        for pulse in pulses {
+            let signature = ArkScale::<G2AffineOpt>::decode(
+                &mut pulse.signature.clone().into_inner().as_slice(),
+            )
+            .map_err(|e| format!("Failed to decode signature: {}", e))?;
+            signatures.push(signature.0);
+            messages_on_curve.push(hash_to_g2(&message(pulse.round, previous))?);
+            previous = pulse.signature.as_slice();
+        }
+
+        let coefficients = batch_coefficients(pulses, previous_signature);
+        let signature_sum = <G2AffineOpt as AffineRepr>::Group::msm(&signatures, &coefficients)
+            .map_err(|_| String::from("Failed to combine signatures"))?;
+        let message_sum =
+            <G2AffineOpt as AffineRepr>::Group::msm(&messages_on_curve, &coefficients)
+                .map_err(|_| String::from("Failed to combine messages"))?;
+
+        Ok(bls12_381::fast_pairing_opt(
+            G1AffineOpt::generator(),
+            signature_sum.into_affine(),
+            pk.0,
+            message_sum.into_affine(),
+        ))
+    }
+}
+
+/// hash a message to G2 the way chained drand beacons do
+pub fn hash_to_g2(message: &[u8]) -> Result<G2AffineOpt, String> {
+    let hasher = MapToCurveBasedHasher::<
+        ark_bls12_381::G2Projective,
+        DefaultFieldHasher<Sha256, 128>,
+        WBMap<ark_bls12_381::g2::Config>,
+    >::new(CHAINED_DST)
+    .map_err(|e| format!("Failed to create hasher: {}", e))?;
+    let message_hash = hasher
+        .hash(message)
+        .map_err(|e| format!("Failed to hash message: {}", e))?;
+
+    let mut bytes = Vec::new();
+    message_hash
+        .serialize_compressed(&mut bytes)
+        .map_err(|e| format!("Failed to serialize message hash: {}", e))?;
+
+    ArkScale::<G2AffineOpt>::decode(&mut &bytes[..])
+        .map(|point| point.0)
+        .map_err(|e| format!("Failed to decode message on curve: {}", e))
+}
+
+/// A verifier for every supported beacon, dispatching on the scheme id of the beacon
+/// configuration, so that governance can switch between quicknet and chained beacons
+pub struct SchemeVerifier;
+
+impl Verifier for SchemeVerifier {
+    fn verify(
+        beacon_config: BeaconConfiguration,
+        pulse: Pulse,
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        match beacon_config.scheme_id.as_slice() {
+            QUICKNET_SCHEME_ID => {
+                QuicknetVerifier::verify(beacon_config, pulse, previous_signature)
+            }
+            CHAINED_SCHEME_ID => ChainedVerifier::verify(beacon_config, pulse, previous_signature),
+            _ => Err(format!(
+                "Unsupported beacon scheme: {}",
+                String::from_utf8_lossy(beacon_config.scheme_id.as_slice())
+            )),
+        }
+    }
+
+    fn verify_batch(
+        beacon_config: BeaconConfiguration,
+        pulses: &[Pulse],
+        previous_signature: &[u8],
+    ) -> Result<bool, String> {
+        match beacon_config.scheme_id.as_slice() {
+            QUICKNET_SCHEME_ID => {
+                QuicknetVerifier::verify_batch(beacon_config, pulses, previous_signature)
+            }
+            CHAINED_SCHEME_ID => {
+                ChainedVerifier::verify_batch(beacon_config, pulses, previous_signature)
+            }
+            _ => Err(format!(
+                "Unsupported beacon scheme: {}",
+                String::from_utf8_lossy(beacon_config.scheme_id.as_slice())
+            )),
+        }
+    }
+}
+
 /// The unsafe skip verifier is just a pass-through verification, always returns true
 pub struct UnsafeSkipVerifier;
 impl Verifier for UnsafeSkipVerifier {
-    fn verify(_beacon_config: BeaconConfiguration, _pulse: Pulse) -> Result<bool, String> {
+    fn verify(
+        _beacon_config: BeaconConfiguration,
+        _pulse: Pulse,
+        _previous_signature: &[u8],
+    ) -> Result<bool, String> {
         Ok(true)
     }
 }
@@ -51,6 +51,11 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     fn write_pulse(pulses_count: u32) -> Weight;
     fn set_beacon_config() -> Weight;
+    fn set_retention_rounds() -> Weight;
+    fn set_beacon_endpoints() -> Weight;
+    fn submit_timelock() -> Weight;
+    fn remove_timelock() -> Weight;
+    fn reveal_timelock() -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
@@ -60,6 +65,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Drand::BeaconConfig` (`max_values`: Some(1), `max_size`: Some(238), added: 733, mode: `MaxEncodedLen`)
 	/// Storage: `Drand::NextUnsignedAt` (r:0 w:1)
 	/// Proof: `Drand::NextUnsignedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::LastStoredRound` (r:1 w:1)
+	/// Storage: `Drand::OldestStoredRound` (r:1 w:1)
+	/// Storage: `Drand::StalePulseRounds` (r:1 w:1)
 	fn set_beacon_config() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -67,14 +75,116 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(8_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Drand::RetentionRounds` (r:0 w:1)
+	/// Proof: `Drand::RetentionRounds` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_retention_rounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Drand::BeaconEndpointList` (r:0 w:1)
+	/// Proof: `Drand::BeaconEndpointList` (`max_values`: Some(1), `max_size`: Some(1049), added: 1544, mode: `MaxEncodedLen`)
+	fn set_beacon_endpoints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Drand::BeaconConfig` (r:1 w:0)
 	/// Proof: `Drand::BeaconConfig` (`max_values`: Some(1), `max_size`: Some(238), added: 733, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::OldestStoredRound` (r:1 w:1)
+	/// Proof: `Drand::OldestStoredRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::Pulses` (r:0 w:50)
+	/// Proof: `Drand::Pulses` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	/// The range of component `pulses_count` is `[1, 50]`.
+	// TODO should be replaced by benchmarked weights
 	fn write_pulse(pulses_count: u32) -> Weight {
-		// Adjust the weight calculation based on pulses_count
-		Weight::from_parts(6_000_000 * pulses_count as u64, 0)
-			.saturating_add(Weight::from_parts(0, 1723 * pulses_count as u64))
-			.saturating_add(T::DbWeight::get().reads_writes(1, pulses_count as u64))
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1723`
+		// Not benchmarked: estimated for the chained verifier, the more expensive of the two beacon
+		// kinds. A single multi-pairing covers the whole batch, priced like the pairing of
+		// `reveal_timelock`. Each pulse adds hashing its message to G2, decoding its G2 signature
+		// and its share of the multi-scalar multiplications.
+		Weight::from_parts(2_000_000_000, 1723)
+			.saturating_add(Weight::from_parts(1_000_000_000, 0).saturating_mul(pulses_count as u64))
+			.saturating_add(Weight::from_parts(0, 2703).saturating_mul(pulses_count as u64))
+			.saturating_add(T::DbWeight::get().reads_writes(2, (pulses_count as u64).saturating_add(1)))
 	}	
+	/// Storage: `Drand::TimelockCursor` (r:1 w:1)
+	/// Proof: `Drand::TimelockCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::LastStoredRound` (r:1 w:0)
+	/// Proof: `Drand::LastStoredRound` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::NextTimelockId` (r:1 w:1)
+	/// Proof: `Drand::NextTimelockId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::TimelockQueue` (r:1 w:1)
+	/// Proof: `Drand::TimelockQueue` (`max_values`: None, `max_size`: Some(533), added: 3008, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::TimelocksOf` (r:1 w:1)
+	/// Proof: `Drand::TimelocksOf` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::Timelocks` (r:0 w:1)
+	/// Proof: `Drand::Timelocks` (`max_values`: None, `max_size`: Some(4175), added: 6650, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::PendingTimelockCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	// TODO should be replaced by benchmarked weights
+	fn submit_timelock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3998`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 3998)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Drand::Timelocks` (r:1 w:1)
+	/// Proof: `Drand::Timelocks` (`max_values`: None, `max_size`: Some(4159), added: 6634, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::RevealedTimelocks` (r:1 w:1)
+	/// Proof: `Drand::RevealedTimelocks` (`max_values`: None, `max_size`: Some(4158), added: 6633, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::TimelockQueue` (r:1 w:1)
+	/// Proof: `Drand::TimelockQueue` (`max_values`: None, `max_size`: Some(533), added: 3008, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::TimelocksOf` (r:1 w:1)
+	/// Proof: `Drand::TimelocksOf` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::PendingTimelockCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	// TODO should be replaced by benchmarked weights
+	fn remove_timelock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4371`
+		//  Estimated: `7624`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 7624)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Drand::Timelocks` (r:1 w:1)
+	/// Proof: `Drand::Timelocks` (`max_values`: None, `max_size`: Some(4159), added: 6634, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::Pulses` (r:1 w:0)
+	/// Proof: `Drand::Pulses` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::RevealedTimelocks` (r:0 w:1)
+	/// Proof: `Drand::RevealedTimelocks` (`max_values`: None, `max_size`: Some(4174), added: 6649, mode: `MaxEncodedLen`)
+	/// Storage: `Drand::PendingTimelockCount` (r:1 w:1)
+	/// Storage: `Drand::RevealedTimelockExpiry` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	// TODO should be replaced by benchmarked weights
+	fn reveal_timelock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4463`
+		//  Estimated: `7624`
+		// Minimum execution time: 1_260_000_000 picoseconds.
+		// One pairing and the symmetric decryption of the payload.
+		Weight::from_parts(1_260_000_000, 7624)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
@@ -100,6 +100,27 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
     height: BlockNumber,
 }
 
+/// Spending budgets of proxy types, checked when a call is dispatched through a proxy.
+pub trait SpendingLimit<ProxyType, RuntimeCall, Balance, BlockNumber> {
+    /// The budget and the length of its rolling window in blocks, if the proxy type has one.
+    fn limit(proxy_type: &ProxyType) -> Option<(Balance, BlockNumber)>;
+
+    /// The amount the call spends from the budget.
+    fn spent(call: &RuntimeCall) -> Balance;
+}
+
+impl<ProxyType, RuntimeCall, Balance: Zero, BlockNumber>
+    SpendingLimit<ProxyType, RuntimeCall, Balance, BlockNumber> for ()
+{
+    fn limit(_: &ProxyType) -> Option<(Balance, BlockNumber)> {
+        None
+    }
+
+    fn spent(_: &RuntimeCall) -> Balance {
+        Zero::zero()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::{DispatchResult, *};
@@ -180,6 +201,20 @@ pub mod pallet {
         /// into a pre-existing storage value.
         #[pallet::constant]
         type AnnouncementDepositFactor: Get<BalanceOf<Self>>;
+
+        /// The spending budgets of proxy types.
+        type SpendingLimit: SpendingLimit<
+            Self::ProxyType,
+            <Self as Config>::RuntimeCall,
+            BalanceOf<Self>,
+            BlockNumberFor<Self>,
+        >;
+
+        /// The maximum number of spends tracked per proxy within a budget window.
+        ///
+        /// Once reached, further spends are merged into the most recent one.
+        #[pallet::constant]
+        type MaxSpendingEntries: Get<u32>;
     }
 
     #[pallet::call]
@@ -201,7 +236,7 @@ pub mod pallet {
 				Pays::No => Weight::zero(),
 			};
 			let base_weight = T::WeightInfo::proxy(T::MaxProxies::get())
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2));
 			(base_weight.saturating_add(inner_call_weight), di.class)
 		})]
         pub fn proxy(
@@ -215,9 +250,7 @@ pub mod pallet {
             let def = Self::find_proxy(&real, &who, force_proxy_type)?;
             ensure!(def.delay.is_zero(), Error::<T>::Unannounced);
 
-            Self::do_proxy(def, real, *call);
-
-            Ok(())
+            Self::do_limited_proxy(def, real, *call)
         }
 
         /// Register a proxy account for the sender that is able to make calls on its behalf.
@@ -303,6 +336,7 @@ pub mod pallet {
             index: u16,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_valid_spending_limit(&proxy_type)?;
 
             let pure = Self::pure_account(&who, &proxy_type, index, None);
             ensure!(!Proxies::<T>::contains_key(&pure), Error::<T>::Duplicate);
@@ -501,8 +535,8 @@ pub mod pallet {
         #[pallet::weight({
 			let di = call.get_dispatch_info();
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
-				 // AccountData for inner call origin accountdata.
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // AccountData for inner call origin accountdata and proxy spending.
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
 				.saturating_add(di.weight),
 			di.class)
 		})]
@@ -527,9 +561,7 @@ pub mod pallet {
             })
             .map_err(|_| Error::<T>::Unannounced)?;
 
-            Self::do_proxy(def, real, *call);
-
-            Ok(())
+            Self::do_limited_proxy(def, real, *call)
         }
     }
 
@@ -586,6 +618,10 @@ pub mod pallet {
         Unannounced,
         /// Cannot add self as proxy.
         NoSelfProxy,
+        /// The call would exceed the spending budget of the proxy.
+        SpendingLimitExceeded,
+        /// A spending-limited proxy must have a budget window of at least one block.
+        ZeroSpendingWindow,
     }
 
     /// The set of account proxies. Maps the account which has delegated to the accounts
@@ -617,6 +653,19 @@ pub mod pallet {
         ),
         ValueQuery,
     >;
+
+    /// The spends made through spending-limited proxies, keyed by the proxied account and
+    /// the delegate, as `(block, amount)` entries.
+    #[pallet::storage]
+    pub type ProxySpending<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), T::MaxSpendingEntries>,
+        ValueQuery,
+    >;
 }
 
 impl<T: Config> Pallet<T> {
@@ -691,6 +740,7 @@ impl<T: Config> Pallet<T> {
         delay: BlockNumberFor<T>,
     ) -> DispatchResult {
         ensure!(delegator != &delegatee, Error::<T>::NoSelfProxy);
+        Self::ensure_valid_spending_limit(&proxy_type)?;
         Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
             let proxy_def = ProxyDefinition {
                 delegate: delegatee.clone(),
@@ -761,6 +811,9 @@ impl<T: Config> Pallet<T> {
                 }
                 Ordering::Equal => (),
             }
+            if !proxies.iter().any(|p| p.delegate == delegatee) {
+                ProxySpending::<T>::remove(delegator, &delegatee);
+            }
             if !proxies.is_empty() {
                 *x = Some((proxies, new_deposit))
             }
@@ -845,11 +898,88 @@ impl<T: Config> Pallet<T> {
             .ok_or(Error::<T>::NotProxy)?)
     }
 
+    /// The remaining spending budget of `delegate` over `real` in the current window, if the
+    /// proxy used by default for `delegate` has a spending limit.
+    pub fn remaining_allowance(
+        real: &T::AccountId,
+        delegate: &T::AccountId,
+    ) -> Option<BalanceOf<T>> {
+        let def = Self::find_proxy(real, delegate, None).ok()?;
+        let (budget, window) = T::SpendingLimit::limit(&def.proxy_type)?;
+        let spent = Self::spending_in_window(real, delegate, window)
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |total, (_, amount)| {
+                total.saturating_add(*amount)
+            });
+        Some(budget.saturating_sub(spent))
+    }
+
+    /// Ensures a spending-limited proxy type has a non-empty budget window, since a zero
+    /// window would forget every spend and only cap single calls.
+    fn ensure_valid_spending_limit(proxy_type: &T::ProxyType) -> DispatchResult {
+        if let Some((_, window)) = T::SpendingLimit::limit(proxy_type) {
+            ensure!(!window.is_zero(), Error::<T>::ZeroSpendingWindow);
+        }
+        Ok(())
+    }
+
+    /// The spends of `delegate` over `real` that are still inside the budget window.
+    fn spending_in_window(
+        real: &T::AccountId,
+        delegate: &T::AccountId,
+        window: BlockNumberFor<T>,
+    ) -> BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), T::MaxSpendingEntries> {
+        let now = system::Pallet::<T>::block_number();
+        let mut spending = ProxySpending::<T>::get(real, delegate);
+        spending.retain(|(at, _)| now.saturating_sub(*at) < window);
+        spending
+    }
+
+    /// Dispatches the call through the proxy, charging it to the proxy's spending budget.
+    ///
+    /// The spend is only recorded if the call succeeds.
+    fn do_limited_proxy(
+        def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+        real: T::AccountId,
+        call: <T as Config>::RuntimeCall,
+    ) -> DispatchResult {
+        let Some((budget, window)) = T::SpendingLimit::limit(&def.proxy_type) else {
+            let _ = Self::do_proxy(def, real, call);
+            return Ok(());
+        };
+
+        let delegate = def.delegate.clone();
+        let amount = T::SpendingLimit::spent(&call);
+        let mut spending = Self::spending_in_window(&real, &delegate, window);
+        let spent = spending
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |total, (_, amount)| {
+                total.saturating_add(*amount)
+            });
+        ensure!(
+            spent.saturating_add(amount) <= budget,
+            Error::<T>::SpendingLimitExceeded
+        );
+
+        if Self::do_proxy(def, real.clone(), call).is_ok() && !amount.is_zero() {
+            let now = system::Pallet::<T>::block_number();
+            if spending.try_push((now, amount)).is_err() {
+                // Merging into the latest spend only keeps it in the window for longer.
+                if let Some((at, latest)) = spending.last_mut() {
+                    *at = now;
+                    *latest = latest.saturating_add(amount);
+                }
+            }
+            ProxySpending::<T>::insert(&real, &delegate, spending);
+        }
+        Ok(())
+    }
+
     fn do_proxy(
         def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
         real: T::AccountId,
         call: <T as Config>::RuntimeCall,
-    ) {
+    ) -> DispatchResult {
         // This is a freshly authenticated new account, the origin restrictions doesn't apply.
         let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
         origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
@@ -874,10 +1004,9 @@ impl<T: Config> Pallet<T> {
                 _ => def.proxy_type.filter(c),
             }
         });
-        let e = call.dispatch(origin);
-        Self::deposit_event(Event::ProxyExecuted {
-            result: e.map(|_| ()).map_err(|e| e.error),
-        });
+        let result = call.dispatch(origin).map(|_| ()).map_err(|e| e.error);
+        Self::deposit_event(Event::ProxyExecuted { result });
+        result
     }
 
     /// Removes all proxy delegates for a given delegator.
@@ -887,5 +1016,6 @@ impl<T: Config> Pallet<T> {
     pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
         let (_, old_deposit) = Proxies::<T>::take(delegator);
         T::Currency::unreserve(delegator, old_deposit);
+        let _ = ProxySpending::<T>::clear_prefix(delegator, u32::MAX, None);
     }
 }
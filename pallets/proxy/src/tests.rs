@@ -61,6 +61,8 @@ impl pallet_utility::Config for Test {
     type RuntimeCall = RuntimeCall;
     type PalletsOrigin = OriginCaller;
     type WeightInfo = ();
+    type Predicate = ();
+    type PredicateEvaluator = ();
 }
 
 #[derive(
@@ -80,6 +82,8 @@ pub enum ProxyType {
     Any,
     JustTransfer,
     JustUtility,
+    LimitedTransfer,
+    UnwindowedTransfer,
 }
 impl Default for ProxyType {
     fn default() -> Self {
@@ -97,6 +101,12 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 )
             }
             ProxyType::JustUtility => matches!(c, RuntimeCall::Utility { .. }),
+            ProxyType::LimitedTransfer | ProxyType::UnwindowedTransfer => {
+                matches!(
+                    c,
+                    RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+                )
+            }
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
@@ -114,6 +124,26 @@ impl Contains<RuntimeCall> for BaseFilter {
         }
     }
 }
+pub struct TestSpendingLimit;
+impl SpendingLimit<ProxyType, RuntimeCall, u64, u64> for TestSpendingLimit {
+    fn limit(proxy_type: &ProxyType) -> Option<(u64, u64)> {
+        match proxy_type {
+            // 5 per 10 blocks
+            ProxyType::LimitedTransfer => Some((5, 10)),
+            ProxyType::UnwindowedTransfer => Some((5, 0)),
+            _ => None,
+        }
+    }
+
+    fn spent(call: &RuntimeCall) -> u64 {
+        match call {
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                value, ..
+            }) => *value,
+            _ => 0,
+        }
+    }
+}
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -127,6 +157,8 @@ impl Config for Test {
     type MaxPending = ConstU32<2>;
     type AnnouncementDepositBase = ConstU64<1>;
     type AnnouncementDepositFactor = ConstU64<1>;
+    type SpendingLimit = TestSpendingLimit;
+    type MaxSpendingEntries = ConstU32<2>;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -963,3 +995,137 @@ fn pure_works() {
         );
     });
 }
+
+#[test]
+fn spending_limit_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(1),
+            3,
+            ProxyType::LimitedTransfer,
+            0
+        ));
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(5));
+        assert_eq!(Proxy::remaining_allowance(&2, &3), None);
+
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(3),
+            1,
+            None,
+            Box::new(call_transfer(6, 2))
+        ));
+        System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(3));
+
+        // Repeating the call cannot drain more than the budget.
+        assert_noop!(
+            Proxy::proxy(
+                RuntimeOrigin::signed(3),
+                1,
+                None,
+                Box::new(call_transfer(6, 4))
+            ),
+            Error::<Test>::SpendingLimitExceeded
+        );
+
+        // Failed calls do not use the budget.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(1),
+            7,
+            4
+        ));
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(3),
+            1,
+            None,
+            Box::new(call_transfer(6, 3))
+        ));
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(3));
+
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(2),
+            1,
+            5
+        ));
+        System::set_block_number(5);
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(3),
+            1,
+            None,
+            Box::new(call_transfer(6, 2))
+        ));
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(1));
+        assert_eq!(Balances::free_balance(6), 4);
+
+        // The first spend leaves the window after 10 blocks.
+        System::set_block_number(11);
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(3));
+        System::set_block_number(15);
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(5));
+
+        // Removing the proxy clears its spending.
+        assert_ok!(Proxy::remove_proxy(
+            RuntimeOrigin::signed(1),
+            3,
+            ProxyType::LimitedTransfer,
+            0
+        ));
+        assert!(ProxySpending::<Test>::get(1, 3).is_empty());
+    });
+}
+
+#[test]
+fn spending_limit_merges_spends_when_full() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(1),
+            3,
+            ProxyType::LimitedTransfer,
+            0
+        ));
+
+        for block in 1..=3 {
+            System::set_block_number(block);
+            assert_ok!(Proxy::proxy(
+                RuntimeOrigin::signed(3),
+                1,
+                None,
+                Box::new(call_transfer(6, 1))
+            ));
+        }
+        assert_eq!(
+            ProxySpending::<Test>::get(1, 3).into_inner(),
+            vec![(1, 1), (3, 2)]
+        );
+
+        // The merged spend stays in the window until its latest block leaves it.
+        System::set_block_number(11);
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(3));
+        System::set_block_number(13);
+        assert_eq!(Proxy::remaining_allowance(&1, &3), Some(5));
+    });
+}
+
+#[test]
+fn spending_limit_rejects_zero_window() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Proxy::add_proxy(
+                RuntimeOrigin::signed(1),
+                3,
+                ProxyType::UnwindowedTransfer,
+                0
+            ),
+            Error::<Test>::ZeroSpendingWindow
+        );
+        assert_noop!(
+            Proxy::create_pure(
+                RuntimeOrigin::signed(1),
+                ProxyType::UnwindowedTransfer,
+                0,
+                0
+            ),
+            Error::<Test>::ZeroSpendingWindow
+        );
+    });
+}
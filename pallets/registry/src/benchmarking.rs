@@ -7,9 +7,12 @@ use super::*;
 use crate::Pallet as Registry;
 use frame_benchmarking::v1::account;
 use frame_benchmarking::v2::*;
-use frame_support::traits::tokens::fungible::Mutate;
+use frame_support::{
+    BoundedVec,
+    traits::{EnsureOrigin, tokens::fungible::Mutate},
+};
 use frame_system::RawOrigin;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 use sp_runtime::traits::Bounded;
 
@@ -39,6 +42,75 @@ fn create_identity_info<T: Config>(_num_fields: u32) -> IdentityInfo<T::MaxAddit
     }
 }
 
+// Gives `who` enough balance to pay every deposit and fee in the benchmarks.
+fn fund<T: Config>(who: &T::AccountId) {
+    let _ = T::Currency::set_balance(who, BalanceOf::<T>::max_value() / 1_000u32.into());
+}
+
+// Registers an identity for `who`, paid for by `who` itself.
+fn add_identity<T: Config>(who: &T::AccountId) {
+    IdentityOf::<T>::insert(
+        who,
+        Registration {
+            deposit: Zero::zero(),
+            info: create_identity_info::<T>(0),
+        },
+    );
+}
+
+// Links `count` sub-accounts to the identity of `who`, returning the last one.
+fn add_subs<T: Config>(who: &T::AccountId, count: u32) -> Option<T::AccountId> {
+    let mut last = None;
+    for index in 0..count {
+        let sub = account::<T::AccountId>("sub", index, 0u32);
+        Registry::<T>::add_sub(
+            RawOrigin::Signed(who.clone()).into(),
+            sub.clone(),
+            Data::None,
+        )
+        .unwrap();
+        last = Some(sub);
+    }
+    last
+}
+
+// Registers an identity for `who` and links the maximum number of sub-accounts to it.
+fn add_identity_with_subs<T: Config>(who: &T::AccountId) -> T::AccountId {
+    fund::<T>(who);
+    add_identity::<T>(who);
+    add_subs::<T>(who, T::MaxSubAccounts::get()).unwrap()
+}
+
+// Adds `count` registrars charging a fee of 10 and returns their accounts.
+fn add_registrar_accounts<T: Config>(count: u32) -> Vec<T::AccountId> {
+    let registrars = (0..count)
+        .map(|index| account::<T::AccountId>("registrar", index, 0u32))
+        .collect::<Vec<_>>();
+    for registrar in registrars.iter() {
+        fund::<T>(registrar);
+    }
+    Registrars::<T>::put(
+        BoundedVec::try_from(
+            registrars
+                .iter()
+                .map(|registrar| {
+                    Some(RegistrarInfo {
+                        account: registrar.clone(),
+                        fee: 10u32.into(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        )
+        .unwrap(),
+    );
+    registrars
+}
+
+// Adds a registrar charging a fee of 10 and returns its account.
+fn add_registrar_account<T: Config>() -> T::AccountId {
+    add_registrar_accounts::<T>(1).remove(0)
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -47,25 +119,33 @@ mod benchmarks {
     fn set_identity() {
         // The target user
         let caller: T::AccountId = whitelisted_caller();
-        let _ = T::Currency::set_balance(&caller, BalanceOf::<T>::max_value());
+        fund::<T>(&caller);
+        let identified = account::<T::AccountId>("account", 0, 0u32);
+
+        // Worst case: every registrar judged the previous identity.
+        for registrar_index in 0..T::MaxRegistrars::get() {
+            Judgements::<T>::insert(&identified, registrar_index, Judgement::KnownGood);
+        }
 
         #[extrinsic_call]
         _(
             RawOrigin::Signed(caller.clone()),
-            account::<T::AccountId>("account", 0, 0u32),
+            identified.clone(),
             Box::new(create_identity_info::<T>(0)),
         );
 
-        assert_last_event::<T>(Event::<T>::IdentitySet { who: caller }.into());
+        assert_last_event::<T>(Event::<T>::IdentitySet { who: identified }.into());
+        assert_eq!(Registry::<T>::get_judgements(&identified).len(), 0);
     }
 
     #[benchmark]
     fn clear_identity() {
         // The target user
         let caller: T::AccountId = whitelisted_caller();
-        let _ = T::Currency::set_balance(&caller, BalanceOf::<T>::max_value());
+        fund::<T>(&caller);
 
         let vali_account = account::<T::AccountId>("account", 0, 0u32);
+        fund::<T>(&vali_account);
 
         Registry::<T>::set_identity(
             RawOrigin::Signed(caller.clone()).into(),
@@ -74,11 +154,188 @@ mod benchmarks {
         )
         .unwrap();
 
+        // Worst case: the maximum number of sub-accounts, and a pending fee with every
+        // registrar.
+        add_subs::<T>(&vali_account, T::MaxSubAccounts::get());
+        add_registrar_accounts::<T>(T::MaxRegistrars::get());
+        for registrar_index in 0..T::MaxRegistrars::get() {
+            Registry::<T>::request_judgement(
+                RawOrigin::Signed(vali_account.clone()).into(),
+                registrar_index,
+                10u32.into(),
+            )
+            .unwrap();
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), vali_account.clone());
+
+        assert_last_event::<T>(Event::<T>::IdentityDissolved { who: vali_account }.into());
+    }
+
+    #[benchmark]
+    fn add_sub() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        add_identity::<T>(&caller);
+        // Worst case: the new sub-account fills the last slot.
+        let linked = T::MaxSubAccounts::get().saturating_sub(1);
+        add_subs::<T>(&caller, linked);
+        let sub = account::<T::AccountId>("sub", linked, 0u32);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), sub.clone(), Data::None);
+
+        assert_last_event::<T>(Event::<T>::SubIdentityAdded { sub, main: caller }.into());
+    }
+
+    #[benchmark]
+    fn rename_sub() {
+        let caller: T::AccountId = whitelisted_caller();
+        let sub = add_identity_with_subs::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), sub.clone(), Data::None);
+
+        assert_last_event::<T>(Event::<T>::SubIdentityRenamed { sub, main: caller }.into());
+    }
+
+    #[benchmark]
+    fn remove_sub() {
+        let caller: T::AccountId = whitelisted_caller();
+        let sub = add_identity_with_subs::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), sub.clone());
+
+        assert_last_event::<T>(Event::<T>::SubIdentityRemoved { sub, main: caller }.into());
+    }
+
+    #[benchmark]
+    fn quit_sub() {
+        let caller: T::AccountId = whitelisted_caller();
+        let sub = add_identity_with_subs::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(sub.clone()));
+
+        assert_last_event::<T>(Event::<T>::SubIdentityRemoved { sub, main: caller }.into());
+    }
+
+    #[benchmark]
+    fn add_registrar() -> Result<(), BenchmarkError> {
+        let origin =
+            T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        // Worst case: the new registrar fills the last slot.
+        let registrar_index = T::MaxRegistrars::get().saturating_sub(1);
+        add_registrar_accounts::<T>(registrar_index);
+        let registrar = account::<T::AccountId>("registrar", registrar_index, 0u32);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, registrar);
+
+        assert_last_event::<T>(Event::<T>::RegistrarAdded { registrar_index }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_registrar() -> Result<(), BenchmarkError> {
+        let origin =
+            T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        add_registrar_account::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0);
+
+        assert_last_event::<T>(Event::<T>::RegistrarRemoved { registrar_index: 0 }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_fee() {
+        let registrar = add_registrar_account::<T>();
+        let fee: BalanceOf<T> = 10u32.into();
+
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), vali_account);
+        _(RawOrigin::Signed(registrar), 0, fee);
+
+        assert_last_event::<T>(
+            Event::<T>::RegistrarFeeSet {
+                registrar_index: 0,
+                fee,
+            }
+            .into(),
+        );
+    }
 
-        assert_last_event::<T>(Event::<T>::IdentityDissolved { who: caller }.into());
+    #[benchmark]
+    fn request_judgement() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        add_identity::<T>(&caller);
+        add_registrar_account::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, 10u32.into());
+
+        assert_last_event::<T>(
+            Event::<T>::JudgementRequested {
+                who: caller,
+                registrar_index: 0,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn cancel_request() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        add_identity::<T>(&caller);
+        add_registrar_account::<T>();
+        Registry::<T>::request_judgement(RawOrigin::Signed(caller.clone()).into(), 0, 10u32.into())
+            .unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0);
+
+        assert_last_event::<T>(
+            Event::<T>::JudgementUnrequested {
+                who: caller,
+                registrar_index: 0,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn provide_judgement() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        add_identity::<T>(&caller);
+        let registrar = add_registrar_account::<T>();
+        Registry::<T>::request_judgement(RawOrigin::Signed(caller.clone()).into(), 0, 10u32.into())
+            .unwrap();
+        let identity =
+            <T::Hashing as sp_runtime::traits::Hash>::hash_of(&create_identity_info::<T>(0));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(registrar),
+            0,
+            caller.clone(),
+            Judgement::KnownGood,
+            identity,
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::JudgementGiven {
+                target: caller,
+                registrar_index: 0,
+            }
+            .into(),
+        );
     }
 
-    //impl_benchmark_test_suite!(Registry, crate::mock::new_test_ext(), crate::mock::Test);
+    impl_benchmark_test_suite!(Registry, crate::mock::new_test_ext(), crate::mock::Test);
 }
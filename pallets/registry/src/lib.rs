@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod mock;
 #[cfg(test)]
 mod tests;
 
@@ -11,12 +13,13 @@ pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
 
+use frame_support::ensure;
 use frame_support::traits::tokens::{
-    Precision,
+    Fortitude, Precision, Restriction,
     fungible::{self, MutateHold as _},
 };
 use sp_runtime::{Saturating, traits::Zero};
-use sp_std::boxed::Box;
+use sp_std::{boxed::Box, vec::Vec};
 
 type BalanceOf<T> =
     <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -62,6 +65,21 @@ pub mod pallet {
 
         /// Reasons for putting funds on hold.
         type RuntimeHoldReason: From<HoldReason>;
+
+        /// The origin which may add or remove registrars.
+        type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of registrars allowed in the system.
+        #[pallet::constant]
+        type MaxRegistrars: Get<u32>;
+
+        /// Maximum number of sub-accounts that can be linked to a single identity.
+        #[pallet::constant]
+        type MaxSubAccounts: Get<u32>;
+
+        /// The amount held on deposit for each sub-account linked to an identity.
+        #[pallet::constant]
+        type SubAccountDeposit: Get<BalanceOf<Self>>;
     }
 
     #[pallet::event]
@@ -77,6 +95,65 @@ pub mod pallet {
             /// The account that dissolved the identity
             who: T::AccountId,
         },
+        /// Emitted when a sub-account is linked to an identity
+        SubIdentityAdded {
+            /// The linked sub-account
+            sub: T::AccountId,
+            /// The account holding the parent identity
+            main: T::AccountId,
+        },
+        /// Emitted when a sub-account is renamed
+        SubIdentityRenamed {
+            /// The renamed sub-account
+            sub: T::AccountId,
+            /// The account holding the parent identity
+            main: T::AccountId,
+        },
+        /// Emitted when a sub-account is unlinked from an identity, by either side
+        SubIdentityRemoved {
+            /// The unlinked sub-account
+            sub: T::AccountId,
+            /// The account holding the parent identity
+            main: T::AccountId,
+        },
+        /// Emitted when governance adds a registrar
+        RegistrarAdded {
+            /// The index of the new registrar
+            registrar_index: RegistrarIndex,
+        },
+        /// Emitted when governance removes a registrar
+        RegistrarRemoved {
+            /// The index of the removed registrar
+            registrar_index: RegistrarIndex,
+        },
+        /// Emitted when a registrar changes its judgement fee
+        RegistrarFeeSet {
+            /// The index of the registrar
+            registrar_index: RegistrarIndex,
+            /// The new fee
+            fee: BalanceOf<T>,
+        },
+        /// Emitted when a user requests a judgement from a registrar
+        JudgementRequested {
+            /// The account requesting the judgement
+            who: T::AccountId,
+            /// The index of the registrar
+            registrar_index: RegistrarIndex,
+        },
+        /// Emitted when a user withdraws a judgement request
+        JudgementUnrequested {
+            /// The account withdrawing the request
+            who: T::AccountId,
+            /// The index of the registrar
+            registrar_index: RegistrarIndex,
+        },
+        /// Emitted when a registrar provides a judgement
+        JudgementGiven {
+            /// The account whose identity was judged
+            target: T::AccountId,
+            /// The index of the registrar
+            registrar_index: RegistrarIndex,
+        },
     }
 
     #[pallet::error]
@@ -87,6 +164,32 @@ pub mod pallet {
         TooManyFieldsInIdentityInfo,
         /// Account doesn't have a registered identity
         NotRegistered,
+        /// The sub-account is already linked to an identity
+        AlreadyClaimed,
+        /// An identity cannot be its own sub-account
+        InvalidSubAccount,
+        /// The account is not a sub-account of any identity
+        NotSub,
+        /// The sub-account is linked to another identity
+        NotOwned,
+        /// Too many sub-accounts are linked to the identity
+        TooManySubAccounts,
+        /// The maximum number of registrars has been reached
+        TooManyRegistrars,
+        /// No registrar exists at the given index
+        InvalidIndex,
+        /// The registrar fee is higher than the given maximum
+        FeeChanged,
+        /// The existing judgement cannot be replaced by a request
+        StickyJudgement,
+        /// No judgement request is pending with the registrar
+        NotFound,
+        /// The registrar already provided a judgement for the request
+        JudgementGiven,
+        /// A registrar cannot provide a fee-paid judgement
+        InvalidJudgement,
+        /// The identity changed since the registrar reviewed it
+        JudgementForDifferentIdentity,
     }
 
     /// Enum to hold reasons for putting funds on hold.
@@ -94,6 +197,10 @@ pub mod pallet {
     pub enum HoldReason {
         /// Funds are held for identity registration
         RegistryIdentity,
+        /// Funds are held for linking a sub-account to an identity
+        RegistrySubAccount,
+        /// Funds are held as the fee of a pending registrar judgement
+        RegistryJudgement,
     }
 
     /// Identity data by account
@@ -107,12 +214,51 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// The parent identity of a sub-account, together with the name of the sub-account
+    #[pallet::storage]
+    pub(super) type SuperOf<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, (T::AccountId, Data), OptionQuery>;
+
+    /// The total deposit held for, and the list of, sub-accounts linked to an identity
+    #[pallet::storage]
+    pub(super) type SubsOf<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        (BalanceOf<T>, BoundedVec<T::AccountId, T::MaxSubAccounts>),
+        ValueQuery,
+    >;
+
+    /// Judgements provided by registrars on an identity, by registrar index
+    #[pallet::storage]
+    pub(super) type Judgements<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        RegistrarIndex,
+        Judgement<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// The registrars, by index. Removed registrars leave an empty slot so indices stay stable
+    #[pallet::storage]
+    pub(super) type Registrars<T: Config> = StorageValue<
+        _,
+        BoundedVec<Option<RegistrarInfo<BalanceOf<T>, T::AccountId>>, T::MaxRegistrars>,
+        ValueQuery,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register an identity for an account. This will overwrite any existing identity.
         #[pallet::call_index(0)]
         #[pallet::weight((
-			T::WeightInfo::set_identity(),
+			T::WeightInfo::set_identity()
+				.saturating_add(T::DbWeight::get().reads_writes(
+					T::MaxRegistrars::get().into(),
+					T::MaxRegistrars::get().into(),
+				)),
 			DispatchClass::Operational
 		))]
         pub fn set_identity(
@@ -166,14 +312,21 @@ pub mod pallet {
             }
 
             <IdentityOf<T>>::insert(&identified, id);
+            Self::clear_stale_judgements(&identified);
             Self::deposit_event(Event::IdentitySet { who: identified });
 
             Ok(())
         }
 
-        /// Clear the identity of an account.
+        /// Clear the identity of an account, together with its sub-accounts and judgements.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::clear_identity())]
+        #[pallet::weight({
+			let items: u64 = T::MaxSubAccounts::get()
+				.saturating_add(T::MaxRegistrars::get())
+				.into();
+			T::WeightInfo::clear_identity()
+				.saturating_add(T::DbWeight::get().reads_writes(items.saturating_add(1), items.saturating_add(1)))
+		})]
         pub fn clear_identity(
             origin: OriginFor<T>,
             identified: T::AccountId,
@@ -191,10 +344,402 @@ pub mod pallet {
             );
             debug_assert!(release_res.is_ok_and(|released_amount| released_amount == deposit));
 
+            let (subs_deposit, subs) = <SubsOf<T>>::take(&identified);
+            for sub in subs.iter() {
+                <SuperOf<T>>::remove(sub);
+            }
+            let release_res = T::Currency::release(
+                &HoldReason::RegistrySubAccount.into(),
+                &identified,
+                subs_deposit,
+                Precision::BestEffort,
+            );
+            debug_assert!(release_res.is_ok_and(|released_amount| released_amount == subs_deposit));
+
+            for (_, judgement) in <Judgements<T>>::drain_prefix(&identified) {
+                if let Judgement::FeePaid(fee) = judgement {
+                    let release_res = T::Currency::release(
+                        &HoldReason::RegistryJudgement.into(),
+                        &identified,
+                        fee,
+                        Precision::BestEffort,
+                    );
+                    debug_assert!(release_res.is_ok_and(|released_amount| released_amount == fee));
+                }
+            }
+
             Self::deposit_event(Event::IdentityDissolved { who: identified });
 
             Ok(().into())
         }
+
+        /// Link a sub-account to the identity of the sender, under the given name.
+        ///
+        /// The sender must have a registered identity. `SubAccountDeposit` is held from the
+        /// sender for as long as the link exists.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::add_sub())]
+        pub fn add_sub(origin: OriginFor<T>, sub: T::AccountId, data: Data) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <IdentityOf<T>>::contains_key(&who),
+                Error::<T>::NotRegistered
+            );
+            ensure!(sub != who, Error::<T>::InvalidSubAccount);
+            ensure!(
+                !<SuperOf<T>>::contains_key(&sub),
+                Error::<T>::AlreadyClaimed
+            );
+
+            let (mut subs_deposit, mut subs) = <SubsOf<T>>::get(&who);
+            subs.try_push(sub.clone())
+                .map_err(|_| Error::<T>::TooManySubAccounts)?;
+
+            let deposit = T::SubAccountDeposit::get();
+            T::Currency::hold(&HoldReason::RegistrySubAccount.into(), &who, deposit)?;
+            subs_deposit = subs_deposit.saturating_add(deposit);
+
+            <SuperOf<T>>::insert(&sub, (who.clone(), data));
+            <SubsOf<T>>::insert(&who, (subs_deposit, subs));
+            Self::deposit_event(Event::SubIdentityAdded { sub, main: who });
+
+            Ok(())
+        }
+
+        /// Change the name of a sub-account linked to the identity of the sender.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::rename_sub())]
+        pub fn rename_sub(origin: OriginFor<T>, sub: T::AccountId, data: Data) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owned_sub(&who, &sub)?;
+
+            <SuperOf<T>>::insert(&sub, (who.clone(), data));
+            Self::deposit_event(Event::SubIdentityRenamed { sub, main: who });
+
+            Ok(())
+        }
+
+        /// Unlink a sub-account from the identity of the sender, releasing its deposit.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::remove_sub())]
+        pub fn remove_sub(origin: OriginFor<T>, sub: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_owned_sub(&who, &sub)?;
+
+            let deposit = Self::unlink_sub(&who, &sub);
+            let release_res = T::Currency::release(
+                &HoldReason::RegistrySubAccount.into(),
+                &who,
+                deposit,
+                Precision::BestEffort,
+            );
+            debug_assert!(release_res.is_ok_and(|released_amount| released_amount == deposit));
+
+            Self::deposit_event(Event::SubIdentityRemoved { sub, main: who });
+
+            Ok(())
+        }
+
+        /// Unlink the sender from the identity it is a sub-account of.
+        ///
+        /// The deposit held for the link is paid to the sender, so that a parent cannot claim
+        /// an account without its consent at no cost. If the sender cannot receive it, for
+        /// instance because it would stay below the existential deposit, the deposit is
+        /// released to the parent instead.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::quit_sub())]
+        pub fn quit_sub(origin: OriginFor<T>) -> DispatchResult {
+            let sub = ensure_signed(origin)?;
+            let (main, _) = <SuperOf<T>>::get(&sub).ok_or(Error::<T>::NotSub)?;
+
+            let deposit = Self::unlink_sub(&main, &sub);
+            let transfer_res = T::Currency::transfer_on_hold(
+                &HoldReason::RegistrySubAccount.into(),
+                &main,
+                &sub,
+                deposit,
+                Precision::Exact,
+                Restriction::Free,
+                Fortitude::Polite,
+            );
+            if transfer_res.is_err() {
+                let release_res = T::Currency::release(
+                    &HoldReason::RegistrySubAccount.into(),
+                    &main,
+                    deposit,
+                    Precision::BestEffort,
+                );
+                debug_assert!(release_res.is_ok_and(|released_amount| released_amount == deposit));
+            }
+
+            Self::deposit_event(Event::SubIdentityRemoved { sub, main });
+
+            Ok(())
+        }
+
+        /// Add a registrar, with a zero fee, that may provide judgements on identities.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::add_registrar())]
+        pub fn add_registrar(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+
+            let registrar_index = <Registrars<T>>::try_mutate(
+                |registrars| -> Result<RegistrarIndex, DispatchError> {
+                    registrars
+                        .try_push(Some(RegistrarInfo {
+                            account,
+                            fee: Zero::zero(),
+                        }))
+                        .map_err(|_| Error::<T>::TooManyRegistrars)?;
+                    Ok((registrars.len() as RegistrarIndex).saturating_sub(1))
+                },
+            )?;
+            Self::deposit_event(Event::RegistrarAdded { registrar_index });
+
+            Ok(())
+        }
+
+        /// Remove a registrar. Judgements it already provided are kept.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_registrar())]
+        pub fn remove_registrar(
+            origin: OriginFor<T>,
+            #[pallet::compact] registrar_index: RegistrarIndex,
+        ) -> DispatchResult {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+
+            <Registrars<T>>::try_mutate(|registrars| -> DispatchResult {
+                let slot = registrars
+                    .get_mut(registrar_index as usize)
+                    .filter(|slot| slot.is_some())
+                    .ok_or(Error::<T>::InvalidIndex)?;
+                *slot = None;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::RegistrarRemoved { registrar_index });
+
+            Ok(())
+        }
+
+        /// Set the fee required for a judgement by the registrar at `registrar_index`.
+        ///
+        /// The sender must be the account of the registrar.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_fee())]
+        pub fn set_fee(
+            origin: OriginFor<T>,
+            #[pallet::compact] registrar_index: RegistrarIndex,
+            #[pallet::compact] fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            <Registrars<T>>::try_mutate(|registrars| -> DispatchResult {
+                let registrar = registrars
+                    .get_mut(registrar_index as usize)
+                    .and_then(|slot| slot.as_mut())
+                    .filter(|registrar| registrar.account == who)
+                    .ok_or(Error::<T>::InvalidIndex)?;
+                registrar.fee = fee;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::RegistrarFeeSet {
+                registrar_index,
+                fee,
+            });
+
+            Ok(())
+        }
+
+        /// Request a judgement on the identity of the sender from a registrar.
+        ///
+        /// The fee of the registrar is held from the sender until the judgement is provided,
+        /// and must not exceed `max_fee`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::request_judgement())]
+        pub fn request_judgement(
+            origin: OriginFor<T>,
+            #[pallet::compact] registrar_index: RegistrarIndex,
+            #[pallet::compact] max_fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <IdentityOf<T>>::contains_key(&who),
+                Error::<T>::NotRegistered
+            );
+
+            let registrar = Self::registrar(registrar_index).ok_or(Error::<T>::InvalidIndex)?;
+            ensure!(registrar.fee <= max_fee, Error::<T>::FeeChanged);
+            if let Some(judgement) = <Judgements<T>>::get(&who, registrar_index) {
+                ensure!(!judgement.is_sticky(), Error::<T>::StickyJudgement);
+            }
+
+            T::Currency::hold(&HoldReason::RegistryJudgement.into(), &who, registrar.fee)?;
+            <Judgements<T>>::insert(&who, registrar_index, Judgement::FeePaid(registrar.fee));
+            Self::deposit_event(Event::JudgementRequested {
+                who,
+                registrar_index,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw a pending judgement request, releasing the held fee.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::cancel_request())]
+        pub fn cancel_request(
+            origin: OriginFor<T>,
+            #[pallet::compact] registrar_index: RegistrarIndex,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let judgement =
+                <Judgements<T>>::get(&who, registrar_index).ok_or(Error::<T>::NotFound)?;
+            let Judgement::FeePaid(fee) = judgement else {
+                return Err(Error::<T>::JudgementGiven.into());
+            };
+
+            let release_res = T::Currency::release(
+                &HoldReason::RegistryJudgement.into(),
+                &who,
+                fee,
+                Precision::BestEffort,
+            );
+            debug_assert!(release_res.is_ok_and(|released_amount| released_amount == fee));
+
+            <Judgements<T>>::remove(&who, registrar_index);
+            Self::deposit_event(Event::JudgementUnrequested {
+                who,
+                registrar_index,
+            });
+
+            Ok(())
+        }
+
+        /// Provide a judgement on the identity of `target`.
+        ///
+        /// The sender must be the account of the registrar at `registrar_index`. `identity` is
+        /// the hash of the `IdentityInfo` that was reviewed, so that a judgement cannot apply to
+        /// an identity changed in the meantime. A pending fee is paid to the registrar.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::provide_judgement())]
+        pub fn provide_judgement(
+            origin: OriginFor<T>,
+            #[pallet::compact] registrar_index: RegistrarIndex,
+            target: T::AccountId,
+            judgement: Judgement<BalanceOf<T>>,
+            identity: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
+
+            let registrar = Self::registrar(registrar_index)
+                .filter(|registrar| registrar.account == who)
+                .ok_or(Error::<T>::InvalidIndex)?;
+            let registration = <IdentityOf<T>>::get(&target).ok_or(Error::<T>::NotRegistered)?;
+            ensure!(
+                <T::Hashing as sp_runtime::traits::Hash>::hash_of(&registration.info) == identity,
+                Error::<T>::JudgementForDifferentIdentity
+            );
+
+            if let Some(Judgement::FeePaid(fee)) = <Judgements<T>>::get(&target, registrar_index) {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::RegistryJudgement.into(),
+                    &target,
+                    &registrar.account,
+                    fee,
+                    Precision::BestEffort,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+            }
+
+            <Judgements<T>>::insert(&target, registrar_index, judgement);
+            Self::deposit_event(Event::JudgementGiven {
+                target,
+                registrar_index,
+            });
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The registrar at `registrar_index`, if it exists and was not removed.
+    fn registrar(
+        registrar_index: RegistrarIndex,
+    ) -> Option<RegistrarInfo<BalanceOf<T>, T::AccountId>> {
+        <Registrars<T>>::get()
+            .get(registrar_index as usize)
+            .cloned()
+            .flatten()
+    }
+
+    /// Ensure `sub` is a sub-account of the identity held by `main`.
+    fn ensure_owned_sub(main: &T::AccountId, sub: &T::AccountId) -> Result<(), Error<T>> {
+        let (parent, _) = <SuperOf<T>>::get(sub).ok_or(Error::<T>::NotSub)?;
+        ensure!(&parent == main, Error::<T>::NotOwned);
+        Ok(())
+    }
+
+    /// Remove the link between `main` and `sub`, returning the deposit held for it.
+    fn unlink_sub(main: &T::AccountId, sub: &T::AccountId) -> BalanceOf<T> {
+        <SuperOf<T>>::remove(sub);
+        <SubsOf<T>>::mutate_exists(main, |entry| {
+            let Some((subs_deposit, subs)) = entry else {
+                return Zero::zero();
+            };
+            subs.retain(|linked| linked != sub);
+            let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
+            *subs_deposit = subs_deposit.saturating_sub(deposit);
+            if subs.is_empty() {
+                *entry = None;
+            }
+            deposit
+        })
+    }
+
+    /// Drop the judgements on an updated identity, except for pending requests and erroneous
+    /// judgements which must survive a change of the data.
+    fn clear_stale_judgements(identified: &T::AccountId) {
+        let stale = <Judgements<T>>::iter_prefix(identified)
+            .filter(|(_, judgement)| !judgement.is_sticky())
+            .map(|(registrar_index, _)| registrar_index)
+            .collect::<Vec<_>>();
+        for registrar_index in stale {
+            <Judgements<T>>::remove(identified, registrar_index);
+        }
+    }
+
+    /// The parent identity and name of a sub-account.
+    pub fn get_super_of(sub: &T::AccountId) -> Option<(T::AccountId, Data)> {
+        <SuperOf<T>>::get(sub)
+    }
+
+    /// The sub-accounts linked to an identity.
+    pub fn get_subs_of(main: &T::AccountId) -> Vec<T::AccountId> {
+        <SubsOf<T>>::get(main).1.into_inner()
+    }
+
+    /// The judgements provided on, or requested for, an identity.
+    pub fn get_judgements(who: &T::AccountId) -> Vec<(RegistrarIndex, Judgement<BalanceOf<T>>)> {
+        <Judgements<T>>::iter_prefix(who).collect()
+    }
+
+    /// All registrar slots, with `None` for removed registrars.
+    pub fn get_registrars() -> Vec<Option<RegistrarInfo<BalanceOf<T>, T::AccountId>>> {
+        <Registrars<T>>::get().into_inner()
+    }
+
+    /// The parent of a sub-account, if its identity carries a favourable judgement from a
+    /// registrar that has not been removed.
+    pub fn get_verified_parent(sub: &T::AccountId) -> Option<T::AccountId> {
+        let (main, _) = <SuperOf<T>>::get(sub)?;
+        <Judgements<T>>::iter_prefix(&main)
+            .any(|(registrar_index, judgement)| {
+                judgement.is_verified() && Self::registrar(registrar_index).is_some()
+            })
+            .then_some(main)
     }
 }
 // Interfaces to interact with other pallets
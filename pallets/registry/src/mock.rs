@@ -1,9 +1,13 @@
-use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use crate as pallet_registry;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
+    traits::{BlakeTwo256, IdentityLookup},
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -13,10 +17,12 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system = 1,
-        TemplateModule: pallet_template = 2,
+        Balances: pallet_balances = 2,
+        Registry: pallet_registry = 3,
     }
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
     type BlockWeights = ();
@@ -34,26 +40,63 @@ impl frame_system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
     type SS58Prefix = ConstU16<42>;
     type OnSetCode = ();
-    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub static ExistentialDeposit: u64 = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u64;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
+
+/// Identities cannot be registered for this account.
+pub const UNREGISTRABLE: u64 = 99;
+
+pub struct TestCanRegister;
+impl pallet_registry::CanRegisterIdentity<u64> for TestCanRegister {
+    fn can_register(_who: &u64, identified: &u64) -> bool {
+        *identified != UNREGISTRABLE
+    }
 }
 
 impl pallet_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type CanRegister = TestCanRegister;
     type WeightInfo = ();
-    type MaxAdditionalFields = frame_support::traits::ConstU32<16>;
-    type CanRegisterIdentity = ();
+
+    type MaxAdditionalFields = ConstU32<2>;
+    type InitialDeposit = ConstU64<10>;
+    type FieldDeposit = ConstU64<1>;
+    type RegistrarOrigin = EnsureRoot<u64>;
+    type MaxRegistrars = ConstU32<2>;
+    type MaxSubAccounts = ConstU32<2>;
+    type SubAccountDeposit = ConstU64<5>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .expect("Expected to not panic");
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100), (10, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .expect("Expected to not panic");
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
@@ -1 +1,375 @@
-// Testing
+use crate::{
+    Error, Event, HoldReason, IdentityInfo, IdentityOf, Judgement, RegistrarInfo, mock::*,
+    types::Data,
+};
+use frame_support::{BoundedVec, assert_noop, assert_ok, traits::fungible::InspectHold};
+use sp_core::H256;
+use sp_runtime::{
+    DispatchError,
+    traits::{BlakeTwo256, Hash},
+};
+
+fn data(name: &[u8]) -> Data {
+    Data::Raw(
+        name.to_vec()
+            .try_into()
+            .expect("name is shorter than 64 bytes"),
+    )
+}
+
+fn info(display: &[u8]) -> IdentityInfo<<Test as crate::Config>::MaxAdditionalFields> {
+    IdentityInfo {
+        display: data(display),
+        ..Default::default()
+    }
+}
+
+fn held(reason: HoldReason, who: u64) -> u64 {
+    Balances::balance_on_hold(&reason.into(), &who)
+}
+
+fn register(who: u64) {
+    assert_ok!(Registry::set_identity(
+        RuntimeOrigin::signed(who),
+        who,
+        Box::new(info(b"org"))
+    ));
+}
+
+#[test]
+fn set_identity_holds_deposit_per_field() {
+    new_test_ext().execute_with(|| {
+        let mut identity = info(b"org");
+        identity.additional = BoundedVec::truncate_from(vec![(data(b"key"), data(b"value"))]);
+        assert_ok!(Registry::set_identity(
+            RuntimeOrigin::signed(1),
+            1,
+            Box::new(identity)
+        ));
+        System::assert_last_event(Event::<Test>::IdentitySet { who: 1 }.into());
+        assert_eq!(held(HoldReason::RegistryIdentity, 1), 11);
+
+        // Dropping the field releases its deposit.
+        register(1);
+        assert_eq!(held(HoldReason::RegistryIdentity, 1), 10);
+        assert_eq!(Balances::free_balance(1), 90);
+
+        assert_noop!(
+            Registry::set_identity(
+                RuntimeOrigin::signed(1),
+                UNREGISTRABLE,
+                Box::new(info(b"org"))
+            ),
+            Error::<Test>::CannotRegister
+        );
+    });
+}
+
+#[test]
+fn clear_identity_releases_all_deposits() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        assert_ok!(Registry::add_registrar(RuntimeOrigin::root(), 10));
+        assert_ok!(Registry::set_fee(RuntimeOrigin::signed(10), 0, 5));
+        assert_ok!(Registry::request_judgement(RuntimeOrigin::signed(1), 0, 5));
+        assert_ok!(Registry::add_sub(RuntimeOrigin::signed(1), 2, data(b"sub")));
+        assert_eq!(Balances::free_balance(1), 80);
+
+        assert_ok!(Registry::clear_identity(RuntimeOrigin::signed(1), 1));
+        System::assert_last_event(Event::<Test>::IdentityDissolved { who: 1 }.into());
+        assert_eq!(Balances::free_balance(1), 100);
+        assert!(IdentityOf::<Test>::get(1).is_none());
+        assert!(Registry::get_super_of(&2).is_none());
+        assert!(Registry::get_subs_of(&1).is_empty());
+        assert!(Registry::get_judgements(&1).is_empty());
+
+        assert_noop!(
+            Registry::clear_identity(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
+
+#[test]
+fn sub_accounts_are_linked_with_deposits() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Registry::add_sub(RuntimeOrigin::signed(1), 2, data(b"sub")),
+            Error::<Test>::NotRegistered
+        );
+
+        register(1);
+        register(10);
+        assert_noop!(
+            Registry::add_sub(RuntimeOrigin::signed(1), 1, data(b"sub")),
+            Error::<Test>::InvalidSubAccount
+        );
+
+        assert_ok!(Registry::add_sub(RuntimeOrigin::signed(1), 2, data(b"sub")));
+        System::assert_last_event(Event::<Test>::SubIdentityAdded { sub: 2, main: 1 }.into());
+        assert_eq!(Registry::get_super_of(&2), Some((1, data(b"sub"))));
+        assert_eq!(held(HoldReason::RegistrySubAccount, 1), 5);
+
+        // A sub-account belongs to a single identity.
+        assert_noop!(
+            Registry::add_sub(RuntimeOrigin::signed(10), 2, data(b"sub")),
+            Error::<Test>::AlreadyClaimed
+        );
+
+        assert_ok!(Registry::add_sub(RuntimeOrigin::signed(1), 3, data(b"sub")));
+        assert_noop!(
+            Registry::add_sub(RuntimeOrigin::signed(1), 4, data(b"sub")),
+            Error::<Test>::TooManySubAccounts
+        );
+        assert_eq!(Registry::get_subs_of(&1), vec![2, 3]);
+
+        assert_ok!(Registry::rename_sub(
+            RuntimeOrigin::signed(1),
+            3,
+            data(b"renamed")
+        ));
+        assert_eq!(Registry::get_super_of(&3), Some((1, data(b"renamed"))));
+        assert_noop!(
+            Registry::rename_sub(RuntimeOrigin::signed(10), 3, data(b"stolen")),
+            Error::<Test>::NotOwned
+        );
+        assert_noop!(
+            Registry::remove_sub(RuntimeOrigin::signed(1), 4),
+            Error::<Test>::NotSub
+        );
+
+        assert_ok!(Registry::remove_sub(RuntimeOrigin::signed(1), 3));
+        System::assert_last_event(Event::<Test>::SubIdentityRemoved { sub: 3, main: 1 }.into());
+        assert_eq!(Registry::get_subs_of(&1), vec![2]);
+        assert_eq!(held(HoldReason::RegistrySubAccount, 1), 5);
+        assert_eq!(Balances::free_balance(1), 85);
+    });
+}
+
+#[test]
+fn quit_sub_pays_deposit_to_sub() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        assert_ok!(Registry::add_sub(RuntimeOrigin::signed(1), 5, data(b"sub")));
+
+        assert_ok!(Registry::quit_sub(RuntimeOrigin::signed(5)));
+        System::assert_last_event(Event::<Test>::SubIdentityRemoved { sub: 5, main: 1 }.into());
+        assert_eq!(Balances::free_balance(5), 5);
+        assert_eq!(held(HoldReason::RegistrySubAccount, 1), 0);
+        assert_eq!(Balances::free_balance(1), 85);
+        assert!(Registry::get_subs_of(&1).is_empty());
+
+        assert_noop!(
+            Registry::quit_sub(RuntimeOrigin::signed(5)),
+            Error::<Test>::NotSub
+        );
+    });
+}
+
+#[test]
+fn quit_sub_below_existential_deposit_releases_to_parent() {
+    new_test_ext().execute_with(|| {
+        ExistentialDeposit::set(10);
+        register(1);
+        assert_ok!(Registry::add_sub(RuntimeOrigin::signed(1), 5, data(b"sub")));
+
+        // The deposit alone cannot create the sub-account, so the parent gets it back.
+        assert_ok!(Registry::quit_sub(RuntimeOrigin::signed(5)));
+        assert_eq!(Balances::free_balance(5), 0);
+        assert_eq!(held(HoldReason::RegistrySubAccount, 1), 0);
+        assert_eq!(Balances::free_balance(1), 90);
+        assert!(Registry::get_super_of(&5).is_none());
+    });
+}
+
+#[test]
+fn registrars_are_managed_by_governance() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Registry::add_registrar(RuntimeOrigin::signed(1), 10),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Registry::add_registrar(RuntimeOrigin::root(), 10));
+        System::assert_last_event(Event::<Test>::RegistrarAdded { registrar_index: 0 }.into());
+        assert_ok!(Registry::add_registrar(RuntimeOrigin::root(), 3));
+        assert_noop!(
+            Registry::add_registrar(RuntimeOrigin::root(), 2),
+            Error::<Test>::TooManyRegistrars
+        );
+
+        // Only the registrar sets its own fee.
+        assert_noop!(
+            Registry::set_fee(RuntimeOrigin::signed(3), 0, 5),
+            Error::<Test>::InvalidIndex
+        );
+        assert_ok!(Registry::set_fee(RuntimeOrigin::signed(10), 0, 5));
+        System::assert_last_event(
+            Event::<Test>::RegistrarFeeSet {
+                registrar_index: 0,
+                fee: 5,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Registry::remove_registrar(RuntimeOrigin::signed(1), 1),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Registry::remove_registrar(RuntimeOrigin::root(), 1));
+        assert_noop!(
+            Registry::remove_registrar(RuntimeOrigin::root(), 1),
+            Error::<Test>::InvalidIndex
+        );
+        assert_eq!(
+            Registry::get_registrars(),
+            vec![
+                Some(RegistrarInfo {
+                    account: 10,
+                    fee: 5
+                }),
+                None
+            ]
+        );
+
+        // Removed slots are not reused, so indices stay stable.
+        assert_noop!(
+            Registry::add_registrar(RuntimeOrigin::root(), 2),
+            Error::<Test>::TooManyRegistrars
+        );
+    });
+}
+
+#[test]
+fn judgement_fee_is_held_until_judged() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Registry::add_registrar(RuntimeOrigin::root(), 10));
+        assert_ok!(Registry::set_fee(RuntimeOrigin::signed(10), 0, 5));
+        assert_noop!(
+            Registry::request_judgement(RuntimeOrigin::signed(1), 0, 5),
+            Error::<Test>::NotRegistered
+        );
+
+        register(1);
+        assert_noop!(
+            Registry::request_judgement(RuntimeOrigin::signed(1), 1, 5),
+            Error::<Test>::InvalidIndex
+        );
+        assert_noop!(
+            Registry::request_judgement(RuntimeOrigin::signed(1), 0, 4),
+            Error::<Test>::FeeChanged
+        );
+
+        assert_ok!(Registry::request_judgement(RuntimeOrigin::signed(1), 0, 5));
+        assert_eq!(held(HoldReason::RegistryJudgement, 1), 5);
+        assert_eq!(
+            Registry::get_judgements(&1),
+            vec![(0, Judgement::FeePaid(5))]
+        );
+        assert_noop!(
+            Registry::request_judgement(RuntimeOrigin::signed(1), 0, 5),
+            Error::<Test>::StickyJudgement
+        );
+
+        assert_ok!(Registry::cancel_request(RuntimeOrigin::signed(1), 0));
+        assert_eq!(held(HoldReason::RegistryJudgement, 1), 0);
+        assert_noop!(
+            Registry::cancel_request(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::NotFound
+        );
+
+        assert_ok!(Registry::request_judgement(RuntimeOrigin::signed(1), 0, 5));
+        let identity = BlakeTwo256::hash_of(&info(b"org"));
+        assert_noop!(
+            Registry::provide_judgement(
+                RuntimeOrigin::signed(3),
+                0,
+                1,
+                Judgement::KnownGood,
+                identity
+            ),
+            Error::<Test>::InvalidIndex
+        );
+        assert_noop!(
+            Registry::provide_judgement(
+                RuntimeOrigin::signed(10),
+                0,
+                1,
+                Judgement::FeePaid(1),
+                identity
+            ),
+            Error::<Test>::InvalidJudgement
+        );
+        assert_noop!(
+            Registry::provide_judgement(
+                RuntimeOrigin::signed(10),
+                0,
+                1,
+                Judgement::KnownGood,
+                H256::zero()
+            ),
+            Error::<Test>::JudgementForDifferentIdentity
+        );
+
+        assert_ok!(Registry::provide_judgement(
+            RuntimeOrigin::signed(10),
+            0,
+            1,
+            Judgement::KnownGood,
+            identity
+        ));
+        System::assert_last_event(
+            Event::<Test>::JudgementGiven {
+                target: 1,
+                registrar_index: 0,
+            }
+            .into(),
+        );
+        assert_eq!(held(HoldReason::RegistryJudgement, 1), 0);
+        assert_eq!(Balances::free_balance(10), 105);
+        assert_noop!(
+            Registry::cancel_request(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::JudgementGiven
+        );
+    });
+}
+
+#[test]
+fn verified_parent_requires_a_current_judgement() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Registry::add_registrar(RuntimeOrigin::root(), 10));
+        register(1);
+        assert_ok!(Registry::add_sub(RuntimeOrigin::signed(1), 2, data(b"sub")));
+        assert_eq!(Registry::get_verified_parent(&2), None);
+
+        assert_ok!(Registry::provide_judgement(
+            RuntimeOrigin::signed(10),
+            0,
+            1,
+            Judgement::Reasonable,
+            BlakeTwo256::hash_of(&info(b"org"))
+        ));
+        assert_eq!(Registry::get_verified_parent(&2), Some(1));
+        assert_eq!(Registry::get_verified_parent(&1), None);
+
+        // Changing the identity drops the judgement on the old data.
+        assert_ok!(Registry::set_identity(
+            RuntimeOrigin::signed(1),
+            1,
+            Box::new(info(b"renamed"))
+        ));
+        assert_eq!(Registry::get_verified_parent(&2), None);
+
+        assert_ok!(Registry::provide_judgement(
+            RuntimeOrigin::signed(10),
+            0,
+            1,
+            Judgement::KnownGood,
+            BlakeTwo256::hash_of(&info(b"renamed"))
+        ));
+        assert_eq!(Registry::get_verified_parent(&2), Some(1));
+
+        // Judgements of a removed registrar no longer verify the identity.
+        assert_ok!(Registry::remove_registrar(RuntimeOrigin::root(), 0));
+        assert_eq!(Registry::get_verified_parent(&2), None);
+    });
+}
@@ -406,6 +406,73 @@ impl<
     }
 }
 
+/// An identifier for a single name registrar/identity verification service.
+pub type RegistrarIndex = u32;
+
+/// An attestation of a registrar over how accurate some `IdentityInfo` is in describing an account.
+///
+/// NOTE: Registrars may pay little attention to some fields. Registrars may want to make clear
+/// which fields their attestation is relevant for by off-chain means.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Judgement<Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq>
+{
+    /// The default value; no opinion is held.
+    Unknown,
+    /// No judgement is yet in place, but a deposit is reserved as payment for providing one.
+    FeePaid(Balance),
+    /// The data appears to be reasonably acceptable in terms of its accuracy, however no in depth
+    /// checks (such as in-person meetings or formal KYC) have been conducted.
+    Reasonable,
+    /// The target is known directly by the registrar and the registrar can fully attest to the
+    /// the data's accuracy.
+    KnownGood,
+    /// The data was once good but is currently out of date. There is no malicious intent in the
+    /// inaccuracy. This judgement can be removed through updating the data.
+    OutOfDate,
+    /// The data is imprecise or of sufficiently low-quality to be problematic. It is not
+    /// indicative of malicious intent. This judgement can be removed through updating the data.
+    LowQuality,
+    /// The data is erroneous. This may be indicative of malicious intent. This cannot be removed
+    /// except by the registrar.
+    Erroneous,
+}
+
+impl<Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq>
+    Judgement<Balance>
+{
+    /// Returns `true` if this judgement is indicative of a deposit being currently held. This means
+    /// it should not be cleared or replaced except by an operation which utilizes the deposit.
+    pub(crate) fn has_deposit(&self) -> bool {
+        matches!(self, Judgement::FeePaid(_))
+    }
+
+    /// Returns `true` if this judgement is one that should not be generally be replaced outside
+    /// of specialized handlers. Examples include "malicious" judgements and deposit-holding
+    /// judgements.
+    pub(crate) fn is_sticky(&self) -> bool {
+        matches!(self, Judgement::FeePaid(_) | Judgement::Erroneous)
+    }
+
+    /// Returns `true` if this judgement attests to the accuracy of the identity.
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Judgement::Reasonable | Judgement::KnownGood)
+    }
+}
+
+/// Information on a registrar, who provides judgements on identities for a fee.
+#[freeze_struct("40734dc77d5247b5")]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RegistrarInfo<
+    Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
+    AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
+> {
+    /// The account of the registrar.
+    pub account: AccountId,
+
+    /// Amount required to be given to the registrar for them to provide judgement.
+    pub fee: Balance,
+}
+
 #[cfg(test)]
 #[allow(clippy::indexing_slicing, clippy::unwrap_used)]
 mod tests {
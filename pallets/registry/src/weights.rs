@@ -31,6 +31,16 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_identity() -> Weight;
 	fn clear_identity() -> Weight;
+	fn add_sub() -> Weight;
+	fn rename_sub() -> Weight;
+	fn remove_sub() -> Weight;
+	fn quit_sub() -> Weight;
+	fn add_registrar() -> Weight;
+	fn remove_registrar() -> Weight;
+	fn set_fee() -> Weight;
+	fn request_judgement() -> Weight;
+	fn cancel_request() -> Weight;
+	fn provide_judgement() -> Weight;
 }
 
 /// Weights for `pallet_registry` using the Substrate node and recommended hardware.
@@ -58,6 +68,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn add_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(38_000_000, 3697)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn rename_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(17_000_000, 3922)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remove_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(36_000_000, 3922)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn quit_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn add_registrar() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(12_000_000, 2626)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remove_registrar() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(12_000_000, 2626)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_fee() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(11_000_000, 2626)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn request_judgement() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(37_000_000, 3619)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn cancel_request() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn provide_judgement() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(52_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -84,4 +154,64 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+	fn add_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(38_000_000, 3697)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn rename_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(17_000_000, 3922)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(36_000_000, 3922)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn quit_sub() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn add_registrar() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(12_000_000, 2626)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_registrar() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(12_000_000, 2626)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_fee() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(11_000_000, 2626)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn request_judgement() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(37_000_000, 3619)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn cancel_request() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn provide_judgement() -> Weight {
+		// TODO should be replaced by benchmarked weights
+		Weight::from_parts(52_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
@@ -13,8 +13,8 @@ use std::sync::Arc;
 use sp_api::ProvideRuntimeApi;
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    CommitmentsRuntimeApi, DelegateInfoRuntimeApi, DrandRuntimeApi, NeuronInfoRuntimeApi,
+    SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
 };
 
 #[rpc(client, server)]
@@ -71,6 +71,31 @@ pub trait SubtensorCustomApi<BlockHash> {
         metagraph_index: Vec<u16>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEmissionProjection")]
+    fn get_emission_projection(
+        &self,
+        blocks: u64,
+        epochs: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "commitments_getCommitmentHistory")]
+    fn get_commitment_history(
+        &self,
+        netuid: u16,
+        account_vec: Vec<u8>,
+        from_block: u32,
+        to_block: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "commitments_getDecodedCommitment")]
+    fn get_decoded_commitment(
+        &self,
+        netuid: u16,
+        account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "drand_getPulse")]
+    fn get_drand_pulse(&self, round: Option<u64>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -119,6 +144,8 @@ where
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
+    C::Api: CommitmentsRuntimeApi<Block>,
+    C::Api: DrandRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
@@ -416,4 +443,99 @@ where
             .into()),
         }
     }
+
+    fn get_emission_projection(
+        &self,
+        blocks: u64,
+        epochs: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_emission_projection(at, blocks, epochs) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get emission projection: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_commitment_history(
+        &self,
+        netuid: u16,
+        account_vec: Vec<u8>,
+        from_block: u32,
+        to_block: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let account = match AccountId32::decode(&mut &account_vec[..]) {
+            Ok(account) => account,
+            Err(e) => {
+                return Err(Error::RuntimeError(format!(
+                    "Unable to get commitment history: {:?}",
+                    e
+                ))
+                .into());
+            }
+        };
+        match api.get_commitment_history(at, netuid, account, from_block, to_block) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get commitment history: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_decoded_commitment(
+        &self,
+        netuid: u16,
+        account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let account = match AccountId32::decode(&mut &account_vec[..]) {
+            Ok(account) => account,
+            Err(e) => {
+                return Err(Error::RuntimeError(format!(
+                    "Unable to get decoded commitment: {:?}",
+                    e
+                ))
+                .into());
+            }
+        };
+        match api.get_decoded_commitment(at, netuid, account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get decoded commitment: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_drand_pulse(
+        &self,
+        round: Option<u64>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_pulse(at, round) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get drand pulse: {:?}", e)).into())
+            }
+        }
+    }
 }
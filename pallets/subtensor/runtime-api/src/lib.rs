@@ -1,15 +1,24 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 use alloc::vec::Vec;
-use codec::Compact;
+use codec::{Codec, Compact};
+use pallet_admin_utils::ScheduledHyperparameterChangeInfo;
+use pallet_collective::WeightedTally;
+use pallet_commitments::{CommitmentHistoryEntry, CommitmentSchema, DecodedCommitmentField};
+use pallet_drand::types::{BeaconConfiguration, Pulse, RoundNumber};
+use pallet_registry::{Data, Judgement, RegistrarIndex, RegistrarInfo};
 use pallet_subtensor::rpc_info::{
+    buyback_info::SubnetBuybackInfo,
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
+    emission_projection::SubnetEmissionProjection,
     metagraph::{Metagraph, SelectiveMetagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
+    rebalance_info::RebalancePlan,
     show_subnet::SubnetState,
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
+    subnet_proposal_info::SubnetProposalInfo,
 };
 use sp_runtime::AccountId32;
 
@@ -41,6 +50,11 @@ sp_api::decl_runtime_apis! {
         fn get_dynamic_info(netuid: u16) -> Option<DynamicInfo<AccountId32>>;
         fn get_subnet_state(netuid: u16) -> Option<SubnetState<AccountId32>>;
         fn get_selective_metagraph(netuid: u16, metagraph_indexes: Vec<u16>) -> Option<SelectiveMetagraph<AccountId32>>;
+        fn get_emission_projection(blocks: u64, epochs: u16) -> Vec<SubnetEmissionProjection>;
+        fn get_subnet_buyback_info(netuid: u16) -> Option<SubnetBuybackInfo>;
+        fn get_all_subnet_buyback_info() -> Vec<Option<SubnetBuybackInfo>>;
+        fn get_subnet_alpha_twap(netuid: u16, window: u64) -> Option<u64>;
+        fn get_subnet_proposals(netuid: u16) -> Vec<SubnetProposalInfo<AccountId32>>;
     }
 
     pub trait StakeInfoRuntimeApi {
@@ -48,9 +62,42 @@ sp_api::decl_runtime_apis! {
         fn get_stake_info_for_coldkeys( coldkey_accounts: Vec<AccountId32> ) -> Vec<(AccountId32, Vec<StakeInfo<AccountId32>>)>;
         fn get_stake_info_for_hotkey_coldkey_netuid( hotkey_account: AccountId32, coldkey_account: AccountId32, netuid: u16 ) -> Option<StakeInfo<AccountId32>>;
         fn get_stake_fee( origin: Option<(AccountId32, u16)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, u16)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64;
+        fn simulate_rebalance_stake( coldkey_account: AccountId32, targets: Vec<(AccountId32, u16, u16)> ) -> Option<RebalancePlan<AccountId32>>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
     }
+
+    pub trait ProxySpendingRuntimeApi {
+        fn get_proxy_remaining_allowance(real: AccountId32, delegate: AccountId32) -> Option<u64>;
+    }
+
+    pub trait HyperparameterTimelockRuntimeApi {
+        fn get_scheduled_hyperparameter_changes() -> Vec<ScheduledHyperparameterChangeInfo<AccountId32>>;
+    }
+
+    pub trait RegistryRuntimeApi {
+        fn get_super_of(sub: AccountId32) -> Option<(AccountId32, Data)>;
+        fn get_subs_of(main: AccountId32) -> Vec<AccountId32>;
+        fn get_judgements(who: AccountId32) -> Vec<(RegistrarIndex, Judgement<u64>)>;
+        fn get_registrars() -> Vec<Option<RegistrarInfo<u64, AccountId32>>>;
+        fn get_verified_parent(sub: AccountId32) -> Option<AccountId32>;
+    }
+
+    pub trait CommitmentsRuntimeApi {
+        fn get_commitment_history(netuid: u16, account: AccountId32, from_block: u32, to_block: u32) -> Vec<CommitmentHistoryEntry<u32>>;
+        fn get_commitment_schema(netuid: u16) -> Option<CommitmentSchema>;
+        fn get_decoded_commitment(netuid: u16, account: AccountId32) -> Option<Vec<DecodedCommitmentField>>;
+    }
+
+    pub trait DrandRuntimeApi {
+        fn get_pulse(round: Option<RoundNumber>) -> Option<Pulse>;
+        fn get_beacon_config() -> BeaconConfiguration;
+    }
+
+    pub trait SenateRuntimeApi<Hash> where Hash: Codec {
+        fn get_weighted_tally(proposal_hash: Hash) -> Option<WeightedTally>;
+        fn get_weighted_tallies() -> Vec<(Hash, WeightedTally)>;
+    }
 }
@@ -108,6 +108,18 @@ impl<T: Config> Pallet<T> {
 
     /// Returns the block emission for an issuance value.
     pub fn get_block_emission_for_issuance(issuance: u64) -> Result<u64, &'static str> {
+        let block_emission_u64: u64 = Self::calculate_block_emission_for_issuance(issuance)?;
+        if BlockEmission::<T>::get() != block_emission_u64 {
+            BlockEmission::<T>::put(block_emission_u64);
+        }
+        Ok(block_emission_u64)
+    }
+
+    /// Returns the block emission for an issuance value without writing to storage.
+    ///
+    /// This is the read-only counterpart of `get_block_emission_for_issuance`, used when
+    /// projecting future emission.
+    pub fn calculate_block_emission_for_issuance(issuance: u64) -> Result<u64, &'static str> {
         // Convert issuance to a float for calculations below.
         let total_issuance: I96F32 = I96F32::saturating_from_num(issuance);
         // Check to prevent division by zero when the total supply is reached
@@ -148,10 +160,24 @@ impl<T: Config> Pallet<T> {
         let block_emission: I96F32 = block_emission_percentage
             .saturating_mul(I96F32::saturating_from_num(DefaultBlockEmission::<T>::get()));
         // Convert to u64
-        let block_emission_u64: u64 = block_emission.saturating_to_num::<u64>();
-        if BlockEmission::<T>::get() != block_emission_u64 {
-            BlockEmission::<T>::put(block_emission_u64);
+        Ok(block_emission.saturating_to_num::<u64>())
+    }
+
+    /// Returns the issuance at which the block emission for `issuance` halves next.
+    ///
+    /// Halvings happen each time the issuance crosses `S * (1 - 1 / 2^k)` where `S`
+    /// is twice the 10.5M TAO halving base. The result is capped at `TotalSupply`,
+    /// at which point emission stops entirely.
+    pub fn get_next_halving_issuance(issuance: u64) -> u64 {
+        let total_supply: u64 = TotalSupply::<T>::get();
+        let halving_base: u64 = 2_u64.saturating_mul(10_500_000_000_000_000);
+        for halvings in 1..u64::BITS {
+            let threshold: u64 =
+                halving_base.saturating_sub(halving_base.checked_shr(halvings).unwrap_or(0));
+            if issuance < threshold {
+                return threshold.min(total_supply);
+            }
         }
-        Ok(block_emission_u64)
+        total_supply
     }
 }
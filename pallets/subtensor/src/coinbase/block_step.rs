@@ -18,6 +18,8 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         Self::run_coinbase(block_emission);
         // --- 4. Set pending children on the epoch; but only after the coinbase has been run.
         Self::try_set_pending_children(block_number);
+        // --- 5. Record the alpha prices for the TWAP oracle.
+        Self::update_price_accumulators(block_number);
         // Return ok.
         Ok(())
     }
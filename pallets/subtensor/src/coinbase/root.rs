@@ -557,17 +557,33 @@ impl<T: Config> Pallet<T> {
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        SubnetPriceCumulative::<T>::remove(netuid);
+        let _ = SubnetPriceObservations::<T>::clear_prefix(netuid, u32::MAX, None);
 
         // --- 12. Add the balance back to the owner.
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
         Self::set_subnet_locked_balance(netuid, 0);
+
+        // --- 13. Refund the buyback budget to the owner.
+        if SubnetBuybackProgram::<T>::contains_key(netuid) {
+            Self::remove_buyback_program_and_refund(netuid);
+        }
+        SubnetBuybackTaoSpent::<T>::remove(netuid);
+        SubnetBuybackAlphaBought::<T>::remove(netuid);
+        SubnetBuybackAlphaBurned::<T>::remove(netuid);
         SubnetOwner::<T>::remove(netuid);
+        SubnetGovernanceEnabled::<T>::remove(netuid);
+        Self::clear_subnet_proposals(netuid);
+        Self::clear_delegate_take_classes(netuid);
 
-        // --- 13. Remove subnet identity if it exists.
+        // --- 14. Remove subnet identity if it exists.
         if SubnetIdentitiesV2::<T>::contains_key(netuid) {
             SubnetIdentitiesV2::<T>::remove(netuid);
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
         }
+
+        // --- 15. Let other pallets clean up their data for the subnet.
+        T::OnNeuronRemoved::on_network_removed(netuid);
     }
 
     #[allow(clippy::arithmetic_side_effects)]
@@ -2,8 +2,6 @@ use super::*;
 use alloc::collections::BTreeMap;
 use safe_math::*;
 use substrate_fixed::types::U96F32;
-use tle::stream_ciphers::AESGCMStreamCipherProvider;
-use tle::tlock::tld;
 
 /// Contains all necesarry information to set weights.
 ///
@@ -52,13 +50,8 @@ impl<T: Config> Pallet<T> {
         log::debug!("Subnets to emit to: {:?}", subnets_to_emit_to);
 
         // --- 2. Get sum of tao reserves ( in a later version we will switch to prices. )
-        let mut total_moving_prices: U96F32 = U96F32::saturating_from_num(0.0);
         // Only get price EMA for subnets that we emit to.
-        for netuid_i in subnets_to_emit_to.iter() {
-            // Get and update the moving price of each subnet adding the total together.
-            total_moving_prices =
-                total_moving_prices.saturating_add(Self::get_moving_alpha_price(*netuid_i));
-        }
+        let total_moving_prices: U96F32 = Self::get_total_moving_prices(&subnets_to_emit_to);
         log::debug!("total_moving_prices: {:?}", total_moving_prices);
 
         // --- 3. Get subnet terms (tao_in, alpha_in, and alpha_out)
@@ -68,38 +61,18 @@ impl<T: Config> Pallet<T> {
         let mut alpha_out: BTreeMap<u16, U96F32> = BTreeMap::new();
         // Only calculate for subnets that we are emitting to.
         for netuid_i in subnets_to_emit_to.iter() {
-            // Get subnet price.
-            let price_i: U96F32 = Self::get_alpha_price(*netuid_i);
-            log::debug!("price_i: {:?}", price_i);
-            // Get subnet TAO.
-            let moving_price_i: U96F32 = Self::get_moving_alpha_price(*netuid_i);
-            log::debug!("moving_price_i: {:?}", moving_price_i);
-            // Emission is price over total.
-            let mut tao_in_i: U96F32 = block_emission
-                .saturating_mul(moving_price_i)
-                .checked_div(total_moving_prices)
-                .unwrap_or(asfloat!(0.0));
-            log::debug!("tao_in_i: {:?}", tao_in_i);
             // Get alpha_emission total
             let alpha_emission_i: U96F32 = asfloat!(
                 Self::get_block_emission_for_issuance(Self::get_alpha_issuance(*netuid_i))
                     .unwrap_or(0)
             );
             log::debug!("alpha_emission_i: {:?}", alpha_emission_i);
-            // Get initial alpha_in
-            let alpha_in_i: U96F32 = tao_in_i
-                .checked_div(price_i)
-                .unwrap_or(alpha_emission_i)
-                .min(alpha_emission_i);
-            log::debug!("alpha_in_i: {:?}", alpha_in_i);
-            // Get alpha_out.
-            let alpha_out_i = alpha_emission_i;
-            // Only emit TAO if the subnetwork allows registration.
-            if !Self::get_network_registration_allowed(*netuid_i)
-                && !Self::get_network_pow_registration_allowed(*netuid_i)
-            {
-                tao_in_i = asfloat!(0.0);
-            }
+            let (tao_in_i, alpha_in_i, alpha_out_i) = Self::get_subnet_injection_terms(
+                *netuid_i,
+                block_emission,
+                total_moving_prices,
+                alpha_emission_i,
+            );
             // Insert values into maps
             tao_in.insert(*netuid_i, tao_in_i);
             alpha_in.insert(*netuid_i, alpha_in_i);
@@ -262,6 +235,65 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns the sum of the moving alpha prices of the given subnets.
+    pub fn get_total_moving_prices(subnets: &[u16]) -> U96F32 {
+        let mut total_moving_prices: U96F32 = U96F32::saturating_from_num(0.0);
+        for netuid_i in subnets.iter() {
+            // Get the moving price of each subnet adding the total together.
+            total_moving_prices =
+                total_moving_prices.saturating_add(Self::get_moving_alpha_price(*netuid_i));
+        }
+        total_moving_prices
+    }
+
+    /// Computes the injection terms of a subnet for a single block.
+    ///
+    /// Only reads state, so the same math can be used by the coinbase and by
+    /// emission projections.
+    ///
+    /// # Arguments
+    /// * `netuid` - The subnet to compute the terms for.
+    /// * `block_emission` - The TAO emission of the block shared by all subnets.
+    /// * `total_moving_prices` - The sum of moving prices of all subnets we emit to.
+    /// * `alpha_emission` - The alpha block emission for the subnet's alpha issuance.
+    ///
+    /// # Returns
+    /// * `(U96F32, U96F32, U96F32)` - The `(tao_in, alpha_in, alpha_out)` terms.
+    pub fn get_subnet_injection_terms(
+        netuid: u16,
+        block_emission: U96F32,
+        total_moving_prices: U96F32,
+        alpha_emission: U96F32,
+    ) -> (U96F32, U96F32, U96F32) {
+        // Get subnet price.
+        let price_i: U96F32 = Self::get_alpha_price(netuid);
+        log::debug!("price_i: {:?}", price_i);
+        // Get subnet TAO.
+        let moving_price_i: U96F32 = Self::get_moving_alpha_price(netuid);
+        log::debug!("moving_price_i: {:?}", moving_price_i);
+        // Emission is price over total.
+        let mut tao_in_i: U96F32 = block_emission
+            .saturating_mul(moving_price_i)
+            .checked_div(total_moving_prices)
+            .unwrap_or(asfloat!(0.0));
+        log::debug!("tao_in_i: {:?}", tao_in_i);
+        // Get initial alpha_in
+        let alpha_in_i: U96F32 = tao_in_i
+            .checked_div(price_i)
+            .unwrap_or(alpha_emission)
+            .min(alpha_emission);
+        log::debug!("alpha_in_i: {:?}", alpha_in_i);
+        // Get alpha_out.
+        let alpha_out_i = alpha_emission;
+        // Only emit TAO if the subnetwork allows registration.
+        if !Self::get_network_registration_allowed(netuid)
+            && !Self::get_network_pow_registration_allowed(netuid)
+        {
+            tao_in_i = asfloat!(0.0);
+        }
+        (tao_in_i, alpha_in_i, alpha_out_i)
+    }
+
     pub fn calculate_dividends_and_incentives(
         netuid: u16,
         hotkey_emission: Vec<(T::AccountId, u64, u64)>,
@@ -446,10 +478,19 @@ impl<T: Config> Pallet<T> {
         let _ = AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
         for (hotkey, mut alpha_divs) in alpha_dividends {
             // Get take prop
-            let alpha_take: U96F32 =
+            let mut alpha_take: U96F32 =
                 Self::get_hotkey_take_float(&hotkey).saturating_mul(alpha_divs);
             // Remove take prop from alpha_divs
             alpha_divs = alpha_divs.saturating_sub(alpha_take);
+            // Hold back the part of the take owed to nominators with a lower tier or override take.
+            let take_rebates: u64 = Self::accrue_delegate_take_rebates(
+                &hotkey,
+                netuid,
+                alpha_divs.saturating_add(alpha_take),
+                alpha_take,
+            );
+            alpha_take = alpha_take.saturating_sub(U96F32::saturating_from_num(take_rebates));
+            log::debug!("hotkey: {:?} take_rebates: {:?}", hotkey, take_rebates);
             // Give the validator their take.
             log::debug!("hotkey: {:?} alpha_take: {:?}", hotkey, alpha_take);
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
@@ -461,6 +502,8 @@ impl<T: Config> Pallet<T> {
             // Give all other nominators.
             log::debug!("hotkey: {:?} alpha_divs: {:?}", hotkey, alpha_divs);
             Self::increase_stake_for_hotkey_on_subnet(&hotkey, netuid, tou64!(alpha_divs));
+            // Credit the take rebates of the next nominators of the hotkey.
+            Self::refresh_delegate_take_classes(&hotkey);
             // Record dividends for this hotkey.
             AlphaDividendsPerSubnet::<T>::mutate(netuid, &hotkey, |divs| {
                 *divs = divs.saturating_add(tou64!(alpha_divs));
@@ -475,9 +518,19 @@ impl<T: Config> Pallet<T> {
         let _ = TaoDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
         for (hotkey, mut root_tao) in tao_dividends {
             // Get take prop
-            let tao_take: U96F32 = Self::get_hotkey_take_float(&hotkey).saturating_mul(root_tao);
+            let mut tao_take: U96F32 =
+                Self::get_hotkey_take_float(&hotkey).saturating_mul(root_tao);
             // Remove take prop from root_tao
             root_tao = root_tao.saturating_sub(tao_take);
+            // Hold back the part of the take owed to root nominators with a lower take.
+            let take_rebates: u64 = Self::accrue_delegate_take_rebates(
+                &hotkey,
+                Self::get_root_netuid(),
+                root_tao.saturating_add(tao_take),
+                tao_take,
+            );
+            tao_take = tao_take.saturating_sub(U96F32::saturating_from_num(take_rebates));
+            log::debug!("hotkey: {:?} root_take_rebates: {:?}", hotkey, take_rebates);
             // Give the validator their take.
             log::debug!("hotkey: {:?} tao_take: {:?}", hotkey, tao_take);
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
@@ -493,6 +546,8 @@ impl<T: Config> Pallet<T> {
                 Self::get_root_netuid(),
                 tou64!(root_tao),
             );
+            // Credit the take rebates of the next root nominators of the hotkey.
+            Self::refresh_delegate_take_classes(&hotkey);
             // Record root dividends for this validator on this subnet.
             TaoDividendsPerSubnet::<T>::mutate(netuid, hotkey.clone(), |divs| {
                 *divs = divs.saturating_add(tou64!(root_tao));
@@ -564,6 +619,9 @@ impl<T: Config> Pallet<T> {
 
         let tao_weight = Self::get_tao_weight();
 
+        // Run the subnet buyback program, burning part of the owner cut.
+        let owner_cut: u64 = Self::run_buyback_program(netuid, owner_cut);
+
         // Run the epoch.
         let hotkey_emission: Vec<(T::AccountId, u64, u64)> =
             Self::epoch(netuid, pending_alpha.saturating_add(pending_swapped));
@@ -841,11 +899,8 @@ impl<T: Config> Pallet<T> {
 
     /// The `reveal_crv3_commits` function is run at the very beginning of epoch `n`,
     pub fn reveal_crv3_commits(netuid: u16) -> dispatch::DispatchResult {
-        use ark_serialize::CanonicalDeserialize;
         use frame_support::traits::OriginTrait;
-        use tle::curves::drand::TinyBLS381;
-        use tle::tlock::TLECiphertext;
-        use w3f_bls::EngineBLS;
+        use pallet_drand::timelock::TimelockError;
 
         let cur_block = Self::get_current_block_as_u64();
         let cur_epoch = Self::get_epoch_index(netuid, cur_block);
@@ -855,9 +910,12 @@ impl<T: Config> Pallet<T> {
             cur_epoch.saturating_sub(Self::get_reveal_period(netuid).saturating_sub(1));
 
         // Clean expired commits
-        for (epoch, _) in CRV3WeightCommits::<T>::iter_prefix(netuid) {
-            if epoch < reveal_epoch {
-                CRV3WeightCommits::<T>::remove(netuid, epoch);
+        let expired_epochs: Vec<u64> = CRV3WeightCommits::<T>::iter_key_prefix(netuid)
+            .filter(|epoch| *epoch < reveal_epoch)
+            .collect();
+        for epoch in expired_epochs {
+            for (_, _, round_number) in CRV3WeightCommits::<T>::take(netuid, epoch) {
+                pallet_drand::Pallet::<T>::release_round(round_number);
             }
         }
 
@@ -868,27 +926,18 @@ impl<T: Config> Pallet<T> {
         }
 
         let mut entries = CRV3WeightCommits::<T>::take(netuid, reveal_epoch);
+        for (_, _, round_number) in entries.iter() {
+            pallet_drand::Pallet::<T>::release_round(*round_number);
+        }
 
         // Keep popping item off the end of the queue until we sucessfully reveal a commit.
         while let Some((who, serialized_compresssed_commit, round_number)) = entries.pop_front() {
-            let reader = &mut &serialized_compresssed_commit[..];
-            let commit = match TLECiphertext::<TinyBLS381>::deserialize_compressed(reader) {
-                Ok(c) => c,
-                Err(e) => {
-                    log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing the commit: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
-            // Try to get the round number from pallet_drand.
-            let pulse = match pallet_drand::Pulses::<T>::get(round_number) {
-                Some(p) => p,
-                None => {
+            let decrypted_bytes: Vec<u8> = match pallet_drand::Pallet::<T>::decrypt_timelock(
+                &serialized_compresssed_commit,
+                round_number,
+            ) {
+                Ok(d) => d,
+                Err(TimelockError::MissingPulse) => {
                     // Round number used was not found on the chain. Skip this commit.
                     log::warn!(
                         "Failed to reveal commit for subnet {} submitted by {:?} due to missing round number {} at time of reveal.",
@@ -898,33 +947,6 @@ impl<T: Config> Pallet<T> {
                     );
                     continue;
                 }
-            };
-
-            let signature_bytes = pulse
-                .signature
-                .strip_prefix(b"0x")
-                .unwrap_or(&pulse.signature);
-
-            let sig_reader = &mut &signature_bytes[..];
-            let sig = match <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(
-                sig_reader,
-            ) {
-                Ok(s) => s,
-                Err(e) => {
-                    log::error!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing signature from drand pallet: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
-            let decrypted_bytes: Vec<u8> = match tld::<TinyBLS381, AESGCMStreamCipherProvider>(
-                commit, sig,
-            ) {
-                Ok(d) => d,
                 Err(e) => {
                     log::warn!(
                         "Failed to reveal commit for subnet {} submitted by {:?} due to error decrypting the commit: {:?}",
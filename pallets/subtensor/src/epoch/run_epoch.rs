@@ -1264,7 +1264,7 @@ impl<T: Config> Pallet<T> {
         ensure_signed(origin.clone())?;
 
         // --- 2. Ensure the function caller is the subnet owner or root.
-        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        Self::ensure_ungoverned_subnet_owner_or_root(origin, netuid)?;
 
         // --- 3. Ensure liquid alpha is enabled
         ensure!(
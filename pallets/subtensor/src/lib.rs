@@ -57,6 +57,33 @@ extern crate alloc;
 
 pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 
+/// The maximum number of targets of a stake rebalance.
+pub const MAX_REBALANCE_TARGETS: u32 = 32;
+
+/// The maximum number of stake positions of a coldkey that a rebalance moves stake out of.
+pub const MAX_REBALANCE_POSITIONS: u32 = 64;
+
+/// The maximum number of tiers in a delegate take schedule.
+pub const MAX_DELEGATE_TAKE_TIERS: u32 = 8;
+
+/// The maximum number of nominator take overrides of a delegate.
+pub const MAX_DELEGATE_TAKE_OVERRIDES: u32 = 64;
+
+/// The maximum number of take classes a delegate accrues rebates for on a subnet.
+pub const MAX_DELEGATE_TAKE_CLASSES: u32 = MAX_DELEGATE_TAKE_TIERS + MAX_DELEGATE_TAKE_OVERRIDES;
+
+/// The maximum number of stake positions moved to their current take class at once.
+pub const MAX_TAKE_CLASS_REFRESHES: u32 = 32;
+
+/// The maximum number of open hyperparameter proposals of a governed subnet.
+pub const MAX_OPEN_SUBNET_PROPOSALS: u32 = 8;
+
+/// The maximum number of coldkeys voting on a subnet proposal.
+pub const MAX_SUBNET_PROPOSAL_VOTERS: u32 = 256;
+
+/// The maximum number of subnet proposals closed in a block.
+pub const MAX_SUBNET_PROPOSALS_CLOSED_PER_BLOCK: u32 = 4;
+
 #[deny(missing_docs)]
 #[import_section(errors::errors)]
 #[import_section(events::events)]
@@ -268,6 +295,79 @@ pub mod pallet {
         /// Additional information about the subnet
         pub additional: Vec<u8>,
     }
+
+    /// Data structure for a subnet owner alpha buyback program.
+    #[crate::freeze_struct("38e414ebb697827e")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct BuybackProgram {
+        /// Proportion of the owner cut burned each epoch, normalized by u16::MAX.
+        pub owner_cut_burn: u16,
+        /// Maximum TAO from the buyback budget spent buying alpha each epoch.
+        pub tao_per_epoch: u64,
+        /// Highest alpha price, in rao per alpha, at which alpha is bought back.
+        pub max_price: u64,
+    }
+
+    /// A subnet hyperparameter change proposed by the owner of a governed subnet.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum SubnetHyperparameter {
+        /// Sets the serving rate limit.
+        ServingRateLimit(u64),
+        /// Sets the maximum POW difficulty.
+        MaxDifficulty(u64),
+        /// Sets the weights version key.
+        WeightsVersionKey(u64),
+        /// Sets the difficulty adjustment alpha.
+        AdjustmentAlpha(u64),
+        /// Sets the maximum weight limit.
+        MaxWeightLimit(u16),
+        /// Sets the immunity period.
+        ImmunityPeriod(u16),
+        /// Sets the minimum number of allowed weights.
+        MinAllowedWeights(u16),
+        /// Sets kappa.
+        Kappa(u16),
+        /// Sets rho.
+        Rho(u16),
+        /// Sets the activity cutoff.
+        ActivityCutoff(u16),
+        /// Sets whether registration is allowed.
+        RegistrationAllowed(bool),
+        /// Sets whether POW registration is allowed.
+        PowRegistrationAllowed(bool),
+        /// Sets the maximum burn.
+        MaxBurn(u64),
+        /// Sets the bonds moving average.
+        BondsMovingAverage(u64),
+        /// Sets the bonds penalty.
+        BondsPenalty(u16),
+        /// Sets whether commit-reveal weights are enabled.
+        CommitRevealWeightsEnabled(bool),
+        /// Sets the commit-reveal weights interval.
+        CommitRevealWeightsInterval(u64),
+        /// Sets whether liquid alpha is enabled.
+        LiquidAlphaEnabled(bool),
+        /// Sets whether stake transfers are enabled.
+        TransferEnabled(bool),
+    }
+
+    /// Data structure for an owner hyperparameter proposal awaiting alpha holder ratification.
+    #[crate::freeze_struct("10d5512253c3ec29")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct SubnetProposal<AccountId> {
+        /// The subnet owner coldkey that proposed the change.
+        pub proposer: AccountId,
+        /// The proposed hyperparameter change.
+        pub change: SubnetHyperparameter,
+        /// Block at which the voting window ends and the change applies unless vetoed.
+        pub end: u64,
+        /// Alpha stake voting to ratify the change.
+        pub ayes: u64,
+        /// Alpha stake voting to veto the change.
+        pub nays: u64,
+        /// Number of coldkeys that voted on the change.
+        pub voters: u32,
+    }
     /// ============================
     /// ==== Staking + Accounts ====
     /// ============================
@@ -852,6 +952,60 @@ pub mod pallet {
     pub type Delegates<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDelegateTake<T>>;
     #[pallet::storage]
+    /// MAP ( hot ) --> Vec<(stake_threshold, take)> | Returns the tiered take schedule of a delegate.
+    pub type DelegateTakeTiers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(u64, u16)>, ValueQuery>;
+    #[pallet::storage]
+    /// DMAP ( hot, cold ) --> take | Returns the take a delegate charges a specific nominator.
+    pub type DelegateTakeOverrides<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // First key: hotkey
+        Blake2_128Concat,
+        T::AccountId, // Second key: nominator coldkey
+        u16,          // Value: take
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// DMAP ( hot, netuid ) --> Vec<(take, shares, rebate_per_share)> | Returns the nominator shares charged each take lower than the delegate take, with the take rebate accrued per share.
+    pub type DelegateTakeClasses<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // First key: hotkey
+        Identity,
+        u16, // Second key: netuid
+        Vec<(u16, U64F64, U64F64)>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// NMAP ( hot, cold, netuid ) --> (take, rebate_per_share) | Returns the take class of a nominator and the class rebate per share at their last stake change.
+    pub type NominatorTakeClass<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>, // hot
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+            NMapKey<Identity, u16>,                  // subnet
+        ),
+        (u16, U64F64),
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// DMAP ( hot, netuid ) --> alpha | Returns the take rebates accrued and not yet credited to the nominators.
+    pub type PendingTakeRebates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // First key: hotkey
+        Identity,
+        u16, // Second key: netuid
+        u64,
+        ValueQuery,
+        DefaultZeroU64<T>,
+    >;
+    #[pallet::storage]
+    /// MAP ( hot ) --> raw_key | Returns the Alpha key of the last stake position of a delegate whose take rebate was credited on dividend distribution.
+    pub type TakeRebateCursor<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<u8>, OptionQuery>;
+    #[pallet::storage]
     /// DMAP ( hot, netuid ) --> take | Returns the hotkey childkey take for a specific subnet
     pub type ChildkeyTake<T: Config> = StorageDoubleMap<
         _,
@@ -975,6 +1129,12 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> moving_price | The subnet moving price.
     pub type SubnetMovingPrice<T: Config> =
         StorageMap<_, Identity, u16, I96F32, ValueQuery, DefaultMovingPrice<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> price_cumulative | Sum of the subnet alpha price, in rao per alpha, over every block.
+    pub type SubnetPriceCumulative<T: Config> =
+        StorageMap<_, Identity, u16, u128, ValueQuery, DefaultZeroU128<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, slot ) --> (block, price_cumulative) | Ring buffer of cumulative price observations.
+    pub type SubnetPriceObservations<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u64, (u64, u128), OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> total_volume | The total amount of TAO bought and sold since the start of the network.
     pub type SubnetVolume<T: Config> =
         StorageMap<_, Identity, u16, u128, ValueQuery, DefaultZeroU128<T>>;
@@ -1192,6 +1352,66 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> pending_owner_cut
     pub type PendingOwnerCut<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> buyback_program
+    pub type SubnetBuybackProgram<T> = StorageMap<_, Identity, u16, BuybackProgram, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> buyback_budget, TAO funded by the owner for buybacks.
+    pub type SubnetBuybackBudget<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> cumulative TAO spent on buybacks.
+    pub type SubnetBuybackTaoSpent<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> cumulative alpha bought back from the pool.
+    pub type SubnetBuybackAlphaBought<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> cumulative alpha burned by the buyback program.
+    pub type SubnetBuybackAlphaBurned<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::type_value]
+    /// Default voting window of subnet proposals.
+    pub fn DefaultSubnetProposalVotingPeriod<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> whether owner hyperparameter changes need alpha holder ratification.
+    pub type SubnetGovernanceEnabled<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultFalse<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, proposal_id ) --> open subnet hyperparameter proposal
+    pub type SubnetProposals<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Identity,
+        u32,
+        SubnetProposal<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> id of the next subnet proposal
+    pub type NextSubnetProposalId<T> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( (netuid, proposal_id), coldkey ) --> (approve, alpha weight) of a subnet proposal vote
+    pub type SubnetProposalVotes<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        (u16, u32),
+        Blake2_128Concat,
+        T::AccountId,
+        (bool, u64),
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( block ) --> (netuid, proposal_id) of the subnet proposals closing at the block
+    pub type SubnetProposalQueue<T> = StorageMap<_, Identity, u64, Vec<(u16, u32)>, ValueQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( subnet_proposal_voting_period )
+    pub type SubnetProposalVotingPeriod<T> =
+        StorageValue<_, u64, ValueQuery, DefaultSubnetProposalVotingPeriod<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> blocks_since_last_step
     pub type BlocksSinceLastStep<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBlocksSinceLastStep<T>>;
@@ -2190,6 +2410,20 @@ where
                     Self::get_priority_staking(who, hotkey, *alpha_amount),
                 )
             }
+            Some(Call::rebalance_stake { targets, .. }) => {
+                if ColdkeySwapScheduled::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInSwapSchedule.into(),
+                    )
+                    .into();
+                }
+
+                // Validate the targets, the moves are validated on execution
+                Self::result_to_validity(
+                    Pallet::<T>::validate_rebalance_targets(targets),
+                    Self::get_priority_vanilla(),
+                )
+            }
             Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
                 if ColdkeySwapScheduled::<T>::contains_key(who) {
                     return InvalidTransaction::Custom(
@@ -2446,3 +2680,30 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
         Ok(true)
     }
 }
+
+/// Lets other pallets clean up data kept for a hotkey or a subnet once it is gone.
+pub trait OnNeuronRemoved<AccountId> {
+    /// The hotkey was deregistered from the subnet, its UID replaced by a new hotkey
+    fn on_neuron_removed(netuid: u16, hotkey: &AccountId);
+
+    /// The subnet was removed
+    fn on_network_removed(netuid: u16);
+}
+
+impl<A> OnNeuronRemoved<A> for () {
+    fn on_neuron_removed(_: u16, _: &A) {}
+
+    fn on_network_removed(_: u16) {}
+}
+
+/// Lets the identity registry expose the organisation an account belongs to.
+pub trait IdentityInterface<AccountId> {
+    /// The parent identity of the account, if a registrar verified it
+    fn verified_parent(who: &AccountId) -> Option<AccountId>;
+}
+
+impl<A> IdentityInterface<A> for () {
+    fn verified_parent(_: &A) -> Option<A> {
+        None
+    }
+}
@@ -36,6 +36,12 @@ mod config {
         /// Interface to allow other pallets to control who can register identities
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
+        /// Notified when a hotkey is deregistered or a subnet is removed.
+        type OnNeuronRemoved: crate::OnNeuronRemoved<Self::AccountId>;
+
+        /// Looks up the verified organisation identity a delegate is linked to.
+        type IdentityInterface: crate::IdentityInterface<Self::AccountId>;
+
         /// The scheduler type used for scheduling delayed calls.
         type Scheduler: ScheduleAnon<
                 BlockNumberFor<Self>,
@@ -213,5 +219,8 @@ mod config {
         /// Block number after a new subnet accept the start call extrinsic.
         #[pallet::constant]
         type DurationOfStartCall: Get<u64>;
+        /// The longest window, in blocks, over which the alpha TWAP can be queried.
+        #[pallet::constant]
+        type MaxTwapWindowBlocks: Get<u64>;
     }
 }
@@ -12,7 +12,7 @@ mod dispatches {
     use sp_core::ecdsa::Signature;
     use sp_runtime::traits::Saturating;
 
-    use crate::MAX_CRV3_COMMIT_SIZE_BYTES;
+    use crate::{MAX_CRV3_COMMIT_SIZE_BYTES, MAX_SUBNET_PROPOSAL_VOTERS, MAX_TAKE_CLASS_REFRESHES};
     /// Dispatchable functions allow users to interact with the pallet and invoke state changes.
     /// These functions materialize as "extrinsics", which are often compared to transactions.
     /// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
@@ -280,8 +280,8 @@ mod dispatches {
         ///
         #[pallet::call_index(99)]
         #[pallet::weight((Weight::from_parts(46_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
         pub fn commit_crv3_weights(
             origin: T::RuntimeOrigin,
             netuid: u16,
@@ -881,8 +881,8 @@ mod dispatches {
         ///
         #[pallet::call_index(6)]
         #[pallet::weight((Weight::from_parts(192_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(24))
-		.saturating_add(T::DbWeight::get().writes(22)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(28))
+		.saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Normal, Pays::No))]
         pub fn register(
             origin: OriginFor<T>,
             netuid: u16,
@@ -898,8 +898,8 @@ mod dispatches {
         /// Register the hotkey to root network
         #[pallet::call_index(62)]
         #[pallet::weight((Weight::from_parts(164_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(23))
-		.saturating_add(T::DbWeight::get().writes(20)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(27))
+		.saturating_add(T::DbWeight::get().writes(28)), DispatchClass::Normal, Pays::No))]
         pub fn root_register(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
             Self::do_root_register(origin, hotkey)
         }
@@ -916,8 +916,8 @@ mod dispatches {
         /// User register a new subnetwork via burning token
         #[pallet::call_index(7)]
         #[pallet::weight((Weight::from_parts(177_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(26))
-		.saturating_add(T::DbWeight::get().writes(24)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(30))
+		.saturating_add(T::DbWeight::get().writes(32)), DispatchClass::Normal, Pays::No))]
         pub fn burned_register(
             origin: OriginFor<T>,
             netuid: u16,
@@ -2023,5 +2023,274 @@ mod dispatches {
         ) -> DispatchResult {
             Self::do_burn_alpha(origin, hotkey, amount, netuid)
         }
+
+        /// Sets the alpha buyback program of a subnet.
+        ///
+        /// Every epoch the program burns a share of the owner cut and spends part of the
+        /// buyback budget buying alpha from the pool, which is burned as well.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed by the subnet owner)
+        /// * `netuid` - The subnet ID
+        /// * `owner_cut_burn` - Proportion of the owner cut burned each epoch, normalized by u16::MAX
+        /// * `tao_per_epoch` - Maximum TAO from the budget spent buying alpha each epoch
+        /// * `max_price` - Highest alpha price, in rao per alpha, at which alpha is bought back
+        ///
+        /// # Events
+        /// Emits a `BuybackProgramSet` event on success.
+        #[pallet::call_index(103)]
+        #[pallet::weight((
+            Weight::from_parts(2_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_buyback_program(
+            origin: T::RuntimeOrigin,
+            netuid: u16,
+            owner_cut_burn: u16,
+            tao_per_epoch: u64,
+            max_price: u64,
+        ) -> DispatchResult {
+            Self::do_set_buyback_program(origin, netuid, owner_cut_burn, tao_per_epoch, max_price)
+        }
+
+        /// Adds TAO from the subnet owner coldkey to the buyback budget of a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed by the subnet owner)
+        /// * `netuid` - The subnet ID
+        /// * `amount` - The amount of TAO to add to the budget
+        ///
+        /// # Events
+        /// Emits a `BuybackProgramFunded` event on success.
+        #[pallet::call_index(104)]
+        #[pallet::weight((
+            Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 2)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn fund_buyback_program(
+            origin: T::RuntimeOrigin,
+            netuid: u16,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_fund_buyback_program(origin, netuid, amount)
+        }
+
+        /// Removes the buyback program of a subnet, refunding the unspent budget to the owner.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed by the subnet owner)
+        /// * `netuid` - The subnet ID
+        ///
+        /// # Events
+        /// Emits a `BuybackProgramCleared` event on success.
+        #[pallet::call_index(105)]
+        #[pallet::weight((
+            Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 3)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn clear_buyback_program(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+            Self::do_clear_buyback_program(origin, netuid)
+        }
+
+        /// Rebalances the stake of the coldkey across hotkeys and subnets in a single call.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey.
+        /// * `targets` - The `(hotkey, netuid, fraction)` targets, fractions normalized by
+        ///   u16::MAX and summing to u16::MAX. Stake outside of the targets is moved into them.
+        /// * `max_slippage` - The largest loss of stake value, including fees, normalized by
+        ///   u16::MAX.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The targets are empty, too many, repeated or do not sum to u16::MAX.
+        /// * A target subnet or hotkey does not exist.
+        /// * The coldkey has no stake, or stake in more than `MAX_REBALANCE_POSITIONS` positions.
+        /// * The stake value lost exceeds `max_slippage`.
+        /// * Any of the planned stake moves fails.
+        ///
+        /// # Events
+        /// Emits a `StakeRebalanced` event with the final allocation on success.
+        #[pallet::call_index(106)]
+        #[pallet::weight((
+            Pallet::<T>::rebalance_stake_weight(targets.len() as u64),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn rebalance_stake(
+            origin: T::RuntimeOrigin,
+            targets: Vec<(T::AccountId, u16, u16)>,
+            max_slippage: u16,
+        ) -> DispatchResult {
+            Self::do_rebalance_stake(origin, targets, max_slippage)
+        }
+
+        /// --- Sets the tiered take schedule of a delegate. Changes that can raise the take
+        /// of a nominator are rate-limited.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>::Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey we are delegating (must be owned by the coldkey.)
+        ///
+        /// * 'tiers' (Vec<(u64, u16)>):
+        /// 	- The (stake threshold in TAO, take) schedule with strictly increasing thresholds.
+        ///        A nominator whose stake on a subnet is worth at least a threshold is charged
+        ///        the take of that tier if it is lower than the delegate take. An empty
+        ///        schedule removes the tiers.
+        ///
+        /// # Event:
+        /// * DelegateTakeTiersSet;
+        /// 	- On successfully setting the take tiers for this hotkey.
+        ///
+        /// # Raises:
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey we are delegating is not owned by the calling coldkey.
+        ///
+        /// * 'InvalidDelegateTakeTiers':
+        /// 	- There are too many tiers or the thresholds are not strictly increasing.
+        ///
+        /// * 'DelegateTakeTooLow' / 'DelegateTakeTooHigh':
+        /// 	- A tier take is outside of the allowed delegate take range.
+        ///
+        #[pallet::call_index(107)]
+        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(4, 2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_take_tiers(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            tiers: Vec<(u64, u16)>,
+        ) -> DispatchResult {
+            Self::do_set_delegate_take_tiers(origin, hotkey, tiers)
+        }
+
+        /// --- Sets or removes the take a delegate charges a specific nominator coldkey.
+        /// Changes that can raise the take of the nominator are rate-limited.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>::Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey we are delegating (must be owned by the coldkey.)
+        ///
+        /// * 'nominator' (T::AccountId):
+        /// 	- The nominator coldkey the take applies to.
+        ///
+        /// * 'take' (Option<u16>):
+        /// 	- The take charged to the nominator when lower than the delegate take,
+        ///        or None to remove the override.
+        ///
+        /// # Event:
+        /// * DelegateTakeOverrideSet;
+        /// 	- On successfully setting or removing the override.
+        ///
+        /// # Raises:
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey we are delegating is not owned by the calling coldkey.
+        ///
+        /// * 'TooManyDelegateTakeOverrides':
+        /// 	- The delegate already has the maximum number of overrides.
+        ///
+        /// * 'DelegateTakeTooLow' / 'DelegateTakeTooHigh':
+        /// 	- The take is outside of the allowed delegate take range.
+        ///
+        #[pallet::call_index(108)]
+        #[pallet::weight((Weight::from_parts(3_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads_writes(68, 2))
+        .saturating_add(T::DbWeight::get().reads_writes(9, 5).saturating_mul(MAX_TAKE_CLASS_REFRESHES as u64)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_take_override(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            nominator: T::AccountId,
+            take: Option<u16>,
+        ) -> DispatchResult {
+            Self::do_set_delegate_take_override(origin, hotkey, nominator, take)
+        }
+
+        /// Enables or disables alpha holder governance of a subnet.
+        ///
+        /// While enabled, the subnet owner changes hyperparameters through
+        /// `propose_subnet_hyperparameter` instead of the admin-utils setters.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (the subnet owner to enable, root to enable or disable)
+        /// * `netuid` - The subnet ID
+        /// * `enabled` - Whether owner changes need alpha holder ratification
+        ///
+        /// # Events
+        /// Emits a `SubnetGovernanceSet` event on success.
+        #[pallet::call_index(109)]
+        #[pallet::weight((
+            Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_subnet_governance(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::do_set_subnet_governance(origin, netuid, enabled)
+        }
+
+        /// Proposes a hyperparameter change on a governed subnet.
+        ///
+        /// The change applies when the voting window ends unless alpha holders veto it.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed by the subnet owner)
+        /// * `netuid` - The subnet ID
+        /// * `change` - The proposed hyperparameter change
+        ///
+        /// # Events
+        /// Emits a `SubnetProposalCreated` event on success.
+        #[pallet::call_index(110)]
+        #[pallet::weight((
+            Weight::from_parts(5_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(14, 3)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn propose_subnet_hyperparameter(
+            origin: OriginFor<T>,
+            netuid: u16,
+            change: SubnetHyperparameter,
+        ) -> DispatchResult {
+            Self::do_propose_subnet_hyperparameter(origin, netuid, change)
+        }
+
+        /// Votes to ratify or veto a subnet proposal, weighted by the caller's alpha on the subnet.
+        ///
+        /// Once the proposal has MAX_SUBNET_PROPOSAL_VOTERS voters, a new voter replaces the
+        /// voter with the least alpha if it holds more.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (the voting coldkey)
+        /// * `netuid` - The subnet ID
+        /// * `proposal_id` - The id of the proposal on the subnet
+        /// * `approve` - Whether to ratify or veto the change
+        ///
+        /// # Events
+        /// Emits a `SubnetProposalVoted` event on success.
+        #[pallet::call_index(111)]
+        #[pallet::weight((
+            Weight::from_parts(5_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(36, 3))
+                .saturating_add(T::DbWeight::get().reads(MAX_SUBNET_PROPOSAL_VOTERS as u64)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn vote_subnet_proposal(
+            origin: OriginFor<T>,
+            netuid: u16,
+            proposal_id: u32,
+            approve: bool,
+        ) -> DispatchResult {
+            Self::do_vote_subnet_proposal(origin, netuid, proposal_id, approve)
+        }
     }
 }
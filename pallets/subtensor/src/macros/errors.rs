@@ -207,5 +207,29 @@ mod errors {
         UnableToRecoverPublicKey,
         /// Recovered public key is invalid.
         InvalidRecoveredPublicKey,
+        /// The subnet has no buyback program.
+        BuybackProgramNotSet,
+        /// Rebalance targets are empty, too many, repeated or do not sum to u16::MAX.
+        InvalidRebalanceTargets,
+        /// Delegate take tiers are too many or their thresholds are not strictly increasing.
+        InvalidDelegateTakeTiers,
+        /// The delegate has reached the maximum number of nominator take overrides.
+        TooManyDelegateTakeOverrides,
+        /// The subnet is governed; owner hyperparameter changes must be proposed.
+        SubnetGovernanceActive,
+        /// The subnet is not governed by its alpha holders.
+        SubnetGovernanceNotEnabled,
+        /// The subnet has reached the maximum number of open proposals.
+        TooManySubnetProposals,
+        /// The subnet proposal does not exist.
+        SubnetProposalNotFound,
+        /// The voting window of the subnet proposal has ended.
+        SubnetProposalVotingClosed,
+        /// The coldkey holds no alpha on the subnet to vote with.
+        NoAlphaToVote,
+        /// The coldkey stakes with more hotkeys on more subnets than a rebalance can read.
+        TooManyStakePositions,
+        /// The subnet proposal has the maximum number of voters, all holding at least as much alpha.
+        TooManySubnetProposalVoters,
     }
 }
@@ -323,5 +323,109 @@ mod events {
         /// - **netuid**: The network identifier.
         /// - **Enabled**: Is Commit-Reveal enabled.
         CommitRevealEnabled(u16, bool),
+
+        /// A subnet owner has set the buyback program of a subnet.
+        BuybackProgramSet {
+            /// The subnet the program belongs to.
+            netuid: u16,
+            /// Proportion of the owner cut burned each epoch, normalized by u16::MAX.
+            owner_cut_burn: u16,
+            /// Maximum TAO spent buying alpha each epoch.
+            tao_per_epoch: u64,
+            /// Highest alpha price, in rao per alpha, at which alpha is bought back.
+            max_price: u64,
+        },
+
+        /// TAO has been added to the buyback budget of a subnet.
+        ///
+        /// Parameters:
+        /// (netuid, amount)
+        BuybackProgramFunded(u16, u64),
+
+        /// The buyback program of a subnet has been removed and its budget refunded.
+        ///
+        /// Parameters:
+        /// (netuid, refunded)
+        BuybackProgramCleared(u16, u64),
+
+        /// The buyback program of a subnet has bought and burned alpha.
+        AlphaBoughtBack {
+            /// The subnet the alpha was bought on.
+            netuid: u16,
+            /// The TAO spent from the buyback budget.
+            tao_spent: u64,
+            /// The alpha bought from the pool.
+            alpha_bought: u64,
+            /// The alpha burned, including the burned share of the owner cut.
+            alpha_burned: u64,
+        },
+
+        /// Stake has been rebalanced across hotkeys and subnets.
+        ///
+        /// Parameters:
+        /// (coldkey, [(hotkey, netuid, alpha)])
+        StakeRebalanced(T::AccountId, Vec<(T::AccountId, u16, u64)>),
+
+        /// Subnet governance by alpha holders has been enabled or disabled.
+        ///
+        /// Parameters:
+        /// (netuid, enabled)
+        SubnetGovernanceSet(u16, bool),
+
+        /// The owner of a governed subnet has proposed a hyperparameter change.
+        SubnetProposalCreated {
+            /// The subnet the proposal belongs to.
+            netuid: u16,
+            /// The id of the proposal on the subnet.
+            proposal_id: u32,
+            /// The proposed hyperparameter change.
+            change: SubnetHyperparameter,
+            /// Block at which the change applies unless vetoed.
+            end: u64,
+        },
+
+        /// An alpha holder has voted on a subnet proposal.
+        SubnetProposalVoted {
+            /// The subnet the proposal belongs to.
+            netuid: u16,
+            /// The id of the proposal on the subnet.
+            proposal_id: u32,
+            /// The voting coldkey.
+            coldkey: T::AccountId,
+            /// Whether the coldkey voted to ratify the change.
+            approve: bool,
+            /// The alpha stake the coldkey voted with.
+            weight: u64,
+        },
+
+        /// A subnet proposal was ratified and its change applied.
+        SubnetProposalApplied {
+            /// The subnet the proposal belongs to.
+            netuid: u16,
+            /// The id of the proposal on the subnet.
+            proposal_id: u32,
+            /// The result of applying the change.
+            result: DispatchResult,
+        },
+
+        /// A subnet proposal was vetoed by alpha holders.
+        SubnetProposalVetoed {
+            /// The subnet the proposal belongs to.
+            netuid: u16,
+            /// The id of the proposal on the subnet.
+            proposal_id: u32,
+        },
+
+        /// The tiered take schedule of a delegate has been set.
+        ///
+        /// Parameters:
+        /// (coldkey, hotkey, [(stake_threshold, take)])
+        DelegateTakeTiersSet(T::AccountId, T::AccountId, Vec<(u64, u16)>),
+
+        /// The take a delegate charges a nominator has been set or removed.
+        ///
+        /// Parameters:
+        /// (coldkey, hotkey, nominator, take)
+        DelegateTakeOverrideSet(T::AccountId, T::AccountId, T::AccountId, Option<u16>),
     }
 }
@@ -16,6 +16,9 @@ mod hooks {
         // 		- The number of the block we are initializing.
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
             let block_step_result = Self::block_step();
+            // --- Apply or veto the subnet proposals whose voting window has ended.
+            let subnet_proposals_weight =
+                Self::process_subnet_proposals(Self::get_current_block_as_u64());
             match block_step_result {
                 Ok(_) => {
                     // --- If the block step was successful, return the weight.
@@ -23,6 +26,8 @@ mod hooks {
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(Self::update_price_accumulators_weight())
+                        .saturating_add(subnet_proposals_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -30,6 +35,8 @@ mod hooks {
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(Self::update_price_accumulators_weight())
+                        .saturating_add(subnet_proposals_weight)
                 }
             }
         }
@@ -87,7 +94,9 @@ mod hooks {
                 // Remove all zero value entries in TotalHotkeyAlpha
                 .saturating_add(migrations::migrate_remove_zero_total_hotkey_alpha::migrate_remove_zero_total_hotkey_alpha::<T>())
                 // Wipe existing items to prevent bad decoding for new type
-                .saturating_add(migrations::migrate_upgrade_revealed_commitments::migrate_upgrade_revealed_commitments::<T>());
+                .saturating_add(migrations::migrate_upgrade_revealed_commitments::migrate_upgrade_revealed_commitments::<T>())
+                // Start pruning drand pulses stored before the retention window existed
+                .saturating_add(migrations::migrate_seed_drand_oldest_round::migrate_seed_drand_oldest_round::<T>());
             weight
         }
 
@@ -0,0 +1,59 @@
+use super::*;
+use crate::HasMigrationRun;
+use frame_support::{traits::Get, weights::Weight};
+use scale_info::prelude::string::String;
+
+/// Seeds `pallet_drand::OldestStoredRound` with the first stored pulse so that pulses written
+/// before pruning existed are pruned too.
+pub fn migrate_seed_drand_oldest_round<T: Config>() -> Weight {
+    let migration_name = b"migrate_seed_drand_oldest_round".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    let last_stored_round = pallet_drand::LastStoredRound::<T>::get();
+    let oldest_stored_round = pallet_drand::OldestStoredRound::<T>::get();
+    weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+    if oldest_stored_round == 0 && pallet_drand::Pulses::<T>::contains_key(last_stored_round) {
+        // Pulses are written without gaps, so binary search for the first stored round.
+        let mut low: u64 = 1;
+        let mut high = last_stored_round;
+        let mut reads: u64 = 1;
+        while low < high {
+            let mid = low.saturating_add(high.saturating_sub(low) / 2);
+            if pallet_drand::Pulses::<T>::contains_key(mid) {
+                high = mid;
+            } else {
+                low = mid.saturating_add(1);
+            }
+            reads = reads.saturating_add(1);
+        }
+
+        pallet_drand::OldestStoredRound::<T>::put(low);
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(reads, 1));
+
+        log::info!("Seeded the oldest stored drand round with {}.", low);
+    }
+
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed successfully.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    weight
+}
@@ -12,6 +12,7 @@ pub mod migrate_rao;
 pub mod migrate_remove_stake_map;
 pub mod migrate_remove_unused_maps_and_values;
 pub mod migrate_remove_zero_total_hotkey_alpha;
+pub mod migrate_seed_drand_oldest_round;
 pub mod migrate_set_first_emission_block_number;
 pub mod migrate_set_min_burn;
 pub mod migrate_set_min_difficulty;
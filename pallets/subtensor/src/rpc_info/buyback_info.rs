@@ -0,0 +1,40 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+
+#[freeze_struct("c3ab1969e7de4747")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetBuybackInfo {
+    pub netuid: Compact<u16>,
+    pub program: Option<BuybackProgram>,
+    pub budget: Compact<u64>,
+    pub tao_spent: Compact<u64>,
+    pub alpha_bought: Compact<u64>,
+    pub alpha_burned: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_subnet_buyback_info(netuid: u16) -> Option<SubnetBuybackInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        Some(SubnetBuybackInfo {
+            netuid: netuid.into(),
+            program: SubnetBuybackProgram::<T>::get(netuid),
+            budget: SubnetBuybackBudget::<T>::get(netuid).into(),
+            tao_spent: SubnetBuybackTaoSpent::<T>::get(netuid).into(),
+            alpha_bought: SubnetBuybackAlphaBought::<T>::get(netuid).into(),
+            alpha_burned: SubnetBuybackAlphaBurned::<T>::get(netuid).into(),
+        })
+    }
+    pub fn get_all_subnet_buyback_info() -> Vec<Option<SubnetBuybackInfo>> {
+        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
+        let mut buyback_info = Vec::<Option<SubnetBuybackInfo>>::new();
+        for netuid in netuids.iter() {
+            buyback_info.push(Self::get_subnet_buyback_info(*netuid));
+        }
+        buyback_info
+    }
+}
@@ -7,7 +7,7 @@ extern crate alloc;
 use alloc::collections::BTreeMap;
 use codec::Compact;
 
-#[freeze_struct("7cd21f57627d2d0d")]
+#[freeze_struct("f3402a4ee0942a1c")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct DelegateInfo<AccountId: TypeInfo + Encode + Decode> {
     pub delegate_ss58: AccountId,
@@ -18,6 +18,9 @@ pub struct DelegateInfo<AccountId: TypeInfo + Encode + Decode> {
     pub validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
     pub return_per_1000: Compact<u64>, // Delegators current daily return per 1000 TAO staked minus take fee
     pub total_daily_return: Compact<u64>, // Delegators current daily return
+    pub take_tiers: Vec<(Compact<u64>, Compact<u16>)>, // Vec of stake threshold and take of each tier
+    pub take_overrides: Vec<(AccountId, Compact<u16>)>, // Vec of nominator coldkey and take override
+    pub verified_parent: Option<AccountId>, // Verified organisation identity the delegate is a sub-account of
 }
 
 impl<T: Config> Pallet<T> {
@@ -122,6 +125,14 @@ impl<T: Config> Pallet<T> {
             validator_permits,
             return_per_1000: return_per_1000.saturating_to_num::<u64>().into(),
             total_daily_return: emissions_per_day.saturating_to_num::<u64>().into(),
+            take_tiers: DelegateTakeTiers::<T>::get(&delegate)
+                .into_iter()
+                .map(|(threshold, take)| (threshold.into(), take.into()))
+                .collect(),
+            take_overrides: DelegateTakeOverrides::<T>::iter_prefix(&delegate)
+                .map(|(nominator, take)| (nominator, take.into()))
+                .collect(),
+            verified_parent: T::IdentityInterface::verified_parent(&delegate),
         }
     }
 
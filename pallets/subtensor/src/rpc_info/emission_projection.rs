@@ -0,0 +1,199 @@
+use super::*;
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use substrate_fixed::types::U96F32;
+use subtensor_macros::freeze_struct;
+
+/// The maximum number of blocks an emission projection will simulate.
+pub const MAX_EMISSION_PROJECTION_BLOCKS: u64 = 50_400;
+
+#[freeze_struct("f66b495901c9b4cc")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct EmissionTotals {
+    pub blocks: Compact<u64>,
+    pub tao_in: Compact<u64>,
+    pub alpha_in: Compact<u64>,
+    pub alpha_out: Compact<u64>,
+    pub owner_cut: Compact<u64>,
+}
+
+#[freeze_struct("fcef533da1dd2a9b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetEmissionProjection {
+    pub netuid: Compact<u16>,
+    pub next_blocks: EmissionTotals,
+    pub next_epochs: Vec<EmissionTotals>,
+}
+
+impl Default for EmissionTotals {
+    fn default() -> Self {
+        Self {
+            blocks: 0.into(),
+            tao_in: 0.into(),
+            alpha_in: 0.into(),
+            alpha_out: 0.into(),
+            owner_cut: 0.into(),
+        }
+    }
+}
+
+impl EmissionTotals {
+    fn accumulate(&mut self, tao_in: u64, alpha_in: u64, alpha_out: u64, owner_cut: u64) {
+        self.blocks = self.blocks.0.saturating_add(1).into();
+        self.tao_in = self.tao_in.0.saturating_add(tao_in).into();
+        self.alpha_in = self.alpha_in.0.saturating_add(alpha_in).into();
+        self.alpha_out = self.alpha_out.0.saturating_add(alpha_out).into();
+        self.owner_cut = self.owner_cut.0.saturating_add(owner_cut).into();
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Projects the emission of every subnet over the next `blocks` blocks and `epochs` epochs.
+    ///
+    /// The projection replays the coinbase injection math in read-only mode. Prices and
+    /// moving prices are assumed to stay at their current values, while the TAO and alpha
+    /// issuance grow with the projected emission so that halvings are taken into account.
+    /// The first projected epoch is the (possibly partial) one drained at the next epoch
+    /// boundary. `alpha_out` is reported before the owner cut is removed.
+    ///
+    /// At most `MAX_EMISSION_PROJECTION_BLOCKS` blocks are simulated; epochs ending past
+    /// that horizon are truncated.
+    pub fn get_emission_projection(blocks: u64, epochs: u16) -> Vec<SubnetEmissionProjection> {
+        let current_block: u64 = Self::get_current_block_as_u64();
+
+        // Same subnet selection as in `run_coinbase`.
+        let subnets_to_emit_to: Vec<u16> = Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|netuid| *netuid != 0)
+            .filter(|netuid| FirstEmissionBlockNumber::<T>::get(*netuid).is_some())
+            .collect();
+
+        // Simulate long enough to cover the requested blocks and epochs for all subnets.
+        let mut horizon: u64 = blocks;
+        if epochs > 0 {
+            for netuid in subnets_to_emit_to.iter() {
+                let tempo: u16 = Self::get_tempo(*netuid);
+                let first_epoch: u64 =
+                    Self::blocks_until_next_epoch(*netuid, tempo, current_block.saturating_add(1))
+                        .saturating_add(1);
+                let epoch_blocks: u64 = first_epoch.saturating_add(
+                    u64::from(epochs.saturating_sub(1))
+                        .saturating_mul(u64::from(tempo).saturating_add(1)),
+                );
+                horizon = horizon.max(epoch_blocks);
+            }
+        }
+        let horizon: u64 = horizon.min(MAX_EMISSION_PROJECTION_BLOCKS);
+
+        // Prices and moving prices are held constant over the projection.
+        let total_moving_prices: U96F32 = Self::get_total_moving_prices(&subnets_to_emit_to);
+        let cut_percent: U96F32 = Self::get_float_subnet_owner_cut();
+
+        let mut projections: BTreeMap<u16, SubnetEmissionProjection> = BTreeMap::new();
+        let mut alpha_issuance: BTreeMap<u16, u64> = BTreeMap::new();
+        let mut alpha_emission: BTreeMap<u16, (u64, u64)> = BTreeMap::new();
+        let mut epochs_drained: BTreeMap<u16, u16> = BTreeMap::new();
+        for netuid in subnets_to_emit_to.iter() {
+            projections.insert(
+                *netuid,
+                SubnetEmissionProjection {
+                    netuid: (*netuid).into(),
+                    next_blocks: EmissionTotals::default(),
+                    next_epochs: if epochs > 0 {
+                        vec![EmissionTotals::default()]
+                    } else {
+                        Vec::new()
+                    },
+                },
+            );
+            alpha_issuance.insert(*netuid, Self::get_alpha_issuance(*netuid));
+        }
+
+        // Emission only changes at halvings, so it is recomputed only when crossing one.
+        let mut total_issuance: u64 = Self::get_total_issuance();
+        let mut block_emission: (u64, u64) = (0, 0);
+
+        for offset in 1..=horizon {
+            let block_number: u64 = current_block.saturating_add(offset);
+            block_emission = Self::get_projected_block_emission(total_issuance, block_emission);
+
+            let mut tao_in_total: u64 = 0;
+            for netuid in subnets_to_emit_to.iter() {
+                let issuance: u64 = alpha_issuance.get(netuid).copied().unwrap_or(0);
+                let (emission, next_halving) = Self::get_projected_block_emission(
+                    issuance,
+                    alpha_emission.get(netuid).copied().unwrap_or((0, 0)),
+                );
+                alpha_emission.insert(*netuid, (emission, next_halving));
+
+                let (tao_in_i, alpha_in_i, alpha_out_i) = Self::get_subnet_injection_terms(
+                    *netuid,
+                    U96F32::saturating_from_num(block_emission.0),
+                    total_moving_prices,
+                    U96F32::saturating_from_num(emission),
+                );
+                let owner_cut_i: u64 = alpha_out_i
+                    .saturating_mul(cut_percent)
+                    .saturating_to_num::<u64>();
+                let tao_in_i: u64 = tao_in_i.saturating_to_num::<u64>();
+                let alpha_in_i: u64 = alpha_in_i.saturating_to_num::<u64>();
+                let alpha_out_i: u64 = alpha_out_i.saturating_to_num::<u64>();
+
+                if let Some(projection) = projections.get_mut(netuid) {
+                    if offset <= blocks {
+                        projection.next_blocks.accumulate(
+                            tao_in_i,
+                            alpha_in_i,
+                            alpha_out_i,
+                            owner_cut_i,
+                        );
+                    }
+                    let drained: u16 = epochs_drained.get(netuid).copied().unwrap_or(0);
+                    if drained < epochs {
+                        if let Some(epoch) = projection.next_epochs.last_mut() {
+                            epoch.accumulate(tao_in_i, alpha_in_i, alpha_out_i, owner_cut_i);
+                        }
+                        // Pending emission is drained at the epoch block, start the next one.
+                        if Self::should_run_epoch(*netuid, block_number) {
+                            let drained: u16 = drained.saturating_add(1);
+                            epochs_drained.insert(*netuid, drained);
+                            if drained < epochs {
+                                projection.next_epochs.push(EmissionTotals::default());
+                            }
+                        }
+                    }
+                }
+
+                alpha_issuance.insert(
+                    *netuid,
+                    issuance
+                        .saturating_add(alpha_in_i)
+                        .saturating_add(alpha_out_i),
+                );
+                tao_in_total = tao_in_total.saturating_add(tao_in_i);
+            }
+            total_issuance = total_issuance.saturating_add(tao_in_total);
+        }
+
+        projections.into_values().collect()
+    }
+
+    /// Returns the block emission for `issuance` together with the issuance at which it
+    /// must be recomputed, reusing `cached` while no halving has been crossed.
+    ///
+    /// The fixed point emission math can lag the exact halving threshold slightly, so the
+    /// threshold is only advanced once the recomputed emission actually changed.
+    fn get_projected_block_emission(issuance: u64, cached: (u64, u64)) -> (u64, u64) {
+        let (emission, next_halving) = cached;
+        if next_halving != 0 && issuance < next_halving {
+            return cached;
+        }
+        let updated: u64 = Self::calculate_block_emission_for_issuance(issuance).unwrap_or(0);
+        if next_halving != 0 && updated == emission && emission != 0 {
+            return (emission, next_halving);
+        }
+        (updated, Self::get_next_halving_issuance(issuance))
+    }
+}
@@ -1,8 +1,12 @@
 use super::*;
+pub mod buyback_info;
 pub mod delegate_info;
 pub mod dynamic_info;
+pub mod emission_projection;
 pub mod metagraph;
 pub mod neuron_info;
+pub mod rebalance_info;
 pub mod show_subnet;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod subnet_proposal_info;
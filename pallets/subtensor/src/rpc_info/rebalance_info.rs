@@ -0,0 +1,71 @@
+use super::*;
+use crate::staking::rebalance::PlannedMove;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+#[freeze_struct("c5a76c810521b131")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct RebalanceMove<AccountId: TypeInfo + Encode + Decode> {
+    origin_hotkey: AccountId,
+    origin_netuid: Compact<u16>,
+    destination_hotkey: AccountId,
+    destination_netuid: Compact<u16>,
+    alpha_amount: Compact<u64>,
+    tao_amount: Compact<u64>,
+    fee: Compact<u64>,
+}
+
+#[freeze_struct("7a80ffd9ac45487e")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct RebalancePlan<AccountId: TypeInfo + Encode + Decode> {
+    total_value: Compact<u64>,
+    total_fee: Compact<u64>,
+    moves: Vec<RebalanceMove<AccountId>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Simulates `rebalance_stake` for a coldkey, returning the planned moves and fees, or
+    /// `None` if the targets are invalid or the coldkey has no stake.
+    pub fn simulate_rebalance_stake(
+        coldkey: T::AccountId,
+        targets: Vec<(T::AccountId, u16, u16)>,
+    ) -> Option<RebalancePlan<T::AccountId>> {
+        let planned: Vec<PlannedMove<T::AccountId>> =
+            Self::plan_rebalance_stake(&coldkey, &targets).ok()?;
+
+        let total_value: u64 =
+            Self::get_all_subnet_netuids()
+                .into_iter()
+                .fold(0u64, |total, netuid| {
+                    StakingHotkeys::<T>::get(&coldkey)
+                        .iter()
+                        .fold(total, |total, hotkey| {
+                            let alpha: u64 = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                                hotkey, &coldkey, netuid,
+                            );
+                            total.saturating_add(Self::get_alpha_value(netuid, alpha))
+                        })
+                });
+        let total_fee: u64 = planned
+            .iter()
+            .fold(0u64, |total, planned| total.saturating_add(planned.fee));
+
+        Some(RebalancePlan {
+            total_value: total_value.into(),
+            total_fee: total_fee.into(),
+            moves: planned
+                .into_iter()
+                .map(|planned| RebalanceMove {
+                    origin_hotkey: planned.origin_hotkey,
+                    origin_netuid: planned.origin_netuid.into(),
+                    destination_hotkey: planned.destination_hotkey,
+                    destination_netuid: planned.destination_netuid.into(),
+                    alpha_amount: planned.alpha_amount.into(),
+                    tao_amount: planned.tao_amount.into(),
+                    fee: planned.fee.into(),
+                })
+                .collect(),
+        })
+    }
+}
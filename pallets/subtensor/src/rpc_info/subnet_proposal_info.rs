@@ -0,0 +1,33 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+
+#[freeze_struct("c3125bb05b3eb203")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetProposalInfo<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<u16>,
+    pub proposal_id: Compact<u32>,
+    pub proposer: AccountId,
+    pub change: SubnetHyperparameter,
+    pub end: Compact<u64>,
+    pub ayes: Compact<u64>,
+    pub nays: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_subnet_proposals(netuid: u16) -> Vec<SubnetProposalInfo<T::AccountId>> {
+        SubnetProposals::<T>::iter_prefix(netuid)
+            .map(|(proposal_id, proposal)| SubnetProposalInfo {
+                netuid: netuid.into(),
+                proposal_id: proposal_id.into(),
+                proposer: proposal.proposer,
+                change: proposal.change,
+                end: proposal.end.into(),
+                ayes: proposal.ayes.into(),
+                nays: proposal.nays.into(),
+            })
+            .collect()
+    }
+}
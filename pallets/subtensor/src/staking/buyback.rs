@@ -0,0 +1,205 @@
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+
+impl<T: Config> Pallet<T> {
+    /// Sets or replaces the alpha buyback program of a subnet.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call (must be signed by the subnet owner)
+    /// * `netuid` - The subnet ID
+    /// * `owner_cut_burn` - Proportion of the owner cut burned each epoch, normalized by u16::MAX
+    /// * `tao_per_epoch` - Maximum TAO from the budget spent buying alpha each epoch
+    /// * `max_price` - Highest alpha price, in rao per alpha, at which alpha is bought back
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_set_buyback_program(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        owner_cut_burn: u16,
+        tao_per_epoch: u64,
+        max_price: u64,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::CannotBurnOrRecycleOnRootSubnet
+        );
+
+        SubnetBuybackProgram::<T>::insert(
+            netuid,
+            BuybackProgram {
+                owner_cut_burn,
+                tao_per_epoch,
+                max_price,
+            },
+        );
+
+        Self::deposit_event(Event::BuybackProgramSet {
+            netuid,
+            owner_cut_burn,
+            tao_per_epoch,
+            max_price,
+        });
+
+        Ok(())
+    }
+
+    /// Moves TAO from the subnet owner coldkey into the buyback budget of the subnet.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call (must be signed by the subnet owner)
+    /// * `netuid` - The subnet ID
+    /// * `amount` - The amount of TAO to add to the budget
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_fund_buyback_program(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        amount: u64,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin.clone())?;
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            SubnetBuybackProgram::<T>::contains_key(netuid),
+            Error::<T>::BuybackProgramNotSet
+        );
+
+        ensure!(amount > 0, Error::<T>::AmountTooLow);
+
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, amount),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+
+        // Take the TAO out of circulation until it is spent in the pool or refunded.
+        let funded: u64 = Self::remove_balance_from_coldkey_account(&coldkey, amount)?;
+        SubnetBuybackBudget::<T>::mutate(netuid, |budget| {
+            *budget = budget.saturating_add(funded);
+        });
+
+        Self::deposit_event(Event::BuybackProgramFunded(netuid, funded));
+
+        Ok(())
+    }
+
+    /// Removes the buyback program of a subnet, refunding the remaining budget to the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call (must be signed by the subnet owner)
+    /// * `netuid` - The subnet ID
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_clear_buyback_program(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            SubnetBuybackProgram::<T>::contains_key(netuid),
+            Error::<T>::BuybackProgramNotSet
+        );
+
+        Self::remove_buyback_program_and_refund(netuid);
+
+        Ok(())
+    }
+
+    /// Removes the buyback program of a subnet and refunds the unspent budget to the owner.
+    pub fn remove_buyback_program_and_refund(netuid: u16) {
+        SubnetBuybackProgram::<T>::remove(netuid);
+        let refund: u64 = SubnetBuybackBudget::<T>::take(netuid);
+        Self::add_balance_to_coldkey_account(&SubnetOwner::<T>::get(netuid), refund);
+
+        Self::deposit_event(Event::BuybackProgramCleared(netuid, refund));
+    }
+
+    /// Executes the buyback program of a subnet when its pending emission is drained.
+    ///
+    /// Burns the configured proportion of the owner cut and spends up to `tao_per_epoch`
+    /// of the budget buying alpha from the pool, as long as the price stays at or below
+    /// `max_price`. All alpha burned here is removed from `SubnetAlphaOut`.
+    ///
+    /// # Arguments
+    ///
+    /// * `netuid` - The subnet ID
+    /// * `owner_cut` - The owner cut drained this epoch
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - The owner cut left to distribute to the subnet owner
+    pub fn run_buyback_program(netuid: u16, owner_cut: u64) -> u64 {
+        let Some(program) = SubnetBuybackProgram::<T>::get(netuid) else {
+            return owner_cut;
+        };
+
+        // --- 1. Burn the configured proportion of the owner cut.
+        let owner_cut_burned: u64 = U96F32::saturating_from_num(owner_cut)
+            .saturating_mul(U96F32::saturating_from_num(program.owner_cut_burn))
+            .safe_div(U96F32::saturating_from_num(u16::MAX))
+            .saturating_to_num::<u64>()
+            .min(owner_cut);
+
+        // --- 2. Buy alpha with the budget without pushing the price above the max price.
+        let budget: u64 = SubnetBuybackBudget::<T>::get(netuid);
+        let tao_to_spend: u64 = program
+            .tao_per_epoch
+            .min(budget)
+            .min(Self::get_max_amount_add(netuid, program.max_price));
+        let alpha_bought: u64 = if tao_to_spend > 0 {
+            Self::swap_tao_for_alpha(netuid, tao_to_spend)
+        } else {
+            0
+        };
+        // The swap is a no-op when it would drain the pool, keep the budget in that case.
+        let tao_spent: u64 = if alpha_bought > 0 { tao_to_spend } else { 0 };
+        SubnetBuybackBudget::<T>::insert(netuid, budget.saturating_sub(tao_spent));
+
+        // --- 3. Burn the owner cut share and the alpha bought back.
+        let alpha_burned: u64 = owner_cut_burned.saturating_add(alpha_bought);
+        if alpha_burned > 0 {
+            SubnetAlphaOut::<T>::mutate(netuid, |total| {
+                *total = total.saturating_sub(alpha_burned);
+            });
+        }
+
+        // --- 4. Record the cumulative program results.
+        SubnetBuybackTaoSpent::<T>::mutate(netuid, |total| {
+            *total = total.saturating_add(tao_spent);
+        });
+        SubnetBuybackAlphaBought::<T>::mutate(netuid, |total| {
+            *total = total.saturating_add(alpha_bought);
+        });
+        SubnetBuybackAlphaBurned::<T>::mutate(netuid, |total| {
+            *total = total.saturating_add(alpha_burned);
+        });
+
+        if tao_spent > 0 || alpha_burned > 0 {
+            Self::deposit_event(Event::AlphaBoughtBack {
+                netuid,
+                tao_spent,
+                alpha_bought,
+                alpha_burned,
+            });
+        }
+
+        owner_cut.saturating_sub(owner_cut_burned)
+    }
+}
@@ -1,11 +1,15 @@
 use super::*;
 pub mod account;
 pub mod add_stake;
+pub mod buyback;
 pub mod decrease_take;
 pub mod helpers;
 pub mod increase_take;
 pub mod move_stake;
+pub mod rebalance;
 pub mod recycle_alpha;
 pub mod remove_stake;
 pub mod set_children;
 pub mod stake_utils;
+pub mod take_tiers;
+pub mod twap;
@@ -0,0 +1,337 @@
+use super::*;
+use frame_support::weights::Weight;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+
+/// A stake move planned by a rebalance.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PlannedMove<AccountId> {
+    /// The hotkey the stake is moved from.
+    pub origin_hotkey: AccountId,
+    /// The subnet the stake is moved from.
+    pub origin_netuid: u16,
+    /// The hotkey the stake is moved to.
+    pub destination_hotkey: AccountId,
+    /// The subnet the stake is moved to.
+    pub destination_netuid: u16,
+    /// The origin alpha moved.
+    pub alpha_amount: u64,
+    /// The TAO expected from unstaking the origin alpha.
+    pub tao_amount: u64,
+    /// The staking fee charged for the move.
+    pub fee: u64,
+}
+
+/// A stake position of a coldkey considered by a rebalance.
+struct Position<AccountId> {
+    hotkey: AccountId,
+    netuid: u16,
+    alpha: u64,
+    value: u64,
+    target_value: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Rebalances the stake of a coldkey so that each `(hotkey, netuid)` target holds its
+    /// fraction of the total stake value.
+    ///
+    /// Stake held outside of the targets is moved into them. The required moves are planned
+    /// with `plan_rebalance_stake` and executed through `do_move_stake` and `do_swap_stake`,
+    /// so the whole rebalance fails if any of them fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the transaction, which must be signed by the coldkey.
+    /// * `targets` - The `(hotkey, netuid, fraction)` targets, fractions normalized by u16::MAX
+    ///   and summing to u16::MAX.
+    /// * `max_slippage` - The largest loss of stake value, including fees, normalized by u16::MAX.
+    ///
+    /// # Errors
+    ///
+    /// * `InvalidRebalanceTargets` - The targets are empty, too many, repeated or do not sum
+    ///   to u16::MAX.
+    /// * `SubnetNotExists` / `HotKeyAccountNotExists` - A target does not exist.
+    /// * `NotEnoughStakeToWithdraw` - The coldkey has no stake to rebalance.
+    /// * `TooManyStakePositions` - The staking hotkeys of the coldkey times the subnets exceed
+    ///   `MAX_REBALANCE_POSITIONS` positions.
+    /// * `SlippageTooHigh` - The stake value lost exceeds `max_slippage`.
+    ///
+    /// # Events
+    ///
+    /// Emits a `StakeRebalanced` event with the final allocation of the targets.
+    pub fn do_rebalance_stake(
+        origin: T::RuntimeOrigin,
+        targets: Vec<(T::AccountId, u16, u16)>,
+        max_slippage: u16,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+
+        let moves = Self::plan_rebalance_stake(&coldkey, &targets)?;
+
+        // Value the portfolio at the prices before the rebalance to measure the loss.
+        let prices: Vec<(u16, U96F32)> = Self::get_all_subnet_netuids()
+            .into_iter()
+            .map(|netuid| (netuid, Self::get_alpha_price(netuid)))
+            .collect();
+        let value_before: u64 = Self::get_coldkey_stake_value(&coldkey, &prices);
+
+        for planned in moves.into_iter() {
+            if planned.origin_hotkey == planned.destination_hotkey {
+                Self::do_swap_stake(
+                    origin.clone(),
+                    planned.origin_hotkey,
+                    planned.origin_netuid,
+                    planned.destination_netuid,
+                    planned.alpha_amount,
+                )?;
+            } else {
+                Self::do_move_stake(
+                    origin.clone(),
+                    planned.origin_hotkey,
+                    planned.destination_hotkey,
+                    planned.origin_netuid,
+                    planned.destination_netuid,
+                    planned.alpha_amount,
+                )?;
+            }
+        }
+
+        let value_after: u64 = Self::get_coldkey_stake_value(&coldkey, &prices);
+        let max_loss: u64 = U96F32::saturating_from_num(value_before)
+            .saturating_mul(U96F32::saturating_from_num(max_slippage))
+            .safe_div(U96F32::saturating_from_num(u16::MAX))
+            .saturating_to_num::<u64>();
+        ensure!(
+            value_before.saturating_sub(value_after) <= max_loss,
+            Error::<T>::SlippageTooHigh
+        );
+
+        let allocation: Vec<(T::AccountId, u16, u64)> = targets
+            .into_iter()
+            .map(|(hotkey, netuid, _)| {
+                let alpha =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+                (hotkey, netuid, alpha)
+            })
+            .collect();
+
+        log::debug!(
+            "StakeRebalanced( coldkey:{:?}, allocation:{:?} )",
+            coldkey.clone(),
+            allocation.clone()
+        );
+        Self::deposit_event(Event::StakeRebalanced(coldkey, allocation));
+
+        Ok(())
+    }
+
+    /// Checks that rebalance targets are well formed and refer to existing subnets and hotkeys.
+    pub fn validate_rebalance_targets(
+        targets: &[(T::AccountId, u16, u16)],
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            !targets.is_empty() && targets.len() <= MAX_REBALANCE_TARGETS as usize,
+            Error::<T>::InvalidRebalanceTargets
+        );
+
+        let mut total_fraction: u32 = 0;
+        for (index, (hotkey, netuid, fraction)) in targets.iter().enumerate() {
+            ensure!(
+                !targets.iter().skip(index.saturating_add(1)).any(
+                    |(other_hotkey, other_netuid, _)| {
+                        other_hotkey == hotkey && other_netuid == netuid
+                    }
+                ),
+                Error::<T>::InvalidRebalanceTargets
+            );
+            ensure!(Self::if_subnet_exist(*netuid), Error::<T>::SubnetNotExists);
+            ensure!(
+                Self::hotkey_account_exists(hotkey),
+                Error::<T>::HotKeyAccountNotExists
+            );
+            total_fraction = total_fraction.saturating_add(u32::from(*fraction));
+        }
+        ensure!(
+            total_fraction == u32::from(u16::MAX),
+            Error::<T>::InvalidRebalanceTargets
+        );
+
+        Ok(())
+    }
+
+    /// Plans the stake moves that bring the stake of `coldkey` to the target allocation.
+    ///
+    /// Positions are valued at the current alpha price. Every position holding more than its
+    /// target value is moved, in order, into the positions holding less than theirs. Moves
+    /// worth no more than `DefaultMinStake` are left out.
+    pub fn plan_rebalance_stake(
+        coldkey: &T::AccountId,
+        targets: &[(T::AccountId, u16, u16)],
+    ) -> Result<Vec<PlannedMove<T::AccountId>>, Error<T>> {
+        Self::validate_rebalance_targets(targets)?;
+
+        // --- 1. Bound the positions to read before reading them.
+        let hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(coldkey);
+        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
+        ensure!(
+            hotkeys.len().saturating_mul(netuids.len()) <= MAX_REBALANCE_POSITIONS as usize,
+            Error::<T>::TooManyStakePositions
+        );
+
+        // --- 2. Collect the current positions and the targets.
+        let mut positions: Vec<Position<T::AccountId>> = Vec::new();
+        for netuid in netuids {
+            for hotkey in hotkeys.iter().cloned() {
+                let alpha: u64 =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, coldkey, netuid);
+                if alpha > 0 {
+                    positions.push(Position {
+                        value: Self::get_alpha_value(netuid, alpha),
+                        hotkey,
+                        netuid,
+                        alpha,
+                        target_value: 0,
+                    });
+                }
+            }
+        }
+        let total_value: u64 = positions
+            .iter()
+            .fold(0u64, |total, position| total.saturating_add(position.value));
+        ensure!(total_value > 0, Error::<T>::NotEnoughStakeToWithdraw);
+
+        for (hotkey, netuid, fraction) in targets.iter() {
+            let target_value: u64 = U96F32::saturating_from_num(total_value)
+                .saturating_mul(U96F32::saturating_from_num(*fraction))
+                .safe_div(U96F32::saturating_from_num(u16::MAX))
+                .saturating_to_num::<u64>();
+            match positions
+                .iter_mut()
+                .find(|position| position.hotkey == *hotkey && position.netuid == *netuid)
+            {
+                Some(position) => position.target_value = target_value,
+                None => positions.push(Position {
+                    hotkey: hotkey.clone(),
+                    netuid: *netuid,
+                    alpha: 0,
+                    value: 0,
+                    target_value,
+                }),
+            }
+        }
+
+        // --- 3. Match the surplus of each position against the deficits.
+        let mut deficits: Vec<(usize, u64)> = positions
+            .iter()
+            .enumerate()
+            .filter(|(_, position)| position.target_value > position.value)
+            .map(|(index, position)| (index, position.target_value.saturating_sub(position.value)))
+            .collect();
+
+        let mut moves: Vec<PlannedMove<T::AccountId>> = Vec::new();
+        for source in positions.iter() {
+            let surplus: u64 = source.value.saturating_sub(source.target_value);
+            if surplus == 0 {
+                continue;
+            }
+            // Move all of the alpha out of positions that are no longer targeted.
+            let surplus_alpha: u64 = if source.target_value == 0 {
+                source.alpha
+            } else {
+                U96F32::saturating_from_num(source.alpha)
+                    .saturating_mul(U96F32::saturating_from_num(surplus))
+                    .safe_div(U96F32::saturating_from_num(source.value))
+                    .saturating_to_num::<u64>()
+            };
+
+            let mut remaining_value: u64 = surplus;
+            let mut remaining_alpha: u64 = surplus_alpha;
+            for (index, deficit) in deficits.iter_mut() {
+                if remaining_value == 0 {
+                    break;
+                }
+                if *deficit == 0 {
+                    continue;
+                }
+                let value: u64 = remaining_value.min(*deficit);
+                let alpha_amount: u64 = if value == remaining_value {
+                    remaining_alpha
+                } else {
+                    U96F32::saturating_from_num(surplus_alpha)
+                        .saturating_mul(U96F32::saturating_from_num(value))
+                        .safe_div(U96F32::saturating_from_num(surplus))
+                        .saturating_to_num::<u64>()
+                        .min(remaining_alpha)
+                };
+                *deficit = deficit.saturating_sub(value);
+                remaining_value = remaining_value.saturating_sub(value);
+                remaining_alpha = remaining_alpha.saturating_sub(alpha_amount);
+
+                let tao_amount: u64 =
+                    Self::sim_swap_alpha_for_tao(source.netuid, alpha_amount).unwrap_or(0);
+                if tao_amount <= DefaultMinStake::<T>::get() {
+                    continue;
+                }
+
+                let Some(destination) = positions.get(*index) else {
+                    continue;
+                };
+                let fee: u64 = Self::calculate_staking_fee(
+                    Some((&source.hotkey, source.netuid)),
+                    coldkey,
+                    Some((&destination.hotkey, destination.netuid)),
+                    coldkey,
+                    U96F32::saturating_from_num(alpha_amount),
+                );
+
+                moves.push(PlannedMove {
+                    origin_hotkey: source.hotkey.clone(),
+                    origin_netuid: source.netuid,
+                    destination_hotkey: destination.hotkey.clone(),
+                    destination_netuid: destination.netuid,
+                    alpha_amount,
+                    tao_amount,
+                    fee,
+                });
+            }
+        }
+
+        Ok(moves)
+    }
+
+    /// The weight of a rebalance into `targets` targets. Each of the at most
+    /// `MAX_REBALANCE_POSITIONS` held positions can be moved into every target, and the
+    /// positions are read to plan the moves and to value the stake before and after them.
+    pub fn rebalance_stake_weight(targets: u64) -> Weight {
+        let positions: u64 = u64::from(MAX_REBALANCE_POSITIONS);
+        Weight::from_parts(3_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_mul(positions.saturating_mul(targets))
+            .saturating_add(
+                T::DbWeight::get().reads(positions.saturating_add(targets).saturating_mul(3)),
+            )
+    }
+
+    /// Returns the value in TAO of `alpha` on a subnet at the current alpha price.
+    pub fn get_alpha_value(netuid: u16, alpha: u64) -> u64 {
+        U96F32::saturating_from_num(alpha)
+            .saturating_mul(Self::get_alpha_price(netuid))
+            .saturating_to_num::<u64>()
+    }
+
+    /// Returns the value in TAO of all the stake of a coldkey at the given alpha prices.
+    fn get_coldkey_stake_value(coldkey: &T::AccountId, prices: &[(u16, U96F32)]) -> u64 {
+        let hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(coldkey);
+        prices.iter().fold(0u64, |total, (netuid, price)| {
+            hotkeys.iter().fold(total, |total, hotkey| {
+                let alpha: u64 =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, *netuid);
+                total.saturating_add(
+                    U96F32::saturating_from_num(alpha)
+                        .saturating_mul(*price)
+                        .saturating_to_num::<u64>(),
+                )
+            })
+        })
+    }
+}
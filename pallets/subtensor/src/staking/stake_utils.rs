@@ -57,6 +57,12 @@ impl<T: Config> Pallet<T> {
             U96F32::saturating_from_num(SubnetMovingPrice::<T>::get(netuid))
         }
     }
+    /// Returns the TAO value of an alpha amount at the current price of the subnet.
+    pub fn alpha_to_tao_at_price(netuid: u16, alpha: u64) -> u64 {
+        U96F32::saturating_from_num(alpha)
+            .saturating_mul(Self::get_alpha_price(netuid))
+            .saturating_to_num::<u64>()
+    }
     pub fn update_moving_price(netuid: u16) {
         let blocks_since_start_call = U96F32::saturating_from_num({
             // We expect FirstEmissionBlockNumber to be set earlier, and we take the block when
@@ -564,10 +570,16 @@ impl<T: Config> Pallet<T> {
         netuid: u16,
         amount: u64,
     ) -> u64 {
+        // Credit the take rebate accrued on the current shares first.
+        Self::settle_delegate_take_rebate(hotkey, coldkey, netuid);
+
         let mut alpha_share_pool = Self::get_alpha_share_pool(hotkey.clone(), netuid);
         // We expect to add a positive amount here.
         let actual_alpha = alpha_share_pool.update_value_for_one(coldkey, amount as i64);
 
+        // Count the new shares under the take class of the new stake.
+        Self::classify_delegate_take(hotkey, coldkey, netuid);
+
         // We should return a positive amount, or 0 if the operation failed.
         // e.g. the stake was removed due to precision issues.
         actual_alpha.max(0).unsigned_abs()
@@ -598,6 +610,9 @@ impl<T: Config> Pallet<T> {
         netuid: u16,
         amount: u64,
     ) -> u64 {
+        // Credit the take rebate accrued on the current shares first.
+        Self::settle_delegate_take_rebate(hotkey, coldkey, netuid);
+
         let mut alpha_share_pool = Self::get_alpha_share_pool(hotkey.clone(), netuid);
 
         // We expect a negative value here
@@ -609,6 +624,9 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // Count the remaining shares under the take class of the new stake.
+        Self::classify_delegate_take(hotkey, coldkey, netuid);
+
         // Get the negation of the removed alpha, and clamp at 0.
         // This ensures we return a positive value, but only if
         // `actual_alpha` was negative (i.e. a decrease in stake).
@@ -0,0 +1,474 @@
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::{U64F64, U96F32};
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_delegate_take_tiers
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey we are delegating (must be owned by the coldkey.)
+    ///
+    /// * 'tiers' (Vec<(u64, u16)>):
+    ///     - The (stake threshold, take) schedule, with strictly increasing thresholds.
+    ///       An empty schedule removes the tiers. Each nominator moves to the new
+    ///       schedule at their next stake change on the hotkey, or when a dividend
+    ///       distribution to the hotkey reaches them, see `refresh_delegate_take_classes`.
+    ///
+    /// # Event:
+    /// * DelegateTakeTiersSet;
+    ///     - On successfully setting the take tiers for this hotkey.
+    ///
+    /// # Raises:
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey we are delegating is not owned by the calling coldkey.
+    ///
+    /// * 'InvalidDelegateTakeTiers':
+    ///     - There are too many tiers or the thresholds are not strictly increasing.
+    ///
+    /// * 'DelegateTakeTooLow' / 'DelegateTakeTooHigh':
+    ///     - A tier take is outside of the min ..= max delegate take range.
+    ///
+    /// * 'DelegateTxRateLimitExceeded':
+    ///     - Thrown if the change can raise a nominator take and the key has hit the
+    ///       delegate take rate limit.
+    ///
+    pub fn do_set_delegate_take_tiers(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        tiers: Vec<(u64, u16)>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_delegate_take_tiers( origin:{:?} hotkey:{:?}, tiers:{:?} )",
+            coldkey,
+            hotkey,
+            tiers
+        );
+
+        // --- 2. Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        // --- 3. Ensure the schedule is bounded and sorted by threshold.
+        ensure!(
+            tiers.len() <= MAX_DELEGATE_TAKE_TIERS as usize,
+            Error::<T>::InvalidDelegateTakeTiers
+        );
+        ensure!(
+            tiers
+                .windows(2)
+                .all(|pair| matches!(pair, [(lower, _), (upper, _)] if lower < upper)),
+            Error::<T>::InvalidDelegateTakeTiers
+        );
+        for (_, take) in tiers.iter() {
+            Self::ensure_delegate_take_in_range(*take)?;
+        }
+
+        // --- 4. Enforce the delegate take rate limit if any nominator take can increase.
+        let current_tiers: Vec<(u64, u16)> = DelegateTakeTiers::<T>::get(&hotkey);
+        let increases_take: bool = current_tiers
+            .iter()
+            .chain(tiers.iter())
+            .map(|(threshold, _)| *threshold)
+            .chain(core::iter::once(0))
+            .any(|value| {
+                Self::get_tier_take(&tiers, value) > Self::get_tier_take(&current_tiers, value)
+            });
+        if increases_take {
+            Self::ensure_delegate_take_rate_limit(&hotkey)?;
+        }
+
+        // --- 5. Set the new schedule.
+        if tiers.is_empty() {
+            DelegateTakeTiers::<T>::remove(&hotkey);
+        } else {
+            DelegateTakeTiers::<T>::insert(&hotkey, tiers.clone());
+        }
+
+        // --- 6. Emit the new schedule.
+        Self::deposit_event(Event::DelegateTakeTiersSet(coldkey, hotkey, tiers));
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic set_delegate_take_override
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey we are delegating (must be owned by the coldkey.)
+    ///
+    /// * 'nominator' (T::AccountId):
+    ///     - The nominator coldkey the take applies to.
+    ///
+    /// * 'take' (Option<u16>):
+    ///     - The take charged to the nominator, or None to remove the override.
+    ///
+    /// # Event:
+    /// * DelegateTakeOverrideSet;
+    ///     - On successfully setting or removing the override.
+    ///
+    /// # Raises:
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey we are delegating is not owned by the calling coldkey.
+    ///
+    /// * 'TooManyDelegateTakeOverrides':
+    ///     - The delegate already has the maximum number of overrides.
+    ///
+    /// * 'DelegateTakeTooLow' / 'DelegateTakeTooHigh':
+    ///     - The take is outside of the min ..= max delegate take range.
+    ///
+    /// * 'DelegateTxRateLimitExceeded':
+    ///     - Thrown if the change can raise a nominator take and the key has hit the
+    ///       delegate take rate limit.
+    ///
+    pub fn do_set_delegate_take_override(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        nominator: T::AccountId,
+        take: Option<u16>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_delegate_take_override( origin:{:?} hotkey:{:?}, nominator:{:?}, take:{:?} )",
+            coldkey,
+            hotkey,
+            nominator,
+            take
+        );
+
+        // --- 2. Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        // --- 3. Ensure the take is in range and the override list stays bounded.
+        if let Some(take) = take {
+            Self::ensure_delegate_take_in_range(take)?;
+            ensure!(
+                DelegateTakeOverrides::<T>::contains_key(&hotkey, &nominator)
+                    || DelegateTakeOverrides::<T>::iter_prefix(&hotkey).count()
+                        < MAX_DELEGATE_TAKE_OVERRIDES as usize,
+                Error::<T>::TooManyDelegateTakeOverrides
+            );
+        }
+
+        // --- 4. Enforce the delegate take rate limit if the nominator take can increase.
+        if let Some(current_take) = DelegateTakeOverrides::<T>::get(&hotkey, &nominator) {
+            if take.is_none_or(|take| take > current_take) {
+                Self::ensure_delegate_take_rate_limit(&hotkey)?;
+            }
+        }
+
+        // --- 5. Set or remove the override.
+        match take {
+            Some(take) => DelegateTakeOverrides::<T>::insert(&hotkey, &nominator, take),
+            None => DelegateTakeOverrides::<T>::remove(&hotkey, &nominator),
+        }
+
+        // --- 6. Move the stake of the nominator to its new take class. Positions past
+        // MAX_TAKE_CLASS_REFRESHES move on a later dividend distribution to the hotkey.
+        let netuids: Vec<u16> = Alpha::<T>::iter_key_prefix((&hotkey, &nominator))
+            .take(MAX_TAKE_CLASS_REFRESHES as usize)
+            .collect();
+        for netuid in netuids {
+            Self::refresh_delegate_take_class(&hotkey, &nominator, netuid);
+        }
+
+        // --- 7. Emit the override.
+        Self::deposit_event(Event::DelegateTakeOverrideSet(
+            coldkey, hotkey, nominator, take,
+        ));
+
+        Ok(())
+    }
+
+    /// Returns the take a delegate charges a nominator holding `alpha` on a subnet.
+    ///
+    /// The take is the lowest of the delegate take, the take of the highest tier whose
+    /// threshold the TAO value of the stake reaches, and the nominator override.
+    pub fn get_nominator_take(
+        hotkey: &T::AccountId,
+        nominator: &T::AccountId,
+        netuid: u16,
+        alpha: u64,
+    ) -> u16 {
+        Self::get_hotkey_take(hotkey).min(Self::get_nominator_class_take(
+            hotkey, nominator, netuid, alpha,
+        ))
+    }
+
+    /// Returns the lowest of the tier take and the override take of a nominator, or
+    /// u16::MAX when neither applies.
+    fn get_nominator_class_take(
+        hotkey: &T::AccountId,
+        nominator: &T::AccountId,
+        netuid: u16,
+        alpha: u64,
+    ) -> u16 {
+        let stake_value: u64 = Self::get_alpha_value(netuid, alpha);
+        let tier_take: u16 = Self::get_tier_take(&DelegateTakeTiers::<T>::get(hotkey), stake_value);
+        DelegateTakeOverrides::<T>::get(hotkey, nominator)
+            .map_or(tier_take, |override_take| tier_take.min(override_take))
+    }
+
+    /// Accrues the part of the delegate take on `dividends` owed back to the nominators
+    /// charged a lower take through the tiers or an override, and returns it.
+    ///
+    /// Nominator shares are counted per take class, so the work is bounded by
+    /// MAX_DELEGATE_TAKE_CLASSES. The rebates are credited to each nominator at their next
+    /// stake change on the hotkey or by `refresh_delegate_take_classes`.
+    pub fn accrue_delegate_take_rebates(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        dividends: U96F32,
+        take: U96F32,
+    ) -> u64 {
+        let mut classes: Vec<(u16, U64F64, U64F64)> = DelegateTakeClasses::<T>::get(hotkey, netuid);
+        let total_shares: U64F64 = TotalHotkeyShares::<T>::get(hotkey, netuid);
+        if classes.is_empty() || total_shares == 0 {
+            return 0;
+        }
+
+        let hotkey_take: u16 = Self::get_hotkey_take(hotkey);
+        let mut remaining: u64 = take.saturating_to_num::<u64>();
+        let mut accrued: u64 = 0;
+        for (class_take, shares, rebate_per_share) in classes.iter_mut() {
+            let rebate: u64 = dividends
+                .saturating_mul(U96F32::saturating_from_num(shares.safe_div(total_shares)))
+                .saturating_mul(
+                    U96F32::saturating_from_num(hotkey_take.saturating_sub(*class_take))
+                        .safe_div(U96F32::saturating_from_num(u16::MAX)),
+                )
+                .saturating_to_num::<u64>()
+                .min(remaining);
+            if rebate == 0 {
+                continue;
+            }
+            *rebate_per_share = rebate_per_share
+                .saturating_add(U64F64::saturating_from_num(rebate).safe_div(*shares));
+            remaining = remaining.saturating_sub(rebate);
+            accrued = accrued.saturating_add(rebate);
+        }
+
+        if accrued > 0 {
+            DelegateTakeClasses::<T>::insert(hotkey, netuid, classes);
+            PendingTakeRebates::<T>::mutate(hotkey, netuid, |pending| {
+                *pending = pending.saturating_add(accrued);
+            });
+        }
+        accrued
+    }
+
+    /// Credits a nominator the take rebate accrued on their shares since their last stake
+    /// change, and moves their shares to the take class matching their current stake.
+    ///
+    /// A new tier schedule, or a change in the value of the stake, applies to a nominator
+    /// from their next stake change on the hotkey or their next refresh on distribution.
+    pub fn refresh_delegate_take_class(
+        hotkey: &T::AccountId,
+        nominator: &T::AccountId,
+        netuid: u16,
+    ) {
+        Self::settle_delegate_take_rebate(hotkey, nominator, netuid);
+        Self::classify_delegate_take(hotkey, nominator, netuid);
+    }
+
+    /// Credits a nominator the take rebate accrued on their shares and removes the shares
+    /// from their take class. Must run before the shares of the nominator change.
+    pub(crate) fn settle_delegate_take_rebate(
+        hotkey: &T::AccountId,
+        nominator: &T::AccountId,
+        netuid: u16,
+    ) {
+        let Some((take, checkpoint)) = NominatorTakeClass::<T>::take((hotkey, nominator, netuid))
+        else {
+            return;
+        };
+
+        let shares: U64F64 = Alpha::<T>::get((hotkey, nominator, netuid));
+        let mut classes: Vec<(u16, U64F64, U64F64)> = DelegateTakeClasses::<T>::get(hotkey, netuid);
+        let mut owed: u64 = 0;
+        if let Some((_, class_shares, rebate_per_share)) = classes
+            .iter_mut()
+            .find(|(class_take, _, _)| *class_take == take)
+        {
+            owed = shares
+                .saturating_mul(rebate_per_share.saturating_sub(checkpoint))
+                .saturating_to_num::<u64>();
+            *class_shares = class_shares.saturating_sub(shares);
+        }
+        classes.retain(|(_, class_shares, _)| *class_shares > 0);
+        if classes.is_empty() {
+            DelegateTakeClasses::<T>::remove(hotkey, netuid);
+        } else {
+            DelegateTakeClasses::<T>::insert(hotkey, netuid, classes);
+        }
+
+        let pending: u64 = PendingTakeRebates::<T>::get(hotkey, netuid);
+        let owed: u64 = owed.min(pending);
+        if owed > 0 {
+            PendingTakeRebates::<T>::insert(hotkey, netuid, pending.saturating_sub(owed));
+            let mut alpha_share_pool = Self::get_alpha_share_pool(hotkey.clone(), netuid);
+            alpha_share_pool.update_value_for_one(nominator, owed as i64);
+        }
+    }
+
+    /// Counts the shares of a nominator under the take class matching their stake. Must run
+    /// after the shares of the nominator change.
+    ///
+    /// The owner of the hotkey keeps the take and is never rebated. Once a delegate has
+    /// MAX_DELEGATE_TAKE_CLASSES classes on a subnet, nominators of a new take are charged
+    /// the delegate take until a class empties.
+    pub(crate) fn classify_delegate_take(
+        hotkey: &T::AccountId,
+        nominator: &T::AccountId,
+        netuid: u16,
+    ) {
+        if !DelegateTakeTiers::<T>::contains_key(hotkey)
+            && !DelegateTakeOverrides::<T>::contains_key(hotkey, nominator)
+        {
+            return;
+        }
+        if *nominator == Owner::<T>::get(hotkey) {
+            return;
+        }
+        let shares: U64F64 = Alpha::<T>::get((hotkey, nominator, netuid));
+        if shares == 0 {
+            return;
+        }
+
+        let alpha: u64 =
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, nominator, netuid);
+        let take: u16 = Self::get_nominator_class_take(hotkey, nominator, netuid, alpha);
+        if take == u16::MAX {
+            return;
+        }
+
+        let mut classes: Vec<(u16, U64F64, U64F64)> = DelegateTakeClasses::<T>::get(hotkey, netuid);
+        let checkpoint: U64F64 = match classes
+            .iter_mut()
+            .find(|(class_take, _, _)| *class_take == take)
+        {
+            Some((_, class_shares, rebate_per_share)) => {
+                *class_shares = class_shares.saturating_add(shares);
+                *rebate_per_share
+            }
+            None => {
+                if classes.len() >= MAX_DELEGATE_TAKE_CLASSES as usize {
+                    return;
+                }
+                classes.push((take, shares, U64F64::saturating_from_num(0)));
+                U64F64::saturating_from_num(0)
+            }
+        };
+        DelegateTakeClasses::<T>::insert(hotkey, netuid, classes);
+        NominatorTakeClass::<T>::insert((hotkey, nominator, netuid), (take, checkpoint));
+    }
+
+    /// Refreshes the take class of the next MAX_TAKE_CLASS_REFRESHES stake positions on a
+    /// delegate, continuing from where the previous call stopped.
+    ///
+    /// Runs on every dividend distribution to the delegate, so nominators who never change
+    /// their stake are still credited their take rebates and move to a new tier schedule.
+    pub fn refresh_delegate_take_classes(hotkey: &T::AccountId) {
+        if !DelegateTakeTiers::<T>::contains_key(hotkey)
+            && DelegateTakeOverrides::<T>::iter_key_prefix(hotkey)
+                .next()
+                .is_none()
+            && DelegateTakeClasses::<T>::iter_key_prefix(hotkey)
+                .next()
+                .is_none()
+        {
+            return;
+        }
+
+        let positions: Vec<(T::AccountId, u16)> = match TakeRebateCursor::<T>::take(hotkey) {
+            Some(cursor) => Alpha::<T>::iter_key_prefix_from((hotkey,), cursor)
+                .take(MAX_TAKE_CLASS_REFRESHES as usize)
+                .collect(),
+            None => Alpha::<T>::iter_key_prefix((hotkey,))
+                .take(MAX_TAKE_CLASS_REFRESHES as usize)
+                .collect(),
+        };
+        for (nominator, netuid) in positions.iter() {
+            Self::refresh_delegate_take_class(hotkey, nominator, *netuid);
+        }
+
+        // Resume after the last position, or start over once every position was refreshed.
+        if positions.len() >= MAX_TAKE_CLASS_REFRESHES as usize {
+            if let Some((nominator, netuid)) = positions.last() {
+                TakeRebateCursor::<T>::insert(
+                    hotkey,
+                    Alpha::<T>::hashed_key_for((hotkey, nominator, *netuid)),
+                );
+            }
+        }
+    }
+
+    /// Removes the take classes, nominator classes and pending take rebates on a subnet.
+    pub(crate) fn clear_delegate_take_classes(netuid: u16) {
+        let hotkeys: Vec<T::AccountId> = DelegateTakeClasses::<T>::iter_keys()
+            .filter(|(_, class_netuid)| *class_netuid == netuid)
+            .map(|(hotkey, _)| hotkey)
+            .collect();
+        for hotkey in hotkeys {
+            DelegateTakeClasses::<T>::remove(&hotkey, netuid);
+        }
+        let hotkeys: Vec<T::AccountId> = PendingTakeRebates::<T>::iter_keys()
+            .filter(|(_, rebate_netuid)| *rebate_netuid == netuid)
+            .map(|(hotkey, _)| hotkey)
+            .collect();
+        for hotkey in hotkeys {
+            PendingTakeRebates::<T>::remove(&hotkey, netuid);
+        }
+        let positions: Vec<(T::AccountId, T::AccountId, u16)> =
+            NominatorTakeClass::<T>::iter_keys()
+                .filter(|(_, _, class_netuid)| *class_netuid == netuid)
+                .collect();
+        for position in positions {
+            NominatorTakeClass::<T>::remove(position);
+        }
+    }
+
+    /// Returns the take of the highest tier whose threshold `value` reaches, or u16::MAX
+    /// when no tier applies.
+    fn get_tier_take(tiers: &[(u64, u16)], value: u64) -> u16 {
+        tiers
+            .iter()
+            .rev()
+            .find(|(threshold, _)| value >= *threshold)
+            .map(|(_, take)| *take)
+            .unwrap_or(u16::MAX)
+    }
+
+    fn ensure_delegate_take_in_range(take: u16) -> Result<(), Error<T>> {
+        ensure!(
+            take >= MinDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooLow
+        );
+        ensure!(
+            take <= MaxDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooHigh
+        );
+        Ok(())
+    }
+
+    fn ensure_delegate_take_rate_limit(hotkey: &T::AccountId) -> Result<(), Error<T>> {
+        let block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::exceeds_tx_delegate_take_rate_limit(
+                Self::get_last_tx_block_delegate_take(hotkey),
+                block
+            ),
+            Error::<T>::DelegateTxRateLimitExceeded
+        );
+        Self::set_last_tx_block_delegate_take(hotkey, block);
+        Ok(())
+    }
+}
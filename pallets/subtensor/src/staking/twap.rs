@@ -0,0 +1,79 @@
+use super::*;
+use frame_support::weights::Weight;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+
+impl<T: Config> Pallet<T> {
+    /// Number of observations kept per subnet, enough to cover the longest TWAP window.
+    fn twap_observation_slots() -> u64 {
+        T::MaxTwapWindowBlocks::get().saturating_add(1)
+    }
+
+    /// Adds the current alpha price of every subnet to its cumulative price and records
+    /// the result in the observation ring buffer.
+    ///
+    /// This runs in `block_step` after the coinbase, so the recorded price is the one
+    /// left by the previous block and cannot be moved by transactions of this block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The current block number
+    pub fn update_price_accumulators(block_number: u64) {
+        let slot: u64 = block_number
+            .checked_rem(Self::twap_observation_slots())
+            .unwrap_or(0);
+        for netuid in Self::get_all_subnet_netuids() {
+            let price: u128 = Self::get_alpha_price(netuid)
+                .saturating_mul(U96F32::saturating_from_num(1_000_000_000))
+                .saturating_to_num::<u128>();
+            let cumulative: u128 = SubnetPriceCumulative::<T>::get(netuid).saturating_add(price);
+            SubnetPriceCumulative::<T>::insert(netuid, cumulative);
+            SubnetPriceObservations::<T>::insert(netuid, slot, (block_number, cumulative));
+        }
+    }
+
+    /// The weight of `update_price_accumulators`: for every subnet, the reads of its price and
+    /// cumulative price and the writes of the cumulative price and of an observation.
+    pub fn update_price_accumulators_weight() -> Weight {
+        let subnets: u64 = u64::from(TotalNetworks::<T>::get());
+        T::DbWeight::get().reads_writes(
+            subnets.saturating_mul(6).saturating_add(1),
+            subnets.saturating_mul(2),
+        )
+    }
+
+    /// Returns the time-weighted average alpha price of a subnet over the last `window` blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `netuid` - The subnet ID
+    /// * `window` - The number of blocks to average over, at most `MaxTwapWindowBlocks`
+    ///
+    /// # Returns
+    ///
+    /// * `Option<u64>` - The average price in rao per alpha, or `None` if the window is
+    ///   empty, too long, or not fully covered by observations
+    pub fn get_alpha_twap(netuid: u16, window: u64) -> Option<u64> {
+        if window == 0 || window > T::MaxTwapWindowBlocks::get() {
+            return None;
+        }
+
+        let slots: u64 = Self::twap_observation_slots();
+        let end_block: u64 = Self::get_current_block_as_u64();
+        let start_block: u64 = end_block.checked_sub(window)?;
+
+        let (observed_end, end_cumulative) =
+            SubnetPriceObservations::<T>::get(netuid, end_block.checked_rem(slots).unwrap_or(0))?;
+        let (observed_start, start_cumulative) =
+            SubnetPriceObservations::<T>::get(netuid, start_block.checked_rem(slots).unwrap_or(0))?;
+        // Slots are reused, make sure both observations belong to the requested window.
+        if observed_end != end_block || observed_start != start_block {
+            return None;
+        }
+
+        let average: u128 = end_cumulative
+            .saturating_sub(start_cumulative)
+            .safe_div(u128::from(window));
+        Some(u64::try_from(average).unwrap_or(u64::MAX))
+    }
+}
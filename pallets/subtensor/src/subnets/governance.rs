@@ -0,0 +1,385 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Enables or disables alpha holder governance of a subnet.
+    ///
+    /// The subnet owner may only enable governance; disabling it again requires root, so an
+    /// owner cannot step around a pending veto.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call (the subnet owner or root)
+    /// * `netuid` - The subnet ID
+    /// * `enabled` - Whether owner hyperparameter changes need alpha holder ratification
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_set_subnet_governance(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        enabled: bool,
+    ) -> DispatchResult {
+        let is_root = ensure_root(origin.clone()).is_ok();
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(is_root || enabled, DispatchError::BadOrigin);
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        SubnetGovernanceEnabled::<T>::insert(netuid, enabled);
+        if !enabled {
+            Self::clear_subnet_proposals(netuid);
+        }
+
+        Self::deposit_event(Event::SubnetGovernanceSet(netuid, enabled));
+        Ok(())
+    }
+
+    /// Proposes a hyperparameter change on a governed subnet.
+    ///
+    /// The change applies once the voting window has ended unless the alpha stake voting to
+    /// veto it exceeds the alpha stake voting to ratify it.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call (must be signed by the subnet owner)
+    /// * `netuid` - The subnet ID
+    /// * `change` - The proposed hyperparameter change
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_propose_subnet_hyperparameter(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        change: SubnetHyperparameter,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin.clone())?;
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            SubnetGovernanceEnabled::<T>::get(netuid),
+            Error::<T>::SubnetGovernanceNotEnabled
+        );
+        ensure!(
+            (SubnetProposals::<T>::iter_prefix(netuid).count() as u32) < MAX_OPEN_SUBNET_PROPOSALS,
+            Error::<T>::TooManySubnetProposals
+        );
+        match change {
+            SubnetHyperparameter::ActivityCutoff(activity_cutoff) => ensure!(
+                activity_cutoff >= MinActivityCutoff::<T>::get(),
+                Error::<T>::ActivityCutoffTooLow
+            ),
+            SubnetHyperparameter::WeightsVersionKey(_) => ensure!(
+                Self::passes_rate_limit_on_subnet(
+                    &TransactionType::SetWeightsVersionKey,
+                    &coldkey,
+                    netuid
+                ),
+                Error::<T>::TxRateLimitExceeded
+            ),
+            _ => {}
+        }
+
+        let proposal_id = NextSubnetProposalId::<T>::get(netuid);
+        NextSubnetProposalId::<T>::insert(netuid, proposal_id.wrapping_add(1));
+
+        let end =
+            Self::get_current_block_as_u64().saturating_add(SubnetProposalVotingPeriod::<T>::get());
+        SubnetProposals::<T>::insert(
+            netuid,
+            proposal_id,
+            SubnetProposal {
+                proposer: coldkey,
+                change: change.clone(),
+                end,
+                ayes: 0,
+                nays: 0,
+                voters: 0,
+            },
+        );
+        SubnetProposalQueue::<T>::append(end, (netuid, proposal_id));
+
+        Self::deposit_event(Event::SubnetProposalCreated {
+            netuid,
+            proposal_id,
+            change,
+            end,
+        });
+        Ok(())
+    }
+
+    /// Votes on an open subnet proposal with the alpha stake of the calling coldkey.
+    ///
+    /// The vote is weighted by the coldkey's alpha on the subnet at vote time, and counted with
+    /// no more than the alpha the coldkey still holds when the proposal closes. Voting again
+    /// replaces the previous vote. Once the proposal has MAX_SUBNET_PROPOSAL_VOTERS voters, the
+    /// vote replaces the vote of the voter with the least alpha, if it carries more.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the call (the voting coldkey)
+    /// * `netuid` - The subnet ID
+    /// * `proposal_id` - The id of the proposal on the subnet
+    /// * `approve` - Whether to ratify or veto the change
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResult` - Success or error
+    pub(crate) fn do_vote_subnet_proposal(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        proposal_id: u32,
+        approve: bool,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        let mut proposal = SubnetProposals::<T>::get(netuid, proposal_id)
+            .ok_or(Error::<T>::SubnetProposalNotFound)?;
+        ensure!(
+            Self::get_current_block_as_u64() < proposal.end,
+            Error::<T>::SubnetProposalVotingClosed
+        );
+
+        let weight = Self::get_coldkey_alpha_on_subnet(&coldkey, netuid);
+        ensure!(weight > 0, Error::<T>::NoAlphaToVote);
+
+        let replaced: Option<(bool, u64)> =
+            match SubnetProposalVotes::<T>::get((netuid, proposal_id), &coldkey) {
+                Some(previous) => Some(previous),
+                None if proposal.voters >= MAX_SUBNET_PROPOSAL_VOTERS => {
+                    let (lightest, lightest_vote) =
+                        SubnetProposalVotes::<T>::iter_prefix((netuid, proposal_id))
+                            .min_by_key(|(_, (_, voter_weight))| *voter_weight)
+                            .ok_or(Error::<T>::TooManySubnetProposalVoters)?;
+                    ensure!(
+                        weight > lightest_vote.1,
+                        Error::<T>::TooManySubnetProposalVoters
+                    );
+                    SubnetProposalVotes::<T>::remove((netuid, proposal_id), &lightest);
+                    Some(lightest_vote)
+                }
+                None => {
+                    proposal.voters = proposal.voters.saturating_add(1);
+                    None
+                }
+            };
+        if let Some((previous, previous_weight)) = replaced {
+            if previous {
+                proposal.ayes = proposal.ayes.saturating_sub(previous_weight);
+            } else {
+                proposal.nays = proposal.nays.saturating_sub(previous_weight);
+            }
+        }
+        if approve {
+            proposal.ayes = proposal.ayes.saturating_add(weight);
+        } else {
+            proposal.nays = proposal.nays.saturating_add(weight);
+        }
+
+        SubnetProposalVotes::<T>::insert((netuid, proposal_id), &coldkey, (approve, weight));
+        SubnetProposals::<T>::insert(netuid, proposal_id, proposal);
+
+        Self::deposit_event(Event::SubnetProposalVoted {
+            netuid,
+            proposal_id,
+            coldkey,
+            approve,
+            weight,
+        });
+        Ok(())
+    }
+
+    /// Applies or vetoes the subnet proposals whose voting window ends at `block_number`.
+    ///
+    /// At most MAX_SUBNET_PROPOSALS_CLOSED_PER_BLOCK proposals are closed, the rest move to
+    /// the next block. Returns the weight consumed.
+    pub fn process_subnet_proposals(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        let mut closing: Vec<(u16, u32)> = SubnetProposalQueue::<T>::take(block_number);
+        if closing.is_empty() {
+            return weight;
+        }
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        let deferred: Vec<(u16, u32)> = closing.split_off(
+            closing
+                .len()
+                .min(MAX_SUBNET_PROPOSALS_CLOSED_PER_BLOCK as usize),
+        );
+        if !deferred.is_empty() {
+            SubnetProposalQueue::<T>::mutate(block_number.saturating_add(1), |next| {
+                let mut queue = deferred;
+                queue.append(next);
+                *next = queue;
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        for (netuid, proposal_id) in closing {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let Some(proposal) = SubnetProposals::<T>::take(netuid, proposal_id) else {
+                continue;
+            };
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+            let (ayes, nays) =
+                Self::recount_subnet_proposal_votes(netuid, proposal_id, &mut weight);
+            if nays > ayes {
+                Self::deposit_event(Event::SubnetProposalVetoed {
+                    netuid,
+                    proposal_id,
+                });
+                continue;
+            }
+
+            let result =
+                Self::apply_subnet_hyperparameter(netuid, &proposal.proposer, &proposal.change);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 2));
+            Self::deposit_event(Event::SubnetProposalApplied {
+                netuid,
+                proposal_id,
+                result,
+            });
+        }
+        weight
+    }
+
+    /// Removes the votes of a closing subnet proposal and returns the (ayes, nays) alpha.
+    ///
+    /// Each coldkey counts with the lower of the alpha it voted with and the alpha it holds on
+    /// the subnet now, so alpha moved to another coldkey after voting is not counted twice.
+    fn recount_subnet_proposal_votes(
+        netuid: u16,
+        proposal_id: u32,
+        weight: &mut Weight,
+    ) -> (u64, u64) {
+        let mut ayes: u64 = 0;
+        let mut nays: u64 = 0;
+        for (coldkey, (approve, voted)) in
+            SubnetProposalVotes::<T>::drain_prefix((netuid, proposal_id))
+        {
+            let hotkeys: u64 = StakingHotkeys::<T>::decode_len(&coldkey).unwrap_or(0) as u64;
+            weight.saturating_accrue(
+                T::DbWeight::get().reads_writes(2_u64.saturating_add(hotkeys.saturating_mul(3)), 1),
+            );
+
+            let counted: u64 = voted.min(Self::get_coldkey_alpha_on_subnet(&coldkey, netuid));
+            if approve {
+                ayes = ayes.saturating_add(counted);
+            } else {
+                nays = nays.saturating_add(counted);
+            }
+        }
+        (ayes, nays)
+    }
+
+    /// Applies a subnet hyperparameter change proposed by the subnet owner `proposer`.
+    ///
+    /// The owner rate limits of the change apply as for a direct owner call.
+    pub fn apply_subnet_hyperparameter(
+        netuid: u16,
+        proposer: &T::AccountId,
+        change: &SubnetHyperparameter,
+    ) -> DispatchResult {
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        match *change {
+            SubnetHyperparameter::ServingRateLimit(value) => {
+                Self::set_serving_rate_limit(netuid, value)
+            }
+            SubnetHyperparameter::MaxDifficulty(value) => Self::set_max_difficulty(netuid, value),
+            SubnetHyperparameter::WeightsVersionKey(value) => {
+                ensure!(
+                    Self::passes_rate_limit_on_subnet(
+                        &TransactionType::SetWeightsVersionKey,
+                        proposer,
+                        netuid
+                    ),
+                    Error::<T>::TxRateLimitExceeded
+                );
+                Self::set_last_transaction_block_on_subnet(
+                    proposer,
+                    netuid,
+                    &TransactionType::SetWeightsVersionKey,
+                    Self::get_current_block_as_u64(),
+                );
+                Self::set_weights_version_key(netuid, value)
+            }
+            SubnetHyperparameter::AdjustmentAlpha(value) => {
+                Self::set_adjustment_alpha(netuid, value)
+            }
+            SubnetHyperparameter::MaxWeightLimit(value) => {
+                Self::set_max_weight_limit(netuid, value)
+            }
+            SubnetHyperparameter::ImmunityPeriod(value) => Self::set_immunity_period(netuid, value),
+            SubnetHyperparameter::MinAllowedWeights(value) => {
+                Self::set_min_allowed_weights(netuid, value)
+            }
+            SubnetHyperparameter::Kappa(value) => Self::set_kappa(netuid, value),
+            SubnetHyperparameter::Rho(value) => Self::set_rho(netuid, value),
+            SubnetHyperparameter::ActivityCutoff(value) => {
+                ensure!(
+                    value >= MinActivityCutoff::<T>::get(),
+                    Error::<T>::ActivityCutoffTooLow
+                );
+                Self::set_activity_cutoff(netuid, value)
+            }
+            SubnetHyperparameter::RegistrationAllowed(value) => {
+                Self::set_network_registration_allowed(netuid, value)
+            }
+            SubnetHyperparameter::PowRegistrationAllowed(value) => {
+                Self::set_network_pow_registration_allowed(netuid, value)
+            }
+            SubnetHyperparameter::MaxBurn(value) => Self::set_max_burn(netuid, value),
+            SubnetHyperparameter::BondsMovingAverage(value) => {
+                Self::set_bonds_moving_average(netuid, value)
+            }
+            SubnetHyperparameter::BondsPenalty(value) => Self::set_bonds_penalty(netuid, value),
+            SubnetHyperparameter::CommitRevealWeightsEnabled(value) => {
+                Self::set_commit_reveal_weights_enabled(netuid, value)
+            }
+            SubnetHyperparameter::CommitRevealWeightsInterval(value) => {
+                Self::set_reveal_period(netuid, value)
+            }
+            SubnetHyperparameter::LiquidAlphaEnabled(value) => {
+                Self::set_liquid_alpha_enabled(netuid, value)
+            }
+            SubnetHyperparameter::TransferEnabled(value) => {
+                return Self::toggle_transfer(netuid, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the alpha a coldkey holds on a subnet across all the hotkeys it stakes to.
+    pub fn get_coldkey_alpha_on_subnet(coldkey: &T::AccountId, netuid: u16) -> u64 {
+        StakingHotkeys::<T>::get(coldkey)
+            .iter()
+            .fold(0u64, |total, hotkey| {
+                total.saturating_add(Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                    hotkey, coldkey, netuid,
+                ))
+            })
+    }
+
+    /// Removes all open proposals of a subnet and their votes.
+    pub fn clear_subnet_proposals(netuid: u16) {
+        let proposal_ids: Vec<u32> = SubnetProposals::<T>::iter_key_prefix(netuid).collect();
+        for proposal_id in proposal_ids {
+            let _ = SubnetProposalVotes::<T>::clear_prefix(
+                (netuid, proposal_id),
+                MAX_SUBNET_PROPOSAL_VOTERS,
+                None,
+            );
+        }
+        let _ = SubnetProposals::<T>::clear_prefix(netuid, MAX_OPEN_SUBNET_PROPOSALS, None);
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+pub mod governance;
 pub mod registration;
 pub mod serving;
 pub mod subnet;
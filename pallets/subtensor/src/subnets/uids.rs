@@ -78,7 +78,10 @@ impl<T: Config> Pallet<T> {
         Self::clear_neuron(netuid, uid_to_replace);
 
         // 5a. reset axon info for the new uid.
-        Axons::<T>::remove(netuid, old_hotkey);
+        Axons::<T>::remove(netuid, old_hotkey.clone());
+
+        // 6. Let other pallets clean up the data of the old hotkey.
+        T::OnNeuronRemoved::on_neuron_removed(netuid, &old_hotkey);
     }
 
     /// Appends the uid to the network.
@@ -284,6 +284,7 @@ impl<T: Config> Pallet<T> {
             // Hash the commit before it is moved, for the event
             let commit_hash = BlakeTwo256::hash(&commit);
             commits.push_back((who.clone(), commit, reveal_round));
+            pallet_drand::Pallet::<T>::reference_round(reveal_round);
 
             // 8. Emit the WeightsCommitted event
             Self::deposit_event(Event::CRV3WeightsCommitted(
@@ -159,8 +159,19 @@ impl<T: Config> Pallet<T> {
         // 3. Swap Stake.
         // StakingHotkeys: MAP ( coldkey ) --> Vec( hotkey )
         for hotkey in StakingHotkeys::<T>::get(old_coldkey) {
-            // 3.1 Swap Alpha
+            // 3.1 Swap the nominator take override.
+            // DelegateTakeOverrides( hotkey, coldkey ) -> take
+            if let Some(take) = DelegateTakeOverrides::<T>::take(&hotkey, old_coldkey) {
+                DelegateTakeOverrides::<T>::insert(&hotkey, new_coldkey, take);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+            // 3.2 Swap Alpha
             for netuid in Self::get_all_subnet_netuids() {
+                // Credit the take rebates accrued by both accounts.
+                Self::settle_delegate_take_rebate(&hotkey, old_coldkey, netuid);
+                Self::settle_delegate_take_rebate(&hotkey, new_coldkey, netuid);
                 // Get the stake on the old (hot,coldkey) account.
                 let old_alpha: U64F64 = Alpha::<T>::get((&hotkey, old_coldkey, netuid));
                 // Get the stake on the new (hot,coldkey) account.
@@ -172,6 +183,8 @@ impl<T: Config> Pallet<T> {
                 );
                 // Remove the value from the old account.
                 Alpha::<T>::remove((&hotkey, old_coldkey, netuid));
+                // Count the merged stake under its take class.
+                Self::classify_delegate_take(&hotkey, new_coldkey, netuid);
             }
             // Add the weight for the read and write.
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
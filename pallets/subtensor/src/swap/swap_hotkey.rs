@@ -140,6 +140,19 @@ impl<T: Config> Pallet<T> {
         coldkey: &T::AccountId,
         weight: &mut Weight,
     ) -> DispatchResult {
+        // 0. Credit the take rebates accrued on the old hotkey before its stake moves.
+        // NominatorTakeClass( hotkey, coldkey, netuid ) -> (take, rebate_per_share)
+        let take_classes: Vec<(T::AccountId, u16)> =
+            NominatorTakeClass::<T>::iter_key_prefix((old_hotkey,)).collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(take_classes.len() as u64));
+        for (nominator, netuid) in take_classes {
+            Self::settle_delegate_take_rebate(old_hotkey, &nominator, netuid);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(5, 4));
+        }
+        let _ = PendingTakeRebates::<T>::clear_prefix(old_hotkey, u32::MAX, None);
+        TakeRebateCursor::<T>::remove(old_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().writes(2));
+
         // 1. Swap owner.
         // Owner( hotkey ) -> coldkey -- the coldkey that owns the hotkey.
         Owner::<T>::remove(old_hotkey);
@@ -223,6 +236,22 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
 
+        // 9.1 Swap the delegate take tiers and nominator overrides.
+        // DelegateTakeTiers( hotkey ) -> Vec<(stake_threshold, take)>
+        if DelegateTakeTiers::<T>::contains_key(old_hotkey) {
+            let old_take_tiers = DelegateTakeTiers::<T>::take(old_hotkey);
+            DelegateTakeTiers::<T>::insert(new_hotkey, old_take_tiers);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+        // DelegateTakeOverrides( hotkey, coldkey ) -> take
+        let old_take_overrides: Vec<(T::AccountId, u16)> =
+            DelegateTakeOverrides::<T>::drain_prefix(old_hotkey).collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        for (nominator, take) in old_take_overrides {
+            DelegateTakeOverrides::<T>::insert(new_hotkey, nominator, take);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
         // 9. swap PendingHotkeyEmissionOnNetuid
         // (DEPRECATED.)
 
@@ -321,6 +350,8 @@ impl<T: Config> Pallet<T> {
 
         // Insert the new alpha values.
         for ((coldkey, netuid), alpha) in old_alpha_values {
+            // Credit the take rebate accrued on the stake already on the new hotkey.
+            Self::settle_delegate_take_rebate(new_hotkey, &coldkey, netuid);
             let new_alpha = Alpha::<T>::get((new_hotkey, &coldkey, netuid));
             Alpha::<T>::insert(
                 (new_hotkey, &coldkey, netuid),
@@ -328,6 +359,10 @@ impl<T: Config> Pallet<T> {
             );
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
+            // Count the stake under its take class on the new hotkey.
+            Self::classify_delegate_take(new_hotkey, &coldkey, netuid);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(6, 3));
+
             // Swap StakingHotkeys.
             // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
             let mut staking_hotkeys = StakingHotkeys::<T>::get(&coldkey);
@@ -1,9 +1,12 @@
 use super::mock::*;
+use crate::utils::batch_predicate::{BatchPredicate, PredicateComparison, PredicateOperand};
+use crate::*;
 use frame_support::{
-    assert_ok,
+    BoundedVec, assert_err_ignore_postinfo, assert_ok,
     traits::{Contains, Currency},
 };
 use frame_system::Config;
+use pallet_utility::EvaluatePredicate;
 use sp_core::U256;
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::batch_tx::test_batch_txs --exact --show-output --nocapture
@@ -117,3 +120,101 @@ fn test_cant_nest_batch_diff_batch_txs() {
         assert!(!<Test as Config>::BaseCallFilter::contains(&call3));
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::batch_tx::test_batch_predicates_evaluate_subtensor_state --exact --show-output --nocapture
+#[test]
+fn test_batch_predicates_evaluate_subtensor_state() {
+    let alice = U256::from(0);
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        run_to_block(10);
+        SubnetMechanism::<Test>::insert(netuid, 1);
+        SubnetTAO::<Test>::insert(netuid, 2_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 10_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&alice, 5_000_000_000);
+
+        let predicate = |operand, comparison, value| BatchPredicate::<U256> {
+            operand,
+            comparison,
+            value,
+        };
+
+        // The alpha price is 0.2 TAO, i.e. 200_000_000 rao per alpha.
+        assert_eq!(
+            SubtensorModule::get_predicate_operand(&PredicateOperand::AlphaPrice(netuid)),
+            200_000_000
+        );
+        assert!(SubtensorModule::holds(&predicate(
+            PredicateOperand::AlphaPrice(netuid),
+            PredicateComparison::LessThan,
+            250_000_000
+        )));
+        assert!(!SubtensorModule::holds(&predicate(
+            PredicateOperand::AlphaPrice(netuid),
+            PredicateComparison::GreaterThan,
+            200_000_000
+        )));
+        assert!(SubtensorModule::holds(&predicate(
+            PredicateOperand::BlockNumber,
+            PredicateComparison::GreaterOrEqual,
+            10
+        )));
+        assert!(!SubtensorModule::holds(&predicate(
+            PredicateOperand::BlockNumber,
+            PredicateComparison::GreaterOrEqual,
+            11
+        )));
+        assert!(SubtensorModule::holds(&predicate(
+            PredicateOperand::FreeBalance(alice),
+            PredicateComparison::GreaterOrEqual,
+            5_000_000_000
+        )));
+        assert!(SubtensorModule::holds(&predicate(
+            PredicateOperand::Stake {
+                hotkey: U256::from(1),
+                coldkey: alice,
+                netuid
+            },
+            PredicateComparison::Equal,
+            0
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::batch_tx::test_batch_if_gated_on_block_number --exact --show-output --nocapture
+#[test]
+fn test_batch_if_gated_on_block_number() {
+    let alice = U256::from(0);
+    let bob = U256::from(1);
+    let initial_balances = vec![(alice, 8_000_000_000), (bob, 1_000_000_000)];
+    test_ext_with_balances(initial_balances).execute_with(|| {
+        let predicates = BoundedVec::truncate_from(vec![BatchPredicate {
+            operand: PredicateOperand::BlockNumber,
+            comparison: PredicateComparison::GreaterOrEqual,
+            value: 5,
+        }]);
+        let calls = vec![RuntimeCall::Balances(BalanceCall::transfer_allow_death {
+            dest: bob,
+            value: 1_000_000_000,
+        })];
+
+        assert_err_ignore_postinfo!(
+            Utility::batch_if(
+                <<Test as Config>::RuntimeOrigin>::signed(alice),
+                predicates.clone(),
+                calls.clone()
+            ),
+            pallet_utility::Error::<Test>::PredicateNotMet
+        );
+        assert_eq!(Balances::total_balance(&bob), 1_000_000_000);
+
+        run_to_block(5);
+        assert_ok!(Utility::batch_if(
+            <<Test as Config>::RuntimeOrigin>::signed(alice),
+            predicates,
+            calls
+        ));
+        assert_eq!(Balances::total_balance(&bob), 2_000_000_000);
+    });
+}
@@ -0,0 +1,208 @@
+#![allow(clippy::arithmetic_side_effects)]
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+
+use super::mock::*;
+use crate::*;
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::buyback::test_buyback_program_set_fund_clear --exact --show-output
+#[test]
+fn test_buyback_program_set_fund_clear() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        let initial_balance = 1_000_000_000;
+        Balances::make_free_balance_be(&owner_coldkey, initial_balance);
+
+        // Funding requires a program.
+        assert_noop!(
+            SubtensorModule::fund_buyback_program(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                1_000
+            ),
+            Error::<Test>::BuybackProgramNotSet
+        );
+
+        assert_ok!(SubtensorModule::set_buyback_program(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            u16::MAX / 2,
+            100_000,
+            2_000_000_000
+        ));
+        assert_eq!(
+            SubnetBuybackProgram::<Test>::get(netuid),
+            Some(BuybackProgram {
+                owner_cut_burn: u16::MAX / 2,
+                tao_per_epoch: 100_000,
+                max_price: 2_000_000_000,
+            })
+        );
+
+        let funding = 300_000_000;
+        assert_ok!(SubtensorModule::fund_buyback_program(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            funding
+        ));
+        assert_eq!(SubnetBuybackBudget::<Test>::get(netuid), funding);
+        assert_eq!(
+            Balances::free_balance(owner_coldkey),
+            initial_balance - funding
+        );
+
+        // Clearing refunds the unspent budget to the owner.
+        assert_ok!(SubtensorModule::clear_buyback_program(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid
+        ));
+        assert!(SubnetBuybackProgram::<Test>::get(netuid).is_none());
+        assert_eq!(SubnetBuybackBudget::<Test>::get(netuid), 0);
+        assert_eq!(Balances::free_balance(owner_coldkey), initial_balance);
+
+        assert!(System::events().iter().any(|e| {
+            matches!(
+                &e.event,
+                RuntimeEvent::SubtensorModule(Event::BuybackProgramCleared(..))
+            )
+        }));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::buyback::test_buyback_program_not_owner --exact --show-output
+#[test]
+fn test_buyback_program_not_owner() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        assert_noop!(
+            SubtensorModule::set_buyback_program(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                u16::MAX,
+                100_000,
+                2_000_000_000
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::set_buyback_program(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            u16::MAX,
+            100_000,
+            2_000_000_000
+        ));
+
+        Balances::make_free_balance_be(&coldkey, 1_000_000_000);
+        assert_noop!(
+            SubtensorModule::fund_buyback_program(RuntimeOrigin::signed(coldkey), netuid, 1_000),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::clear_buyback_program(RuntimeOrigin::signed(coldkey), netuid),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::buyback::test_run_buyback_program --exact --show-output
+#[test]
+fn test_run_buyback_program() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        SubnetTAO::<Test>::insert(netuid, 1_000_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 1_000_000_000_000);
+        SubnetAlphaOut::<Test>::insert(netuid, 1_000_000_000_000);
+
+        let tao_per_epoch = 1_000_000;
+        SubnetBuybackProgram::<Test>::insert(
+            netuid,
+            BuybackProgram {
+                owner_cut_burn: u16::MAX,
+                tao_per_epoch,
+                max_price: 2_000_000_000,
+            },
+        );
+        SubnetBuybackBudget::<Test>::insert(netuid, 1_500_000);
+
+        let owner_cut = 10_000;
+        let remaining = SubtensorModule::run_buyback_program(netuid, owner_cut);
+        assert_eq!(remaining, 0);
+
+        let alpha_bought = SubnetBuybackAlphaBought::<Test>::get(netuid);
+        assert!(alpha_bought > 0);
+        assert_eq!(SubnetBuybackTaoSpent::<Test>::get(netuid), tao_per_epoch);
+        assert_eq!(SubnetBuybackBudget::<Test>::get(netuid), 500_000);
+        assert_eq!(
+            SubnetBuybackAlphaBurned::<Test>::get(netuid),
+            owner_cut + alpha_bought
+        );
+        assert_eq!(
+            SubnetTAO::<Test>::get(netuid),
+            1_000_000_000_000 + tao_per_epoch
+        );
+        assert_eq!(
+            SubnetAlphaIn::<Test>::get(netuid),
+            1_000_000_000_000 - alpha_bought
+        );
+        // Bought alpha enters and leaves SubnetAlphaOut, only the owner cut burn remains.
+        assert_eq!(
+            SubnetAlphaOut::<Test>::get(netuid),
+            1_000_000_000_000 - owner_cut
+        );
+
+        // Only the remaining budget is spent on the next epoch.
+        SubtensorModule::run_buyback_program(netuid, 0);
+        assert_eq!(SubnetBuybackBudget::<Test>::get(netuid), 0);
+        assert_eq!(
+            SubnetBuybackTaoSpent::<Test>::get(netuid),
+            tao_per_epoch + 500_000
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::buyback::test_run_buyback_program_above_max_price --exact --show-output
+#[test]
+fn test_run_buyback_program_above_max_price() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        // Price is 1 TAO per alpha, above the max price of the program.
+        SubnetTAO::<Test>::insert(netuid, 1_000_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 1_000_000_000_000);
+        SubnetAlphaOut::<Test>::insert(netuid, 1_000_000_000_000);
+
+        SubnetBuybackProgram::<Test>::insert(
+            netuid,
+            BuybackProgram {
+                owner_cut_burn: 0,
+                tao_per_epoch: 1_000_000,
+                max_price: 500_000_000,
+            },
+        );
+        SubnetBuybackBudget::<Test>::insert(netuid, 1_000_000);
+
+        let owner_cut = 10_000;
+        assert_eq!(
+            SubtensorModule::run_buyback_program(netuid, owner_cut),
+            owner_cut
+        );
+        assert_eq!(SubnetBuybackBudget::<Test>::get(netuid), 1_000_000);
+        assert_eq!(SubnetBuybackAlphaBought::<Test>::get(netuid), 0);
+        assert_eq!(SubnetAlphaIn::<Test>::get(netuid), 1_000_000_000_000);
+        assert_eq!(SubnetAlphaOut::<Test>::get(netuid), 1_000_000_000_000);
+    });
+}
@@ -555,3 +555,34 @@ fn test_migrate_revealed_commitments() {
         assert!(!weight.is_zero(), "Migration weight should be non-zero");
     });
 }
+
+#[test]
+fn test_migrate_seed_drand_oldest_round() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_seed_drand_oldest_round";
+
+        // Pulses stored before pruning was introduced
+        for round in 500..=520 {
+            pallet_drand::Pulses::<Test>::insert(
+                round,
+                pallet_drand::types::Pulse {
+                    round,
+                    ..Default::default()
+                },
+            );
+        }
+        pallet_drand::LastStoredRound::<Test>::put(520);
+        assert_eq!(pallet_drand::OldestStoredRound::<Test>::get(), 0);
+
+        let weight =
+            crate::migrations::migrate_seed_drand_oldest_round::migrate_seed_drand_oldest_round::<
+                Test,
+            >();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert_eq!(pallet_drand::OldestStoredRound::<Test>::get(), 500);
+        assert!(!weight.is_zero());
+    });
+}
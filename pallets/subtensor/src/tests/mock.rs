@@ -12,7 +12,7 @@ use frame_support::{
 use frame_system as system;
 use frame_system::{EnsureNever, EnsureRoot, RawOrigin, limits};
 use pallet_collective::MemberCount;
-use sp_core::{ConstU64, Get, H256, U256, offchain::KeyTypeId};
+use sp_core::{ConstU32, ConstU64, Get, H256, U256, offchain::KeyTypeId};
 use sp_runtime::Perbill;
 use sp_runtime::{
     BuildStorage,
@@ -209,6 +209,7 @@ parameter_types! {
     pub const InitialTaoWeight: u64 = 0; // 100% global weight.
     pub const InitialEmaPriceHalvingPeriod: u64 = 201_600_u64; // 4 weeks
     pub const DurationOfStartCall: u64 =  7 * 24 * 60 * 60 / 12; // Default as 7 days
+    pub const MaxTwapWindowBlocks: u64 = 7200; // 1 day
 }
 
 // Configure collective pallet for council
@@ -317,6 +318,8 @@ impl pallet_collective::Config<TriumvirateCollective> for Test {
     type CanPropose = CanProposeToTriumvirate;
     type CanVote = CanVoteToTriumvirate;
     type GetVotingMembers = GetSenateMemberCount;
+    type VoteWeight = ();
+    type MaxAutoCloseWeight = ();
 }
 
 // We call council members Triumvirate
@@ -352,6 +355,8 @@ impl pallet_collective::Config<SenateCollective> for Test {
     type CanPropose = ();
     type CanVote = ();
     type GetVotingMembers = ();
+    type VoteWeight = ();
+    type MaxAutoCloseWeight = ();
 }
 
 // We call our top K delegates membership Senate
@@ -379,6 +384,8 @@ impl crate::Config for Test {
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnNeuronRemoved = ();
+    type IdentityInterface = ();
     type Scheduler = Scheduler;
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -433,6 +440,7 @@ impl crate::Config for Test {
     type InitialTaoWeight = InitialTaoWeight;
     type InitialEmaPriceHalvingPeriod = InitialEmaPriceHalvingPeriod;
     type DurationOfStartCall = DurationOfStartCall;
+    type MaxTwapWindowBlocks = MaxTwapWindowBlocks;
 }
 
 pub struct OriginPrivilegeCmp;
@@ -468,6 +476,8 @@ impl pallet_utility::Config for Test {
     type RuntimeCall = RuntimeCall;
     type PalletsOrigin = OriginCaller;
     type WeightInfo = pallet_utility::weights::SubstrateWeight<Test>;
+    type Predicate = crate::utils::batch_predicate::BatchPredicate<U256>;
+    type PredicateEvaluator = SubtensorModule;
 }
 
 parameter_types! {
@@ -533,6 +543,17 @@ impl pallet_drand::Config for Test {
     type Verifier = pallet_drand::verifier::QuicknetVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type DefaultRetentionRounds = ConstU64<201_600>;
+    type MaxPrunedPulsesPerBlock = ConstU32<100>;
+    type MaxReferenceAge = ConstU64<403_200>;
+    type OnTimelockRevealed = ();
+    type MaxTimelocksPerRound = ConstU32<64>;
+    type MaxTimelockRevealsPerBlock = ConstU32<16>;
+    type MaxTimelocksPerAccount = ConstU32<32>;
+    type MaxPendingTimelocks = ConstU32<1_024>;
+    type RevealedTimelockLifetime = ConstU64<50_400>;
+    type Currency = Balances;
+    type TimelockDeposit = ConstU64<0>;
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -1,4 +1,5 @@
 mod batch_tx;
+mod buyback;
 mod children;
 mod coinbase;
 mod delegate_info;
@@ -19,7 +20,9 @@ mod serving;
 mod staking;
 mod staking2;
 mod subnet;
+mod subnet_governance;
 mod swap_coldkey;
 mod swap_hotkey;
+mod twap;
 mod uids;
 mod weights;
@@ -1,6 +1,7 @@
 use super::mock::*;
 use crate::*;
 use approx::assert_abs_diff_eq;
+use frame_support::dispatch::GetDispatchInfo;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use sp_core::{Get, U256};
 use substrate_fixed::types::{U64F64, U96F32};
@@ -1752,3 +1753,249 @@ fn test_move_stake_specific_stake_into_subnet_fail() {
         );
     });
 }
+
+fn setup_rebalance_subnets(reserve: u64) -> (u16, u16) {
+    let subnet_owner_coldkey = U256::from(1001);
+    let subnet_owner_hotkey = U256::from(1002);
+    let netuid_a = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+    let netuid_b = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+    for netuid in [netuid_a, netuid_b] {
+        SubnetTAO::<Test>::insert(netuid, reserve);
+        SubnetAlphaIn::<Test>::insert(netuid, reserve);
+    }
+    (netuid_a, netuid_b)
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_rebalance_stake_success --exact --show-output
+#[test]
+fn test_rebalance_stake_success() {
+    new_test_ext(1).execute_with(|| {
+        let (netuid_a, netuid_b) = setup_rebalance_subnets(1_000_000_000_000_000);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        let stake_amount = 10_000_000_000;
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &other_hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid_a, stake_amount, 0);
+
+        // Half on netuid A, a quarter on each of the hotkeys on netuid B.
+        let half = u16::MAX / 2 + 1;
+        let quarter = u16::MAX / 4;
+        assert_ok!(SubtensorModule::rebalance_stake(
+            RuntimeOrigin::signed(coldkey),
+            vec![
+                (hotkey, netuid_a, half),
+                (hotkey, netuid_b, quarter),
+                (other_hotkey, netuid_b, u16::MAX - half - quarter),
+            ],
+            u16::MAX / 100,
+        ));
+
+        let epsilon = stake_amount / 1000;
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid_a
+            ),
+            stake_amount / 2,
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid_b
+            ),
+            stake_amount / 4,
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &other_hotkey,
+                &coldkey,
+                netuid_b
+            ),
+            stake_amount / 4,
+            epsilon = epsilon
+        );
+
+        assert!(System::events().iter().any(|e| {
+            matches!(
+                &e.event,
+                RuntimeEvent::SubtensorModule(Event::StakeRebalanced(..))
+            )
+        }));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_rebalance_stake_invalid_targets --exact --show-output
+#[test]
+fn test_rebalance_stake_invalid_targets() {
+    new_test_ext(1).execute_with(|| {
+        let (netuid_a, netuid_b) = setup_rebalance_subnets(1_000_000_000_000_000);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid_a, 10_000_000_000, 0);
+
+        let invalid_targets = vec![
+            vec![],
+            vec![(hotkey, netuid_a, u16::MAX / 2)],
+            vec![
+                (hotkey, netuid_a, u16::MAX / 2),
+                (hotkey, netuid_a, u16::MAX / 2 + 1),
+            ],
+        ];
+        for targets in invalid_targets {
+            assert_noop!(
+                SubtensorModule::rebalance_stake(RuntimeOrigin::signed(coldkey), targets, u16::MAX),
+                Error::<Test>::InvalidRebalanceTargets
+            );
+        }
+
+        assert_noop!(
+            SubtensorModule::rebalance_stake(
+                RuntimeOrigin::signed(coldkey),
+                vec![(hotkey, netuid_b + 1, u16::MAX)],
+                u16::MAX
+            ),
+            Error::<Test>::SubnetNotExists
+        );
+        assert_noop!(
+            SubtensorModule::rebalance_stake(
+                RuntimeOrigin::signed(coldkey),
+                vec![(U256::from(99), netuid_b, u16::MAX)],
+                u16::MAX
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_rebalance_stake_too_many_positions --exact --show-output
+#[test]
+fn test_rebalance_stake_too_many_positions() {
+    new_test_ext(1).execute_with(|| {
+        let (netuid_a, netuid_b) = setup_rebalance_subnets(1_000_000_000_000_000);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid_a, 10_000_000_000, 0);
+
+        // The hotkeys are bounded against every subnet before any position is read
+        let hotkeys: Vec<U256> = (0..MAX_REBALANCE_POSITIONS)
+            .map(|i| U256::from(i.saturating_add(100)))
+            .chain(core::iter::once(hotkey))
+            .collect();
+        StakingHotkeys::<Test>::insert(coldkey, hotkeys);
+        assert_noop!(
+            SubtensorModule::rebalance_stake(
+                RuntimeOrigin::signed(coldkey),
+                vec![(hotkey, netuid_b, u16::MAX)],
+                u16::MAX
+            ),
+            Error::<Test>::TooManyStakePositions
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_rebalance_stake_slippage_too_high --exact --show-output
+#[test]
+fn test_rebalance_stake_slippage_too_high() {
+    new_test_ext(1).execute_with(|| {
+        // Shallow pools, moving all of the stake loses a large share of its value.
+        let (netuid_a, netuid_b) = setup_rebalance_subnets(10_000_000_000);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid_a, 5_000_000_000, 0);
+
+        assert_err!(
+            SubtensorModule::rebalance_stake(
+                RuntimeOrigin::signed(coldkey),
+                vec![(hotkey, netuid_b, u16::MAX)],
+                u16::MAX / 100,
+            ),
+            Error::<Test>::SlippageTooHigh
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_simulate_rebalance_stake --exact --show-output
+#[test]
+fn test_simulate_rebalance_stake() {
+    new_test_ext(1).execute_with(|| {
+        let (netuid_a, netuid_b) = setup_rebalance_subnets(1_000_000_000_000_000);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let stake_amount = 10_000_000_000;
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid_a, stake_amount, 0);
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey, &coldkey, netuid_a,
+        );
+
+        let targets = vec![(hotkey, netuid_b, u16::MAX)];
+        let moves = SubtensorModule::plan_rebalance_stake(&coldkey, &targets).unwrap();
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].origin_netuid, netuid_a);
+        assert_eq!(moves[0].destination_netuid, netuid_b);
+        assert_eq!(moves[0].alpha_amount, alpha);
+        let fee = SubtensorModule::get_stake_fee(
+            Some((hotkey, netuid_a)),
+            coldkey,
+            Some((hotkey, netuid_b)),
+            coldkey,
+            alpha,
+        );
+        assert_eq!(moves[0].fee, fee);
+
+        assert!(SubtensorModule::simulate_rebalance_stake(coldkey, targets).is_some());
+        assert!(
+            SubtensorModule::simulate_rebalance_stake(coldkey, vec![(hotkey, netuid_b, 1)])
+                .is_none()
+        );
+
+        // Nothing is moved by the simulation.
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid_a
+            ),
+            alpha
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_rebalance_stake_weight_covers_every_move --exact --show-output
+#[test]
+fn test_rebalance_stake_weight_covers_every_move() {
+    new_test_ext(1).execute_with(|| {
+        let targets = vec![
+            (U256::from(2), 1, u16::MAX / 2 + 1),
+            (U256::from(3), 1, u16::MAX / 2),
+        ];
+        let rebalance = RuntimeCall::SubtensorModule(crate::Call::rebalance_stake {
+            targets,
+            max_slippage: 0,
+        });
+        let move_stake = RuntimeCall::SubtensorModule(crate::Call::move_stake {
+            origin_hotkey: U256::from(2),
+            destination_hotkey: U256::from(3),
+            origin_netuid: 1,
+            destination_netuid: 1,
+            alpha_amount: 1,
+        });
+
+        // Every held position can be moved into each of the two targets.
+        let moves = u64::from(MAX_REBALANCE_POSITIONS) * 2;
+        assert!(
+            rebalance
+                .get_dispatch_info()
+                .weight
+                .all_gte(move_stake.get_dispatch_info().weight.saturating_mul(moves))
+        );
+    });
+}
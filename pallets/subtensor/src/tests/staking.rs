@@ -1,6 +1,8 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::arithmetic_side_effects)]
 
+use alloc::collections::BTreeMap;
+use codec::Compact;
 use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
 use frame_system::RawOrigin;
 use safe_math::SafeDiv;
@@ -1957,6 +1959,324 @@ fn test_rate_limits_enforced_on_decrease_before_increase_take() {
     });
 }
 
+// Test setting the tiered take schedule of a delegate.
+#[test]
+fn test_set_delegate_take_tiers() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let nominator = U256::from(4);
+
+        let netuid = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        Delegates::<Test>::insert(hotkey0, InitialDefaultDelegateTake::get());
+
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let tiers = vec![(1_000_000_000, min_take + 100), (5_000_000_000, min_take)];
+
+        // Only the owner can set the tiers.
+        assert_noop!(
+            SubtensorModule::set_delegate_take_tiers(
+                RuntimeOrigin::signed(nominator),
+                hotkey0,
+                tiers.clone()
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // Thresholds must be strictly increasing.
+        assert_noop!(
+            SubtensorModule::set_delegate_take_tiers(
+                RuntimeOrigin::signed(coldkey0),
+                hotkey0,
+                vec![(5_000_000_000, min_take), (1_000_000_000, min_take)]
+            ),
+            Error::<Test>::InvalidDelegateTakeTiers
+        );
+
+        // Takes must be in range.
+        assert_noop!(
+            SubtensorModule::set_delegate_take_tiers(
+                RuntimeOrigin::signed(coldkey0),
+                hotkey0,
+                vec![(1_000_000_000, min_take - 1)]
+            ),
+            Error::<Test>::DelegateTakeTooLow
+        );
+
+        assert_ok!(SubtensorModule::set_delegate_take_tiers(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            tiers.clone()
+        ));
+        assert_eq!(DelegateTakeTiers::<Test>::get(hotkey0), tiers);
+
+        // The take of the highest tier reached applies, stable subnet alpha is worth 1 TAO.
+        assert_eq!(
+            SubtensorModule::get_nominator_take(&hotkey0, &nominator, netuid, 500_000_000),
+            InitialDefaultDelegateTake::get()
+        );
+        assert_eq!(
+            SubtensorModule::get_nominator_take(&hotkey0, &nominator, netuid, 1_000_000_000),
+            min_take + 100
+        );
+        assert_eq!(
+            SubtensorModule::get_nominator_take(&hotkey0, &nominator, netuid, 10_000_000_000),
+            min_take
+        );
+
+        // The override applies when it is lower than the tier take.
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            nominator,
+            Some(min_take + 50)
+        ));
+        assert_eq!(
+            SubtensorModule::get_nominator_take(&hotkey0, &nominator, netuid, 1_000_000_000),
+            min_take + 50
+        );
+        assert_eq!(
+            SubtensorModule::get_nominator_take(&hotkey0, &nominator, netuid, 10_000_000_000),
+            min_take
+        );
+
+        // Both are shown in the delegate info.
+        let delegate_info = SubtensorModule::get_delegate(hotkey0).unwrap();
+        assert_eq!(delegate_info.take_tiers.len(), 2);
+        assert_eq!(
+            delegate_info.take_overrides,
+            vec![(nominator, Compact(min_take + 50))]
+        );
+    });
+}
+
+// Test that only changes raising a nominator take are rate-limited.
+#[test]
+fn test_rate_limits_enforced_on_delegate_take_override_removal() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let nominator = U256::from(4);
+
+        let netuid = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+
+        let min_take = SubtensorModule::get_min_delegate_take();
+
+        // Lowering the take is not rate-limited.
+        for take in [min_take + 2, min_take + 1] {
+            assert_ok!(SubtensorModule::set_delegate_take_override(
+                RuntimeOrigin::signed(coldkey0),
+                hotkey0,
+                nominator,
+                Some(take)
+            ));
+        }
+
+        // Removing the override raises the take.
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            nominator,
+            None
+        ));
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            nominator,
+            Some(min_take)
+        ));
+        assert_noop!(
+            SubtensorModule::set_delegate_take_override(
+                RuntimeOrigin::signed(coldkey0),
+                hotkey0,
+                nominator,
+                None
+            ),
+            Error::<Test>::DelegateTxRateLimitExceeded
+        );
+
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            nominator,
+            None
+        ));
+        assert_eq!(DelegateTakeOverrides::<Test>::get(hotkey0, nominator), None);
+    });
+}
+
+// Test that nominators with a lower take receive the difference out of the delegate take.
+#[test]
+fn test_delegate_take_override_applied_to_dividends() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let nominator_a = U256::from(4);
+        let nominator_b = U256::from(5);
+
+        let netuid = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        let take = InitialDefaultDelegateTake::get();
+        let min_take = SubtensorModule::get_min_delegate_take();
+        Delegates::<Test>::insert(hotkey0, take);
+
+        let stake = 1_000_000_000;
+        increase_stake_on_coldkey_hotkey_account(&nominator_a, &hotkey0, stake, netuid);
+        increase_stake_on_coldkey_hotkey_account(&nominator_b, &hotkey0, stake, netuid);
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            nominator_a,
+            Some(min_take)
+        ));
+
+        let dividends: u64 = 1_000_000_000;
+        let mut alpha_dividends: BTreeMap<U256, U96F32> = BTreeMap::new();
+        alpha_dividends.insert(hotkey0, U96F32::from_num(dividends));
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            0,
+            BTreeMap::new(),
+            alpha_dividends,
+            BTreeMap::new(),
+        );
+
+        // The rebate is credited on the distribution, without a stake change of the nominator.
+        let rebate = dividends / 2 * u64::from(take - min_take) / u64::from(u16::MAX);
+        assert_abs_diff_eq!(
+            PendingTakeRebates::<Test>::get(hotkey0, netuid),
+            0,
+            epsilon = 1
+        );
+
+        let gain_a = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey0,
+            &nominator_a,
+            netuid,
+        ) - stake;
+        let gain_b = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey0,
+            &nominator_b,
+            netuid,
+        ) - stake;
+        let gain_owner = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey0, &coldkey0, netuid,
+        );
+
+        // Nominator A holds half of the stake and pays the lower take on its half.
+        assert_abs_diff_eq!(gain_a - gain_b, rebate, epsilon = 10);
+        assert_abs_diff_eq!(gain_a + gain_b + gain_owner, dividends, epsilon = 10);
+    });
+}
+
+// Test that dividend distributions credit passive nominators and move them to a new tier schedule,
+// a bounded number of stake positions at a time.
+#[test]
+fn test_delegate_take_rebates_credited_on_distribution() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+
+        let netuid = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        let take = InitialDefaultDelegateTake::get();
+        let min_take = SubtensorModule::get_min_delegate_take();
+        Delegates::<Test>::insert(hotkey0, take);
+
+        // The nominators stake before the delegate lowers the take for everyone.
+        let nominators = MAX_TAKE_CLASS_REFRESHES as u64 + 8;
+        let stake = 1_000_000_000;
+        for i in 0..nominators {
+            increase_stake_on_coldkey_hotkey_account(&U256::from(100 + i), &hotkey0, stake, netuid);
+        }
+        assert_ok!(SubtensorModule::set_delegate_take_tiers(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            vec![(0, min_take)]
+        ));
+        assert_eq!(NominatorTakeClass::<Test>::iter().count(), 0);
+
+        let dividends: u64 = 1_000_000_000;
+        let distribute = || {
+            let mut alpha_dividends: BTreeMap<U256, U96F32> = BTreeMap::new();
+            alpha_dividends.insert(hotkey0, U96F32::from_num(dividends));
+            SubtensorModule::distribute_dividends_and_incentives(
+                netuid,
+                0,
+                BTreeMap::new(),
+                alpha_dividends,
+                BTreeMap::new(),
+            );
+        };
+        let rebate = dividends * u64::from(take - min_take) / u64::from(u16::MAX);
+
+        // Each distribution moves at most MAX_TAKE_CLASS_REFRESHES positions to the new schedule.
+        distribute();
+        assert_eq!(
+            NominatorTakeClass::<Test>::iter().count(),
+            MAX_TAKE_CLASS_REFRESHES as usize
+        );
+        assert!(TakeRebateCursor::<Test>::contains_key(hotkey0));
+        distribute();
+        assert_eq!(
+            NominatorTakeClass::<Test>::iter().count(),
+            nominators as usize
+        );
+        assert!(!TakeRebateCursor::<Test>::contains_key(hotkey0));
+
+        // Two distributions accrued rebates, the next one credits the first positions again and
+        // only the rebates of the remaining positions stay pending.
+        assert!(PendingTakeRebates::<Test>::get(hotkey0, netuid) > rebate / 2);
+        distribute();
+        let pending = PendingTakeRebates::<Test>::get(hotkey0, netuid);
+        assert!(pending > 0 && pending < rebate / 2);
+    });
+}
+
+// Test that removing a subnet removes its take classes and pending take rebates.
+#[test]
+fn test_remove_network_clears_delegate_take_classes() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let nominator = U256::from(4);
+
+        let netuid = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        let min_take = SubtensorModule::get_min_delegate_take();
+
+        increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey0, 1_000_000_000, netuid);
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            nominator,
+            Some(min_take)
+        ));
+        PendingTakeRebates::<Test>::insert(hotkey0, netuid, 1_000);
+        assert!(DelegateTakeClasses::<Test>::contains_key(hotkey0, netuid));
+        assert!(NominatorTakeClass::<Test>::contains_key((
+            hotkey0, nominator, netuid
+        )));
+
+        SubtensorModule::remove_network(netuid);
+
+        assert!(!DelegateTakeClasses::<Test>::contains_key(hotkey0, netuid));
+        assert!(!NominatorTakeClass::<Test>::contains_key((
+            hotkey0, nominator, netuid
+        )));
+        assert!(!PendingTakeRebates::<Test>::contains_key(hotkey0, netuid));
+    });
+}
+
 #[test]
 fn test_get_total_delegated_stake_after_unstaking() {
     new_test_ext(1).execute_with(|| {
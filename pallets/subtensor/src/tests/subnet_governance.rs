@@ -0,0 +1,527 @@
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::unwrap_used
+)]
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_core::U256;
+use sp_runtime::DispatchError;
+
+use super::mock::*;
+use crate::*;
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_set_subnet_governance --exact --show-output
+#[test]
+fn test_set_subnet_governance() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        // Only the owner or root can enable governance.
+        assert_noop!(
+            SubtensorModule::set_subnet_governance(
+                RuntimeOrigin::signed(U256::from(7)),
+                netuid,
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+        assert!(SubnetGovernanceEnabled::<Test>::get(netuid));
+        System::assert_last_event(Event::SubnetGovernanceSet(netuid, true).into());
+
+        // The owner can no longer change hyperparameters directly, root still can.
+        assert_noop!(
+            SubtensorModule::ensure_ungoverned_subnet_owner_or_root(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid
+            ),
+            Error::<Test>::SubnetGovernanceActive
+        );
+        assert_ok!(SubtensorModule::ensure_ungoverned_subnet_owner_or_root(
+            RawOrigin::Root.into(),
+            netuid
+        ));
+
+        // Only root can disable governance again.
+        assert_noop!(
+            SubtensorModule::set_subnet_governance(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                false
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RawOrigin::Root.into(),
+            netuid,
+            false
+        ));
+        assert!(!SubnetGovernanceEnabled::<Test>::get(netuid));
+        assert_ok!(SubtensorModule::ensure_ungoverned_subnet_owner_or_root(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposal_applies_without_veto --exact --show-output
+#[test]
+fn test_subnet_proposal_applies_without_veto() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetProposalVotingPeriod::<Test>::set(5);
+
+        // Proposals need governance to be enabled.
+        assert_noop!(
+            SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::Kappa(123)
+            ),
+            Error::<Test>::SubnetGovernanceNotEnabled
+        );
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+
+        // Only the owner can propose.
+        assert_noop!(
+            SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(U256::from(7)),
+                netuid,
+                SubnetHyperparameter::Kappa(123)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            SubnetHyperparameter::Kappa(123)
+        ));
+        let end = System::block_number() + 5;
+        System::assert_last_event(
+            Event::SubnetProposalCreated {
+                netuid,
+                proposal_id: 0,
+                change: SubnetHyperparameter::Kappa(123),
+                end,
+            }
+            .into(),
+        );
+
+        let proposals = SubtensorModule::get_subnet_proposals(netuid);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].change, SubnetHyperparameter::Kappa(123));
+
+        // Nothing changes until the voting window ends.
+        step_block(4);
+        assert_ne!(SubtensorModule::get_kappa(netuid), 123);
+
+        step_block(1);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 123);
+        assert!(SubtensorModule::get_subnet_proposals(netuid).is_empty());
+        System::assert_has_event(
+            Event::SubnetProposalApplied {
+                netuid,
+                proposal_id: 0,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposal_vetoed_by_alpha_holders --exact --show-output
+#[test]
+fn test_subnet_proposal_vetoed_by_alpha_holders() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let staker = U256::from(2001);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetProposalVotingPeriod::<Test>::set(5);
+        SubtensorModule::stake_into_subnet(&owner_hotkey, &staker, netuid, 1_000_000_000, 0);
+        let alpha = SubtensorModule::get_coldkey_alpha_on_subnet(&staker, netuid);
+        assert!(alpha > 0);
+
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+        let initial_kappa = SubtensorModule::get_kappa(netuid);
+        assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            SubnetHyperparameter::Kappa(123)
+        ));
+
+        // Coldkeys without alpha cannot vote.
+        assert_noop!(
+            SubtensorModule::vote_subnet_proposal(
+                RuntimeOrigin::signed(U256::from(7)),
+                netuid,
+                0,
+                false
+            ),
+            Error::<Test>::NoAlphaToVote
+        );
+        assert_noop!(
+            SubtensorModule::vote_subnet_proposal(RuntimeOrigin::signed(staker), netuid, 1, false),
+            Error::<Test>::SubnetProposalNotFound
+        );
+
+        // Voting again replaces the previous vote.
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(staker),
+            netuid,
+            0,
+            true
+        ));
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(staker),
+            netuid,
+            0,
+            false
+        ));
+        let proposal = SubnetProposals::<Test>::get(netuid, 0).unwrap();
+        assert_eq!((proposal.ayes, proposal.nays), (0, alpha));
+        System::assert_last_event(
+            Event::SubnetProposalVoted {
+                netuid,
+                proposal_id: 0,
+                coldkey: staker,
+                approve: false,
+                weight: alpha,
+            }
+            .into(),
+        );
+
+        step_block(5);
+        assert_eq!(SubtensorModule::get_kappa(netuid), initial_kappa);
+        assert!(SubnetProposals::<Test>::get(netuid, 0).is_none());
+        assert!(SubnetProposalVotes::<Test>::get((netuid, 0), staker).is_none());
+        System::assert_has_event(
+            Event::SubnetProposalVetoed {
+                netuid,
+                proposal_id: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposal_limits --exact --show-output
+#[test]
+fn test_subnet_proposal_limits() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let staker = U256::from(2001);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetProposalVotingPeriod::<Test>::set(5);
+        SubtensorModule::stake_into_subnet(&owner_hotkey, &staker, netuid, 1_000_000_000, 0);
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+
+        assert_noop!(
+            SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::ActivityCutoff(0)
+            ),
+            Error::<Test>::ActivityCutoffTooLow
+        );
+
+        for rho in 0..MAX_OPEN_SUBNET_PROPOSALS {
+            assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::Rho(rho as u16)
+            ));
+        }
+        assert_noop!(
+            SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::Rho(99)
+            ),
+            Error::<Test>::TooManySubnetProposals
+        );
+
+        // Votes are rejected once the voting window has ended.
+        System::set_block_number(System::block_number() + 5);
+        assert_noop!(
+            SubtensorModule::vote_subnet_proposal(RuntimeOrigin::signed(staker), netuid, 0, false),
+            Error::<Test>::SubnetProposalVotingClosed
+        );
+
+        // Disabling governance drops the open proposals.
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RawOrigin::Root.into(),
+            netuid,
+            false
+        ));
+        assert!(SubtensorModule::get_subnet_proposals(netuid).is_empty());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposal_votes_recounted_at_close --exact --show-output
+#[test]
+fn test_subnet_proposal_votes_recounted_at_close() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let staker = U256::from(2001);
+        let receiver = U256::from(2002);
+        let supporter = U256::from(2003);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetProposalVotingPeriod::<Test>::set(5);
+        for coldkey in [staker, receiver, supporter] {
+            SubtensorModule::stake_into_subnet(&owner_hotkey, &coldkey, netuid, 1_000_000, 0);
+        }
+        let alpha: u64 = 1_000_000_000;
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &staker,
+            netuid,
+            alpha,
+        );
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &supporter,
+            netuid,
+            alpha * 3 / 2,
+        );
+
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+        assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            SubnetHyperparameter::Kappa(123)
+        ));
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(supporter),
+            netuid,
+            0,
+            true
+        ));
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(staker),
+            netuid,
+            0,
+            false
+        ));
+
+        // The staker moves its alpha to another coldkey, which votes with it again.
+        let staker_alpha = SubtensorModule::get_coldkey_alpha_on_subnet(&staker, netuid);
+        SubtensorModule::decrease_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &staker,
+            netuid,
+            staker_alpha,
+        );
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &receiver,
+            netuid,
+            staker_alpha,
+        );
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(receiver),
+            netuid,
+            0,
+            false
+        ));
+        let proposal = SubnetProposals::<Test>::get(netuid, 0).unwrap();
+        assert!(proposal.nays > proposal.ayes);
+        assert_eq!(proposal.voters, 3);
+
+        // The moved alpha only counts once when the proposal closes.
+        step_block(5);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 123);
+        System::assert_has_event(
+            Event::SubnetProposalApplied {
+                netuid,
+                proposal_id: 0,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposals_closed_in_bounded_batches --exact --show-output
+#[test]
+fn test_subnet_proposals_closed_in_bounded_batches() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetProposalVotingPeriod::<Test>::set(5);
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+
+        for rho in 0..MAX_OPEN_SUBNET_PROPOSALS {
+            assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::Rho(rho as u16)
+            ));
+        }
+        let end = SubtensorModule::get_current_block_as_u64() + 5;
+        assert_eq!(
+            SubnetProposalQueue::<Test>::get(end).len(),
+            MAX_OPEN_SUBNET_PROPOSALS as usize
+        );
+
+        // Only a bounded number of proposals close per block, the rest move to the next one.
+        step_block(5);
+        let remaining = MAX_OPEN_SUBNET_PROPOSALS - MAX_SUBNET_PROPOSALS_CLOSED_PER_BLOCK;
+        assert_eq!(
+            SubtensorModule::get_subnet_proposals(netuid).len(),
+            remaining as usize
+        );
+        assert!(!SubnetProposalQueue::<Test>::contains_key(end));
+        assert_eq!(
+            SubnetProposalQueue::<Test>::get(end + 1).len(),
+            remaining as usize
+        );
+
+        step_block(1);
+        assert!(SubtensorModule::get_subnet_proposals(netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_rho(netuid),
+            (MAX_OPEN_SUBNET_PROPOSALS - 1) as u16
+        );
+
+        // A block with nothing to close only pays for reading the queue.
+        assert_eq!(
+            SubtensorModule::process_subnet_proposals(end + 2),
+            <Test as frame_system::Config>::DbWeight::get().reads(1)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposal_weights_version_key_rate_limited --exact --show-output
+#[test]
+fn test_subnet_proposal_weights_version_key_rate_limited() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetProposalVotingPeriod::<Test>::set(5);
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+
+        for key in [1, 2] {
+            assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::WeightsVersionKey(key)
+            ));
+        }
+
+        // The second change hits the owner rate limit set by the first one.
+        step_block(5);
+        assert_eq!(SubtensorModule::get_weights_version_key(netuid), 1);
+        System::assert_has_event(
+            Event::SubnetProposalApplied {
+                netuid,
+                proposal_id: 1,
+                result: Err(Error::<Test>::TxRateLimitExceeded.into()),
+            }
+            .into(),
+        );
+
+        // New proposals are rejected while the rate limit holds.
+        assert_noop!(
+            SubtensorModule::propose_subnet_hyperparameter(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                SubnetHyperparameter::WeightsVersionKey(3)
+            ),
+            Error::<Test>::TxRateLimitExceeded
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::subnet_governance::test_subnet_proposal_voters_bounded --exact --show-output
+#[test]
+fn test_subnet_proposal_voters_bounded() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let staker = U256::from(2001);
+        let lightest = U256::from(2002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubtensorModule::stake_into_subnet(&owner_hotkey, &staker, netuid, 1_000_000_000, 0);
+        let alpha = SubtensorModule::get_coldkey_alpha_on_subnet(&staker, netuid);
+        assert_ok!(SubtensorModule::set_subnet_governance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+        assert_ok!(SubtensorModule::propose_subnet_hyperparameter(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            SubnetHyperparameter::Kappa(123)
+        ));
+
+        // Fill the proposal up to the voter limit.
+        SubnetProposals::<Test>::mutate(netuid, 0, |proposal| {
+            let proposal = proposal.as_mut().unwrap();
+            proposal.voters = MAX_SUBNET_PROPOSAL_VOTERS;
+            proposal.nays = alpha + 1;
+        });
+        SubnetProposalVotes::<Test>::insert((netuid, 0), lightest, (false, alpha + 1));
+
+        // A vote carrying no more alpha than the lightest voter is rejected.
+        assert_noop!(
+            SubtensorModule::vote_subnet_proposal(RuntimeOrigin::signed(staker), netuid, 0, true),
+            Error::<Test>::TooManySubnetProposalVoters
+        );
+
+        // A heavier vote replaces the lightest voter.
+        SubnetProposalVotes::<Test>::insert((netuid, 0), lightest, (false, 1));
+        SubnetProposals::<Test>::mutate(netuid, 0, |proposal| {
+            proposal.as_mut().unwrap().nays = 1;
+        });
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(staker),
+            netuid,
+            0,
+            true
+        ));
+        assert!(SubnetProposalVotes::<Test>::get((netuid, 0), lightest).is_none());
+        let proposal = SubnetProposals::<Test>::get(netuid, 0).unwrap();
+        assert_eq!(
+            (proposal.ayes, proposal.nays, proposal.voters),
+            (alpha, 0, MAX_SUBNET_PROPOSAL_VOTERS)
+        );
+    });
+}
@@ -329,6 +329,77 @@ fn test_swap_idempotency() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_swap_delegate_take_override --exact --show-output
+#[test]
+fn test_swap_delegate_take_override() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let owner_coldkey = U256::from(4);
+        let netuid = 1u16;
+        let stake = DefaultMinStake::<Test>::get() * 10;
+        let min_take = SubtensorModule::get_min_delegate_take();
+
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, owner_coldkey, 1001000);
+        Delegates::<Test>::insert(hotkey, InitialDefaultDelegateTake::get());
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, stake);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            hotkey,
+            netuid,
+            stake
+        ));
+        assert_ok!(SubtensorModule::set_delegate_take_override(
+            <<Test as Config>::RuntimeOrigin>::signed(owner_coldkey),
+            hotkey,
+            old_coldkey,
+            Some(min_take)
+        ));
+        assert!(NominatorTakeClass::<Test>::contains_key((
+            hotkey,
+            old_coldkey,
+            netuid
+        )));
+
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight
+        ));
+
+        // The override and the take class follow the stake to the new coldkey.
+        assert_eq!(
+            DelegateTakeOverrides::<Test>::get(hotkey, old_coldkey),
+            None
+        );
+        assert_eq!(
+            DelegateTakeOverrides::<Test>::get(hotkey, new_coldkey),
+            Some(min_take)
+        );
+        assert!(!NominatorTakeClass::<Test>::contains_key((
+            hotkey,
+            old_coldkey,
+            netuid
+        )));
+        assert_eq!(
+            NominatorTakeClass::<Test>::get((hotkey, new_coldkey, netuid)).map(|(take, _)| take),
+            Some(min_take)
+        );
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &new_coldkey,
+            netuid,
+        );
+        assert_eq!(
+            SubtensorModule::get_nominator_take(&hotkey, &new_coldkey, netuid, alpha),
+            min_take
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_swap_with_max_values --exact --show-output
 #[test]
 fn test_swap_with_max_values() {
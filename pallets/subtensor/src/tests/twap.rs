@@ -0,0 +1,129 @@
+#![allow(clippy::arithmetic_side_effects)]
+use sp_core::U256;
+
+use super::mock::*;
+use crate::*;
+
+fn set_price_and_record(netuid: u16, tao: u64, alpha: u64, blocks: core::ops::RangeInclusive<u64>) {
+    SubnetTAO::<Test>::insert(netuid, tao);
+    SubnetAlphaIn::<Test>::insert(netuid, alpha);
+    for block in blocks {
+        System::set_block_number(block);
+        SubtensorModule::update_price_accumulators(block);
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::twap::test_twap_constant_price --exact --show-output
+#[test]
+fn test_twap_constant_price() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = add_dynamic_network(&U256::from(1002), &U256::from(1001));
+
+        set_price_and_record(netuid, 2_000_000_000_000, 1_000_000_000_000, 1..=10);
+
+        assert_eq!(
+            SubnetPriceCumulative::<Test>::get(netuid),
+            10 * 2_000_000_000
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 1),
+            Some(2_000_000_000)
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 9),
+            Some(2_000_000_000)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::twap::test_twap_changing_price --exact --show-output
+#[test]
+fn test_twap_changing_price() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = add_dynamic_network(&U256::from(1002), &U256::from(1001));
+
+        set_price_and_record(netuid, 1_000_000_000_000, 1_000_000_000_000, 1..=4);
+        set_price_and_record(netuid, 3_000_000_000_000, 1_000_000_000_000, 5..=8);
+
+        // Only the last price is in the window.
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 4),
+            Some(3_000_000_000)
+        );
+        // Blocks 3 and 4 at price 1, blocks 5 to 8 at price 3.
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 6),
+            Some(14_000_000_000 / 6)
+        );
+        // The spot price does not move the TWAP until it is recorded.
+        SubnetTAO::<Test>::insert(netuid, 100_000_000_000_000);
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 4),
+            Some(3_000_000_000)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::twap::test_twap_unavailable_window --exact --show-output
+#[test]
+fn test_twap_unavailable_window() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = add_dynamic_network(&U256::from(1002), &U256::from(1001));
+
+        set_price_and_record(netuid, 1_000_000_000_000, 1_000_000_000_000, 1..=5);
+
+        assert_eq!(SubtensorModule::get_alpha_twap(netuid, 0), None);
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, MaxTwapWindowBlocks::get() + 1),
+            None
+        );
+        // No observation before block 1.
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 4),
+            Some(1_000_000_000)
+        );
+        assert_eq!(SubtensorModule::get_alpha_twap(netuid, 5), None);
+        // No observation for the current block.
+        System::set_block_number(6);
+        assert_eq!(SubtensorModule::get_alpha_twap(netuid, 1), None);
+        // Unknown subnet.
+        assert_eq!(SubtensorModule::get_alpha_twap(netuid + 1, 1), None);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::twap::test_twap_recorded_in_block_step --exact --show-output
+#[test]
+fn test_twap_recorded_in_block_step() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+
+        step_block(3);
+
+        // Stable subnets always have a price of 1.
+        assert_eq!(
+            SubtensorModule::get_alpha_twap(netuid, 2),
+            Some(1_000_000_000)
+        );
+
+        SubtensorModule::remove_network(netuid);
+        assert_eq!(SubnetPriceCumulative::<Test>::get(netuid), 0);
+        assert_eq!(SubtensorModule::get_alpha_twap(netuid, 2), None);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::twap::test_twap_weight_scales_with_subnets --exact --show-output
+#[test]
+fn test_twap_weight_scales_with_subnets() {
+    new_test_ext(1).execute_with(|| {
+        let before = SubtensorModule::update_price_accumulators_weight();
+        add_dynamic_network(&U256::from(1002), &U256::from(1001));
+        let after = SubtensorModule::update_price_accumulators_weight();
+
+        assert!(after.ref_time() > before.ref_time());
+        assert_eq!(
+            after.saturating_sub(before),
+            <Test as frame_system::Config>::DbWeight::get().reads_writes(6, 2)
+        );
+    });
+}
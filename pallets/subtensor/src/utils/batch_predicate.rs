@@ -0,0 +1,82 @@
+use super::*;
+use frame_support::weights::Weight;
+use substrate_fixed::types::U96F32;
+use subtensor_macros::freeze_struct;
+
+/// A quantity of subtensor state that a batch predicate compares against a value.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum PredicateOperand<AccountId> {
+    /// The alpha price of a subnet, in rao of TAO per alpha.
+    AlphaPrice(u16),
+    /// The current block number.
+    BlockNumber,
+    /// The transferable balance of a coldkey, in rao.
+    FreeBalance(AccountId),
+    /// The alpha staked by a coldkey to a hotkey on a subnet.
+    Stake {
+        hotkey: AccountId,
+        coldkey: AccountId,
+        netuid: u16,
+    },
+}
+
+/// How an operand is compared against the value of a batch predicate.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum PredicateComparison {
+    LessThan,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    GreaterThan,
+}
+
+/// A condition on subtensor state gating the calls of a `pallet_utility::batch_if`, read as
+/// `operand comparison value`, e.g. `AlphaPrice(3) LessThan 20_000_000`.
+#[freeze_struct("cd859113b3341126")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct BatchPredicate<AccountId> {
+    pub operand: PredicateOperand<AccountId>,
+    pub comparison: PredicateComparison,
+    pub value: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Reads the current value of a predicate operand.
+    pub fn get_predicate_operand(operand: &PredicateOperand<T::AccountId>) -> u64 {
+        match operand {
+            PredicateOperand::AlphaPrice(netuid) => Self::get_alpha_price(*netuid)
+                .saturating_mul(U96F32::saturating_from_num(1_000_000_000))
+                .saturating_to_num::<u64>(),
+            PredicateOperand::BlockNumber => Self::get_current_block_as_u64(),
+            PredicateOperand::FreeBalance(coldkey) => Self::get_coldkey_balance(coldkey),
+            PredicateOperand::Stake {
+                hotkey,
+                coldkey,
+                netuid,
+            } => Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, *netuid),
+        }
+    }
+}
+
+impl<T: Config> pallet_utility::EvaluatePredicate<BatchPredicate<T::AccountId>> for Pallet<T> {
+    fn holds(predicate: &BatchPredicate<T::AccountId>) -> bool {
+        let current = Self::get_predicate_operand(&predicate.operand);
+        match predicate.comparison {
+            PredicateComparison::LessThan => current < predicate.value,
+            PredicateComparison::LessOrEqual => current <= predicate.value,
+            PredicateComparison::Equal => current == predicate.value,
+            PredicateComparison::GreaterOrEqual => current >= predicate.value,
+            PredicateComparison::GreaterThan => current > predicate.value,
+        }
+    }
+
+    fn weight(predicate: &BatchPredicate<T::AccountId>) -> Weight {
+        let reads = match predicate.operand {
+            PredicateOperand::AlphaPrice(_) => 3,
+            PredicateOperand::BlockNumber => 1,
+            PredicateOperand::FreeBalance(_) => 1,
+            PredicateOperand::Stake { .. } => 3,
+        };
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads(reads))
+    }
+}
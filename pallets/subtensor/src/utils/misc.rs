@@ -23,6 +23,27 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Like `ensure_subnet_owner_or_root`, but rejects the subnet owner while the subnet is
+    /// governed by its alpha holders, whose changes go through `propose_subnet_hyperparameter`.
+    pub fn ensure_ungoverned_subnet_owner_or_root(
+        o: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> Result<(), DispatchError> {
+        let coldkey = ensure_signed_or_root(o);
+        match coldkey {
+            Ok(Some(who)) if SubnetOwner::<T>::get(netuid) == who => {
+                ensure!(
+                    !SubnetGovernanceEnabled::<T>::get(netuid),
+                    Error::<T>::SubnetGovernanceActive
+                );
+                Ok(())
+            }
+            Ok(Some(_)) => Err(DispatchError::BadOrigin),
+            Ok(None) => Ok(()),
+            Err(x) => Err(x.into()),
+        }
+    }
+
     pub fn ensure_subnet_owner(o: T::RuntimeOrigin, netuid: u16) -> Result<(), DispatchError> {
         let coldkey = ensure_signed(o);
         match coldkey {
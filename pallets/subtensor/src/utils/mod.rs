@@ -1,4 +1,5 @@
 use super::*;
+pub mod batch_predicate;
 pub mod evm;
 pub mod identity;
 pub mod misc;
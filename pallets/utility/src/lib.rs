@@ -45,6 +45,7 @@
 //!
 //! #### For batch dispatch
 //! * `batch` - Dispatch multiple calls from the sender's origin.
+//! * `batch_if` - Atomically dispatch multiple calls, only if a set of on-chain predicates hold.
 //!
 //! #### For pseudonymal dispatch
 //! * `as_derivative` - Dispatch a call from a derivative signed origin.
@@ -61,8 +62,11 @@ extern crate alloc;
 use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::{
-    dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+    dispatch::{
+        extract_actual_weight, DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo,
+    },
     traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
+    weights::Weight,
 };
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
@@ -73,6 +77,29 @@ use subtensor_macros::freeze_struct;
 
 pub use pallet::*;
 
+/// The maximum number of predicates a single `batch_if` may be gated on.
+pub const MAX_BATCH_PREDICATES: u32 = 8;
+
+/// Evaluates the predicates that gate a `batch_if` against the current chain state.
+pub trait EvaluatePredicate<Predicate> {
+    /// Whether `predicate` holds at the time of dispatch.
+    fn holds(predicate: &Predicate) -> bool;
+
+    /// The weight of evaluating `predicate`.
+    fn weight(predicate: &Predicate) -> Weight;
+}
+
+/// An evaluator for which no predicate ever holds.
+impl<Predicate> EvaluatePredicate<Predicate> for () {
+    fn holds(_predicate: &Predicate) -> bool {
+        false
+    }
+
+    fn weight(_predicate: &Predicate) -> Weight {
+        Weight::zero()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -104,6 +131,12 @@ pub mod pallet {
 
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
+
+        /// A condition on chain state that can gate the calls of a `batch_if`.
+        type Predicate: Parameter;
+
+        /// Evaluates `Predicate`s at the time of dispatch.
+        type PredicateEvaluator: EvaluatePredicate<Self::Predicate>;
     }
 
     #[pallet::event]
@@ -167,6 +200,8 @@ pub mod pallet {
     pub enum Error<T> {
         /// Too many calls batched.
         TooManyCalls,
+        /// A predicate gating the batch did not hold.
+        PredicateNotMet,
     }
 
     #[pallet::call]
@@ -471,6 +506,66 @@ pub mod pallet {
             let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
             res.map(|_| ()).map_err(|e| e.error)
         }
+
+        /// Send a batch of dispatch calls and atomically execute them, only if every one of the
+        /// given predicates holds at the time of dispatch.
+        ///
+        /// May be called from any origin except `None`.
+        ///
+        /// - `predicates`: The conditions on chain state that must all hold. If any of them does
+        ///   not, no call is dispatched and the transaction fails with `PredicateNotMet`, paying
+        ///   only for the evaluation.
+        /// - `calls`: The calls to be dispatched from the same origin, with the semantics of
+        ///   `batch_all`.
+        ///
+        /// ## Complexity
+        /// - O(P + C) where P is the number of predicates and C the number of calls.
+        #[pallet::call_index(6)]
+        #[pallet::weight({
+			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(calls);
+			let dispatch_weight = dispatch_weight
+				.saturating_add(T::WeightInfo::batch_all(calls.len() as u32))
+				.saturating_add(Pallet::<T>::predicates_weight(predicates));
+			(dispatch_weight, dispatch_class)
+		})]
+        pub fn batch_if(
+            origin: OriginFor<T>,
+            predicates: BoundedVec<T::Predicate, ConstU32<MAX_BATCH_PREDICATES>>,
+            calls: Vec<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            // Do not allow the `None` origin.
+            if ensure_none(origin.clone()).is_ok() {
+                return Err(BadOrigin.into());
+            }
+
+            // Evaluate the predicates in order, stopping at the first one that does not hold.
+            let mut predicates_weight = Weight::zero();
+            for predicate in predicates.iter() {
+                predicates_weight =
+                    predicates_weight.saturating_add(T::PredicateEvaluator::weight(predicate));
+                if !T::PredicateEvaluator::holds(predicate) {
+                    return Err(DispatchErrorWithPostInfo {
+                        post_info: Some(predicates_weight).into(),
+                        error: Error::<T>::PredicateNotMet.into(),
+                    });
+                }
+            }
+
+            Self::batch_all(origin, calls)
+                .map(|mut post_info| {
+                    post_info.actual_weight = post_info
+                        .actual_weight
+                        .map(|weight| weight.saturating_add(predicates_weight));
+                    post_info
+                })
+                .map_err(|mut err| {
+                    err.post_info.actual_weight = err
+                        .post_info
+                        .actual_weight
+                        .map(|weight| weight.saturating_add(predicates_weight));
+                    err
+                })
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -499,6 +594,13 @@ pub mod pallet {
 
             (dispatch_weight, dispatch_class)
         }
+
+        /// Get the accumulated weight of evaluating the given `predicates`.
+        fn predicates_weight(predicates: &[T::Predicate]) -> Weight {
+            predicates.iter().fold(Weight::zero(), |total, predicate| {
+                total.saturating_add(T::PredicateEvaluator::weight(predicate))
+            })
+        }
     }
 }
 
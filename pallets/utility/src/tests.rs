@@ -29,6 +29,7 @@ use frame_support::{
     parameter_types, storage,
     traits::{ConstU64, Contains},
     weights::Weight,
+    BoundedVec,
 };
 use pallet_collective::{EnsureProportionAtLeast, Instance1};
 use sp_runtime::{
@@ -214,6 +215,8 @@ impl pallet_collective::Config<CouncilCollective> for Test {
     type CanPropose = MemberProposals;
     type CanVote = MemberVotes;
     type GetVotingMembers = StoredVotingMembers;
+    type VoteWeight = ();
+    type MaxAutoCloseWeight = ();
 }
 
 impl example::Config for Test {}
@@ -239,11 +242,24 @@ impl mock_democracy::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type ExternalMajorityOrigin = EnsureProportionAtLeast<u64, Instance1, 3, 4>;
 }
+/// A predicate that holds exactly when it is `true`.
+pub struct TestPredicateEvaluator;
+impl EvaluatePredicate<bool> for TestPredicateEvaluator {
+    fn holds(predicate: &bool) -> bool {
+        *predicate
+    }
+
+    fn weight(_predicate: &bool) -> Weight {
+        Weight::from_parts(10, 0)
+    }
+}
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
     type PalletsOrigin = OriginCaller;
     type WeightInfo = ();
+    type Predicate = bool;
+    type PredicateEvaluator = TestPredicateEvaluator;
 }
 
 type ExampleCall = example::Call<Test>;
@@ -638,6 +654,62 @@ fn batch_all_revert() {
     });
 }
 
+#[test]
+fn batch_if_works() {
+    new_test_ext().execute_with(|| {
+        let predicates = BoundedVec::truncate_from(vec![true, true]);
+        assert_ok!(Utility::batch_if(
+            RuntimeOrigin::signed(1),
+            predicates,
+            vec![call_transfer(2, 5), call_transfer(2, 5)]
+        ));
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(Balances::free_balance(2), 20);
+        System::assert_last_event(utility::Event::BatchCompleted.into());
+    });
+}
+
+#[test]
+fn batch_if_skips_calls_when_a_predicate_fails() {
+    new_test_ext().execute_with(|| {
+        let predicates = BoundedVec::truncate_from(vec![true, false, true]);
+        assert_noop!(
+            Utility::batch_if(
+                RuntimeOrigin::signed(1),
+                predicates,
+                vec![call_transfer(2, 5)]
+            ),
+            DispatchErrorWithPostInfo {
+                post_info: PostDispatchInfo {
+                    // Only the predicates up to and including the failing one are paid for.
+                    actual_weight: Some(Weight::from_parts(20, 0)),
+                    pays_fee: Pays::Yes
+                },
+                error: Error::<Test>::PredicateNotMet.into(),
+            }
+        );
+        assert_eq!(Balances::free_balance(1), 10);
+        assert_eq!(Balances::free_balance(2), 10);
+    });
+}
+
+#[test]
+fn batch_if_reverts_like_batch_all() {
+    new_test_ext().execute_with(|| {
+        let predicates = BoundedVec::truncate_from(vec![true]);
+        assert_err_ignore_postinfo!(
+            Utility::batch_if(
+                RuntimeOrigin::signed(1),
+                predicates,
+                vec![call_transfer(2, 5), call_transfer(2, 10)]
+            ),
+            TokenError::FundsUnavailable
+        );
+        assert_eq!(Balances::free_balance(1), 10);
+        assert_eq!(Balances::free_balance(2), 10);
+    });
+}
+
 #[test]
 fn batch_all_handles_weight_refund() {
     new_test_ext().execute_with(|| {
@@ -822,6 +894,10 @@ fn none_origin_does_not_work() {
         );
         assert_noop!(Utility::batch(RuntimeOrigin::none(), vec![]), BadOrigin);
         assert_noop!(Utility::batch_all(RuntimeOrigin::none(), vec![]), BadOrigin);
+        assert_noop!(
+            Utility::batch_if(RuntimeOrigin::none(), BoundedVec::new(), vec![]),
+            BadOrigin
+        );
     })
 }
 
@@ -0,0 +1,179 @@
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_commitments::{CommitmentInfo, Data};
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::{EvmResult, prelude::UnboundedBytes};
+use sp_core::H256;
+use sp_runtime::{SaturatedConversion, traits::Dispatchable};
+use sp_std::{boxed::Box, vec::Vec};
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+// The kind of a commitment field, as passed to `setCommitment` and returned by `getCommitment`.
+const FIELD_NONE: u8 = 0;
+const FIELD_RAW: u8 = 1;
+const FIELD_BLAKE_TWO_256: u8 = 2;
+const FIELD_SHA_256: u8 = 3;
+const FIELD_KECCAK_256: u8 = 4;
+const FIELD_SHA_THREE_256: u8 = 5;
+const FIELD_TIMELOCK_ENCRYPTED: u8 = 6;
+
+/// A commitment field as `(kind, data, revealRound)`, where `revealRound` is only used by
+/// timelock-encrypted fields.
+type Field = (u8, UnboundedBytes, u64);
+
+pub struct CommitmentsPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for CommitmentsPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_commitments::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_commitments::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2056;
+}
+
+#[precompile_utils::precompile]
+impl<R> CommitmentsPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_commitments::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_commitments::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("setCommitment(uint16,(uint8,bytes,uint64)[])")]
+    #[precompile::payable]
+    fn set_commitment(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        fields: Vec<Field>,
+    ) -> EvmResult<()> {
+        let fields = fields
+            .into_iter()
+            .map(Self::data_from_field)
+            .collect::<EvmResult<Vec<Data>>>()?;
+        let fields = fields.try_into().map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::Other("too many fields".into()),
+        })?;
+        let call = pallet_commitments::Call::<R>::set_commitment {
+            netuid,
+            info: Box::new(CommitmentInfo { fields }),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("clearCommitment(uint16)")]
+    #[precompile::payable]
+    fn clear_commitment(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<()> {
+        let call = pallet_commitments::Call::<R>::clear_commitment { netuid };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getCommitment(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_commitment(
+        _: &mut impl PrecompileHandle,
+        netuid: u16,
+        account: H256,
+    ) -> EvmResult<(u64, u64, Vec<Field>)> {
+        let account = R::AccountId::from(account.0);
+        Ok(
+            match pallet_commitments::Pallet::<R>::commitment_of(netuid, account) {
+                Some(registration) => (
+                    registration.block.saturated_into(),
+                    registration.deposit.saturated_into(),
+                    registration
+                        .info
+                        .fields
+                        .into_iter()
+                        .map(Self::field_from_data)
+                        .collect(),
+                ),
+                None => (0, 0, Vec::new()),
+            },
+        )
+    }
+
+    #[precompile::public("getRevealedCommitments(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_revealed_commitments(
+        _: &mut impl PrecompileHandle,
+        netuid: u16,
+        account: H256,
+    ) -> EvmResult<Vec<(UnboundedBytes, u64)>> {
+        let account = R::AccountId::from(account.0);
+        Ok(
+            pallet_commitments::Pallet::<R>::revealed_commitments(netuid, account)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(data, block)| (data.into(), block))
+                .collect(),
+        )
+    }
+}
+
+impl<R> CommitmentsPrecompile<R>
+where
+    R: frame_system::Config + pallet_commitments::Config,
+{
+    fn data_from_field((kind, data, reveal_round): Field) -> EvmResult<Data> {
+        let data: Vec<u8> = data.into();
+        let invalid = |reason: &'static str| PrecompileFailure::Error {
+            exit_status: ExitError::Other(reason.into()),
+        };
+        let hash = |data: Vec<u8>| -> EvmResult<[u8; 32]> {
+            data.try_into()
+                .map_err(|_| invalid("hash must be 32 bytes"))
+        };
+
+        Ok(match kind {
+            FIELD_NONE => Data::None,
+            FIELD_RAW => Data::Raw(data.try_into().map_err(|_| invalid("field is too long"))?),
+            FIELD_BLAKE_TWO_256 => Data::BlakeTwo256(hash(data)?),
+            FIELD_SHA_256 => Data::Sha256(hash(data)?),
+            FIELD_KECCAK_256 => Data::Keccak256(hash(data)?),
+            FIELD_SHA_THREE_256 => Data::ShaThree256(hash(data)?),
+            FIELD_TIMELOCK_ENCRYPTED => Data::TimelockEncrypted {
+                encrypted: data
+                    .try_into()
+                    .map_err(|_| invalid("ciphertext is too long"))?,
+                reveal_round,
+            },
+            _ => return Err(invalid("unknown field kind")),
+        })
+    }
+
+    fn field_from_data(data: Data) -> Field {
+        match data {
+            Data::None => (FIELD_NONE, Vec::new().into(), 0),
+            Data::Raw(bytes) => (FIELD_RAW, bytes.into_inner().into(), 0),
+            Data::BlakeTwo256(hash) => (FIELD_BLAKE_TWO_256, hash.to_vec().into(), 0),
+            Data::Sha256(hash) => (FIELD_SHA_256, hash.to_vec().into(), 0),
+            Data::Keccak256(hash) => (FIELD_KECCAK_256, hash.to_vec().into(), 0),
+            Data::ShaThree256(hash) => (FIELD_SHA_THREE_256, hash.to_vec().into(), 0),
+            Data::TimelockEncrypted {
+                encrypted,
+                reveal_round,
+            } => (
+                FIELD_TIMELOCK_ENCRYPTED,
+                encrypted.into_inner().into(),
+                reveal_round,
+            ),
+        }
+    }
+}
@@ -10,7 +10,7 @@ use pallet_evm::{
     PrecompileHandle, PrecompileResult,
 };
 use precompile_utils::EvmResult;
-use sp_core::{H160, U256, blake2_256};
+use sp_core::{H160, H256, U256, blake2_256, keccak_256};
 use sp_runtime::traits::Dispatchable;
 use sp_std::vec::Vec;
 
@@ -106,10 +106,38 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
             }
         }
     }
+
+    /// Emits an EVM log from the precompile address and charges its gas cost. The first topic is
+    /// the keccak256 hash of the Solidity event `signature`, followed by the indexed parameters.
+    fn emit_event(&mut self, signature: &[u8], indexed: &[H256], data: Vec<u8>) -> EvmResult<()> {
+        let mut topics = Vec::with_capacity(indexed.len().saturating_add(1));
+        topics.push(H256(keccak_256(signature)));
+        topics.extend_from_slice(indexed);
+
+        // LOG gas costs from the yellow paper: 375 per log and per topic, 8 per byte of data
+        let cost = 375u64
+            .saturating_mul(topics.len().saturating_add(1) as u64)
+            .saturating_add(8u64.saturating_mul(data.len() as u64));
+        self.record_cost(cost)?;
+
+        let address = self.context().address;
+        self.log(address, topics, data)?;
+        Ok(())
+    }
 }
 
 impl<T> PrecompileHandleExt for T where T: PrecompileHandle {}
 
+/// The topic of an indexed `bytes32` account parameter.
+pub(crate) fn account_topic<AccountId: Into<[u8; 32]>>(account: AccountId) -> H256 {
+    H256(account.into())
+}
+
+/// The topic of an indexed integer parameter, e.g. a netuid.
+pub(crate) fn uint_topic(value: u64) -> H256 {
+    H256::from_low_u64_be(value)
+}
+
 pub(crate) trait PrecompileExt<AccountId: From<[u8; 32]>>: Precompile {
     const INDEX: u64;
 
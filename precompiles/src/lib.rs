@@ -20,20 +20,26 @@ use subtensor_runtime_common::ProxyType;
 use pallet_admin_utils::PrecompileEnum;
 
 use crate::balance_transfer::*;
+use crate::commitments::*;
 use crate::ed25519::*;
 use crate::extensions::*;
 use crate::metagraph::*;
 use crate::neuron::*;
+use crate::randomness::*;
 use crate::staking::*;
 use crate::subnet::*;
+use crate::timelock::*;
 
 mod balance_transfer;
+mod commitments;
 mod ed25519;
 mod extensions;
 mod metagraph;
 mod neuron;
+mod randomness;
 mod staking;
 mod subnet;
+mod timelock;
 
 pub struct Precompiles<R>(PhantomData<R>);
 
@@ -44,12 +50,16 @@ where
         + pallet_balances::Config
         + pallet_admin_utils::Config
         + pallet_subtensor::Config
+        + pallet_drand::Config
+        + pallet_commitments::Config
         + pallet_proxy::Config<ProxyType = ProxyType>,
     R::AccountId: From<[u8; 32]> + ByteArray + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
+        + From<pallet_drand::Call<R>>
+        + From<pallet_commitments::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
@@ -68,12 +78,16 @@ where
         + pallet_balances::Config
         + pallet_admin_utils::Config
         + pallet_subtensor::Config
+        + pallet_drand::Config
+        + pallet_commitments::Config
         + pallet_proxy::Config<ProxyType = ProxyType>,
     R::AccountId: From<[u8; 32]> + ByteArray + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
+        + From<pallet_drand::Call<R>>
+        + From<pallet_commitments::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
@@ -84,7 +98,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 14] {
+    pub fn used_addresses() -> [H160; 18] {
         [
             hash(1),
             hash(2),
@@ -100,6 +114,10 @@ where
             hash(MetagraphPrecompile::<R>::INDEX),
             hash(NeuronPrecompile::<R>::INDEX),
             hash(StakingPrecompileV2::<R>::INDEX),
+            hash(StakingPrecompileV3::<R>::INDEX),
+            hash(TimelockPrecompile::<R>::INDEX),
+            hash(RandomnessPrecompile::<R>::INDEX),
+            hash(CommitmentsPrecompile::<R>::INDEX),
         ]
     }
 }
@@ -110,12 +128,16 @@ where
         + pallet_balances::Config
         + pallet_admin_utils::Config
         + pallet_subtensor::Config
+        + pallet_drand::Config
+        + pallet_commitments::Config
         + pallet_proxy::Config<ProxyType = ProxyType>,
     R::AccountId: From<[u8; 32]> + ByteArray + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
+        + From<pallet_drand::Call<R>>
+        + From<pallet_commitments::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
@@ -149,6 +171,9 @@ where
             a if a == hash(StakingPrecompileV2::<R>::INDEX) => {
                 StakingPrecompileV2::<R>::try_execute::<R>(handle, PrecompileEnum::Staking)
             }
+            a if a == hash(StakingPrecompileV3::<R>::INDEX) => {
+                StakingPrecompileV3::<R>::try_execute::<R>(handle, PrecompileEnum::Staking)
+            }
             a if a == hash(SubnetPrecompile::<R>::INDEX) => {
                 SubnetPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Subnet)
             }
@@ -158,6 +183,15 @@ where
             a if a == hash(NeuronPrecompile::<R>::INDEX) => {
                 NeuronPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Neuron)
             }
+            a if a == hash(TimelockPrecompile::<R>::INDEX) => {
+                TimelockPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Timelock)
+            }
+            a if a == hash(RandomnessPrecompile::<R>::INDEX) => {
+                RandomnessPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Randomness)
+            }
+            a if a == hash(CommitmentsPrecompile::<R>::INDEX) => {
+                CommitmentsPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Commitments)
+            }
             _ => None,
         }
     }
@@ -3,19 +3,27 @@ use core::marker::PhantomData;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
 use pallet_evm::{AddressMapping, PrecompileHandle};
-use precompile_utils::{EvmResult, prelude::UnboundedBytes};
+use precompile_utils::{EvmResult, prelude::UnboundedBytes, solidity::encode_event_data};
 use sp_core::H256;
 use sp_runtime::traits::Dispatchable;
 use sp_std::vec::Vec;
 
-use crate::{PrecompileExt, PrecompileHandleExt};
+use crate::{PrecompileExt, PrecompileHandleExt, account_topic, uint_topic};
+
+// The events of the precompile, see `solidity/neuron.sol`.
+const WEIGHTS_SET: &[u8] = b"WeightsSet(uint16,bytes32,uint64)";
+const WEIGHTS_COMMITTED: &[u8] = b"WeightsCommitted(uint16,bytes32,bytes32)";
+const WEIGHTS_REVEALED: &[u8] = b"WeightsRevealed(uint16,bytes32,uint64)";
+const NEURON_REGISTERED: &[u8] = b"NeuronRegistered(uint16,bytes32,bytes32,uint16,uint64)";
+const AXON_SERVED: &[u8] = b"AxonServed(uint16,bytes32,uint128,uint16)";
+const PROMETHEUS_SERVED: &[u8] = b"PrometheusServed(uint16,bytes32,uint128,uint16)";
 
 pub struct NeuronPrecompile<R>(PhantomData<R>);
 
 impl<R> PrecompileExt<R::AccountId> for NeuronPrecompile<R>
 where
     R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
@@ -28,7 +36,7 @@ where
 impl<R> NeuronPrecompile<R>
 where
     R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
@@ -50,9 +58,13 @@ where
             version_key,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        let hotkey = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(hotkey.clone()))?;
+
+        handle.emit_event(
+            WEIGHTS_SET,
+            &[uint_topic(netuid.into()), account_topic(hotkey)],
+            encode_event_data(version_key),
         )
     }
 
@@ -68,9 +80,13 @@ where
             commit_hash,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        let hotkey = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(hotkey.clone()))?;
+
+        handle.emit_event(
+            WEIGHTS_COMMITTED,
+            &[uint_topic(netuid.into()), account_topic(hotkey)],
+            encode_event_data(commit_hash),
         )
     }
 
@@ -92,9 +108,13 @@ where
             version_key,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        let hotkey = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(hotkey.clone()))?;
+
+        handle.emit_event(
+            WEIGHTS_REVEALED,
+            &[uint_topic(netuid.into()), account_topic(hotkey)],
+            encode_event_data(version_key),
         )
     }
 
@@ -107,9 +127,27 @@ where
     ) -> EvmResult<()> {
         let coldkey = handle.caller_account_id::<R>();
         let hotkey = R::AccountId::from(hotkey.0);
-        let call = pallet_subtensor::Call::<R>::burned_register { netuid, hotkey };
+        let call = pallet_subtensor::Call::<R>::burned_register {
+            netuid,
+            hotkey: hotkey.clone(),
+        };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey))
+        let balance_before = pallet_subtensor::Pallet::<R>::get_coldkey_balance(&coldkey);
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey.clone()))?;
+        let burned = balance_before
+            .saturating_sub(pallet_subtensor::Pallet::<R>::get_coldkey_balance(&coldkey));
+        let uid = pallet_subtensor::Pallet::<R>::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .unwrap_or_default();
+
+        handle.emit_event(
+            NEURON_REGISTERED,
+            &[
+                uint_topic(netuid.into()),
+                account_topic(hotkey),
+                account_topic(coldkey),
+            ],
+            encode_event_data((uid, burned)),
+        )
     }
 
     #[precompile::public("serveAxon(uint16,uint32,uint128,uint16,uint8,uint8,uint8,uint8)")]
@@ -137,9 +175,13 @@ where
             placeholder2,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        let hotkey = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(hotkey.clone()))?;
+
+        handle.emit_event(
+            AXON_SERVED,
+            &[uint_topic(netuid.into()), account_topic(hotkey)],
+            encode_event_data((ip, port)),
         )
     }
 
@@ -172,9 +214,13 @@ where
             certificate: certificate.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        let hotkey = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(hotkey.clone()))?;
+
+        handle.emit_event(
+            AXON_SERVED,
+            &[uint_topic(netuid.into()), account_topic(hotkey)],
+            encode_event_data((ip, port)),
         )
     }
 
@@ -197,9 +243,13 @@ where
             ip_type,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        let hotkey = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(hotkey.clone()))?;
+
+        handle.emit_event(
+            PROMETHEUS_SERVED,
+            &[uint_topic(netuid.into()), account_topic(hotkey)],
+            encode_event_data((ip, port)),
         )
     }
 }
@@ -0,0 +1,55 @@
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::EvmResult;
+use sp_core::H256;
+
+use crate::PrecompileExt;
+
+pub struct RandomnessPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for RandomnessPrecompile<R>
+where
+    R: frame_system::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    const INDEX: u64 = 2055;
+}
+
+#[precompile_utils::precompile]
+impl<R> RandomnessPrecompile<R>
+where
+    R: frame_system::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    #[precompile::public("getRandomness(uint64)")]
+    #[precompile::view]
+    fn get_randomness(_: &mut impl PrecompileHandle, round: u64) -> EvmResult<(u64, H256)> {
+        Self::randomness_of(Some(round))
+    }
+
+    #[precompile::public("getLatestRandomness()")]
+    #[precompile::view]
+    fn get_latest_randomness(_: &mut impl PrecompileHandle) -> EvmResult<(u64, H256)> {
+        Self::randomness_of(None)
+    }
+
+    #[precompile::public("getLatestRound()")]
+    #[precompile::view]
+    fn get_latest_round(_: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        Ok(pallet_drand::LastStoredRound::<R>::get())
+    }
+}
+
+impl<R> RandomnessPrecompile<R>
+where
+    R: frame_system::Config + pallet_drand::Config,
+{
+    fn randomness_of(round: Option<u64>) -> EvmResult<(u64, H256)> {
+        pallet_drand::Pallet::<R>::randomness_of(round)
+            .map(|(round, randomness)| (round, H256::from(randomness)))
+            .ok_or(PrecompileFailure::Error {
+                exit_status: ExitError::Other("pulse not found".into()),
+            })
+    }
+}
@@ -0,0 +1,70 @@
+pragma solidity ^0.8.0;
+
+address constant ICommitments_ADDRESS = 0x0000000000000000000000000000000000000808;
+
+/**
+ * @dev A commitment field. `kind` is 0 for an empty field, 1 for raw bytes (at most 128),
+ * 2 for a BlakeTwo256 hash, 3 for a Sha256 hash, 4 for a Keccak256 hash, 5 for a Sha3-256 hash
+ * (32 bytes each) and 6 for a ciphertext timelock encrypted to the drand round `revealRound`.
+ * `revealRound` is ignored by the other kinds.
+ */
+struct CommitmentField {
+    uint8 kind;
+    bytes data;
+    uint64 revealRound;
+}
+
+interface ICommitments {
+    /**
+     * @dev Sets the commitment of the caller on a subnet, with the origin set to the ss58 mirror
+     * of the H160 address, which has to be a hotkey registered on the subnet.
+     *
+     * @param netuid The subnet of the commitment (uint16).
+     * @param fields The fields of the commitment (CommitmentField[]).
+     */
+    function setCommitment(
+        uint16 netuid,
+        CommitmentField[] memory fields
+    ) external payable;
+
+    /**
+     * @dev Clears the commitment of the caller on a subnet and returns its deposit.
+     *
+     * @param netuid The subnet of the commitment (uint16).
+     */
+    function clearCommitment(uint16 netuid) external payable;
+
+    /**
+     * @dev Returns the commitment of an account on a subnet.
+     *
+     * @param netuid The subnet of the commitment (uint16).
+     * @param account The ss58 public key of the account (bytes32).
+     * @return block The block the commitment was set at, 0 if there is none.
+     * @return deposit The deposit held for the commitment.
+     * @return fields The fields of the commitment.
+     */
+    function getCommitment(
+        uint16 netuid,
+        bytes32 account
+    )
+        external
+        view
+        returns (uint64 block, uint64 deposit, CommitmentField[] memory fields);
+
+    /**
+     * @dev Returns the timelocked commitments of an account on a subnet that have been revealed.
+     *
+     * @param netuid The subnet of the commitments (uint16).
+     * @param account The ss58 public key of the account (bytes32).
+     * @return The decrypted commitments, each with the block it was revealed at, oldest first.
+     */
+    function getRevealedCommitments(
+        uint16 netuid,
+        bytes32 account
+    ) external view returns (RevealedCommitment[] memory);
+}
+
+struct RevealedCommitment {
+    bytes data;
+    uint64 revealedBlock;
+}
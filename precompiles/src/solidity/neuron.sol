@@ -3,6 +3,64 @@ pragma solidity ^0.8.0;
 address constant INeuron_ADDRESS = 0x0000000000000000000000000000000000000804;
 
 interface INeuron {
+    /**
+     * @dev Emitted when `hotkey` sets its weights on `netuid`.
+     */
+    event WeightsSet(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        uint64 versionKey
+    );
+
+    /**
+     * @dev Emitted when `hotkey` commits the hash of its weights on `netuid`.
+     */
+    event WeightsCommitted(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        bytes32 commitHash
+    );
+
+    /**
+     * @dev Emitted when `hotkey` reveals its committed weights on `netuid`.
+     */
+    event WeightsRevealed(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        uint64 versionKey
+    );
+
+    /**
+     * @dev Emitted when `coldkey` registers `hotkey` on `netuid` as `uid`, burning `burned` rao.
+     */
+    event NeuronRegistered(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        bytes32 indexed coldkey,
+        uint16 uid,
+        uint64 burned
+    );
+
+    /**
+     * @dev Emitted when `hotkey` serves its axon on `netuid`, with or without TLS.
+     */
+    event AxonServed(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        uint128 ip,
+        uint16 port
+    );
+
+    /**
+     * @dev Emitted when `hotkey` serves its prometheus endpoint on `netuid`.
+     */
+    event PrometheusServed(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        uint128 ip,
+        uint16 port
+    );
+
     /**
      * @dev Registers a neuron by calling `do_burned_registration` internally with the origin set to the ss58 mirror of the H160 address.
      * This allows the H160 to further call neuron-related methods and receive emissions.
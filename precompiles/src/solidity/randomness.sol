@@ -0,0 +1,35 @@
+pragma solidity ^0.8.0;
+
+address constant IRandomness_ADDRESS = 0x0000000000000000000000000000000000000807;
+
+interface IRandomness {
+    /**
+     * @dev Returns the drand randomness of a round, reverting if its pulse is not stored.
+     * The pulse and its signature are available from the `drand_getPulse` rpc to verify it off-chain.
+     *
+     * @param round The drand round (uint64).
+     * @return The drand round.
+     * @return randomness The sha256 hash of the round's signature.
+     */
+    function getRandomness(
+        uint64 round
+    ) external view returns (uint64, bytes32 randomness);
+
+    /**
+     * @dev Returns the randomness of the last stored drand round, reverting if no pulse is stored.
+     *
+     * @return round The last stored drand round.
+     * @return randomness The sha256 hash of the round's signature.
+     */
+    function getLatestRandomness()
+        external
+        view
+        returns (uint64 round, bytes32 randomness);
+
+    /**
+     * @dev Returns the last stored drand round, 0 if no pulse is stored.
+     *
+     * @return The last stored drand round.
+     */
+    function getLatestRound() external view returns (uint64);
+}
@@ -3,6 +3,64 @@ pragma solidity ^0.8.0;
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000805;
 
 interface IStaking {
+    /**
+     * @dev Emitted when `coldkey` stakes `tao` rao to `hotkey` on `netuid` and receives `alpha`.
+     */
+    event StakeAdded(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 tao,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` unstakes `alpha` from `hotkey` on `netuid` and receives `tao` rao.
+     */
+    event StakeRemoved(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 tao,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` moves `alpha` of its stake from `originHotkey` on
+     * `originNetuid` to `destinationHotkey` on `destinationNetuid`.
+     */
+    event StakeMoved(
+        bytes32 indexed coldkey,
+        bytes32 indexed originHotkey,
+        bytes32 indexed destinationHotkey,
+        uint256 originNetuid,
+        uint256 destinationNetuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` transfers `alpha` of its stake with `hotkey` on `originNetuid`
+     * to `destinationColdkey` on `destinationNetuid`.
+     */
+    event StakeTransferred(
+        bytes32 indexed coldkey,
+        bytes32 indexed destinationColdkey,
+        bytes32 indexed hotkey,
+        uint256 originNetuid,
+        uint256 destinationNetuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `account` allows `delegate` to stake on its behalf.
+     */
+    event StakingProxyAdded(bytes32 indexed account, bytes32 indexed delegate);
+
+    /**
+     * @dev Emitted when `account` revokes the staking proxy of `delegate`.
+     */
+    event StakingProxyRemoved(bytes32 indexed account, bytes32 indexed delegate);
+
     /**
      * @dev Adds a subtensor stake `amount` associated with the `hotkey`.
      *
@@ -0,0 +1,639 @@
+pragma solidity ^0.8.0;
+
+address constant ISTAKING_V3_ADDRESS = 0x0000000000000000000000000000000000000809;
+
+/**
+ * @dev A superset of IStaking at 0x805 (stakingV2.sol). Limit prices are in rao per alpha,
+ * e.g. 1_000_000_000 for a price of 1.0.
+ */
+struct ChildKey {
+    uint64 proportion;
+    bytes32 child;
+}
+
+interface IStakingV3 {
+    /**
+     * @dev Emitted when `coldkey` stakes `tao` rao to `hotkey` on `netuid` and receives `alpha`.
+     */
+    event StakeAdded(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 tao,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` unstakes `alpha` from `hotkey` on `netuid` and receives `tao` rao.
+     */
+    event StakeRemoved(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 tao,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` moves `alpha` of its stake from `originHotkey` on
+     * `originNetuid` to `destinationHotkey` on `destinationNetuid`.
+     */
+    event StakeMoved(
+        bytes32 indexed coldkey,
+        bytes32 indexed originHotkey,
+        bytes32 indexed destinationHotkey,
+        uint256 originNetuid,
+        uint256 destinationNetuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` transfers `alpha` of its stake with `hotkey` on `originNetuid`
+     * to `destinationColdkey` on `destinationNetuid`.
+     */
+    event StakeTransferred(
+        bytes32 indexed coldkey,
+        bytes32 indexed destinationColdkey,
+        bytes32 indexed hotkey,
+        uint256 originNetuid,
+        uint256 destinationNetuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `account` allows `delegate` to stake on its behalf.
+     */
+    event StakingProxyAdded(bytes32 indexed account, bytes32 indexed delegate);
+
+    /**
+     * @dev Emitted when `account` revokes the staking proxy of `delegate`.
+     */
+    event StakingProxyRemoved(bytes32 indexed account, bytes32 indexed delegate);
+
+    /**
+     * @dev Emitted when `coldkey` swaps `alpha` of its stake with `hotkey` from `originNetuid`
+     * to `destinationNetuid`.
+     */
+    event StakeSwapped(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 originNetuid,
+        uint256 destinationNetuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` unstakes everything from `hotkey` and receives `tao` rao.
+     */
+    event AllStakeRemoved(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 tao
+    );
+
+    /**
+     * @dev Emitted when `coldkey` unstakes all its alpha from `hotkey` into `rootAlpha` of stake
+     * on the root subnet.
+     */
+    event AllAlphaUnstaked(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 rootAlpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` recycles `alpha` of its stake with `hotkey` on `netuid`.
+     */
+    event AlphaRecycled(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `coldkey` burns `alpha` of its stake with `hotkey` on `netuid`.
+     */
+    event AlphaBurned(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 alpha
+    );
+
+    /**
+     * @dev Emitted when `count` children of `hotkey` on `netuid` are scheduled to be set.
+     */
+    event ChildrenSet(
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint256 count
+    );
+
+    /**
+     * @dev Emitted when the childkey take of `hotkey` on `netuid` is set to `take`.
+     */
+    event ChildkeyTakeSet(
+        bytes32 indexed hotkey,
+        uint256 indexed netuid,
+        uint16 take
+    );
+
+    /**
+     * @dev Adds a subtensor stake `amount` associated with the `hotkey`.
+     *
+     * This function allows external accounts and contracts to stake TAO into the subtensor pallet,
+     * which effectively calls `add_stake` on the subtensor pallet with specified hotkey as a parameter
+     * and coldkey being the hashed address mapping of H160 sender address to Substrate ss58 address as
+     * implemented in Frontier HashedAddressMapping:
+     * https://github.com/polkadot-evm/frontier/blob/2e219e17a526125da003e64ef22ec037917083fa/frame/evm/src/lib.rs#L739
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to stake in rao.
+     * @param netuid The subnet to stake to (uint256).
+     *
+     * Requirements:
+     * - `hotkey` must be a valid hotkey registered on the network, ensuring that the stake is
+     *   correctly attributed.
+     */
+    function addStake(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 netuid
+    ) external payable;
+
+    /**
+     * @dev Removes a subtensor stake `amount` from the specified `hotkey`.
+     *
+     * This function allows external accounts and contracts to unstake TAO from the subtensor pallet,
+     * which effectively calls `remove_stake` on the subtensor pallet with specified hotkey as a parameter
+     * and coldkey being the hashed address mapping of H160 sender address to Substrate ss58 address as
+     * implemented in Frontier HashedAddressMapping:
+     * https://github.com/polkadot-evm/frontier/blob/2e219e17a526125da003e64ef22ec037917083fa/frame/evm/src/lib.rs#L739
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to unstake in alpha.
+     * @param netuid The subnet to stake to (uint256).
+     *
+     * Requirements:
+     * - `hotkey` must be a valid hotkey registered on the network, ensuring that the stake is
+     *   correctly attributed.
+     * - The existing stake amount must be not lower than specified amount
+     */
+    function removeStake(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 netuid
+    ) external;
+
+    /**
+     * @dev Moves a subtensor stake `amount` associated with the `hotkey` to a different hotkey 
+     * `destination_hotkey`.
+     *
+     * This function allows external accounts and contracts to move staked TAO from one hotkey to another,
+     * which effectively calls `move_stake` on the subtensor pallet with specified origin and destination 
+     * hotkeys as parameters being the hashed address mappings of H160 sender address to Substrate ss58 
+     * address as implemented in Frontier HashedAddressMapping:
+     * https://github.com/polkadot-evm/frontier/blob/2e219e17a526125da003e64ef22ec037917083fa/frame/evm/src/lib.rs#L739
+     *
+     * @param origin_hotkey The origin hotkey public key (32 bytes).
+     * @param destination_hotkey The destination hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in rao.
+     *
+     * Requirements:
+     * - `origin_hotkey` and `destination_hotkey` must be valid hotkeys registered on the network, ensuring 
+     * that the stake is correctly attributed.
+     */
+    function moveStake(
+        bytes32 origin_hotkey,
+        bytes32 destination_hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external;
+
+    /**
+     * @dev Transfer a subtensor stake `amount` associated with the transaction signer to a different coldkey 
+     * `destination_coldkey`.
+     *
+     * This function allows external accounts and contracts to transfer staked TAO to another coldkey,
+     * which effectively calls `transfer_stake` on the subtensor pallet with specified destination 
+     * coldkey as a parameter being the hashed address mapping of H160 sender address to Substrate ss58 
+     * address as implemented in Frontier HashedAddressMapping:
+     * https://github.com/polkadot-evm/frontier/blob/2e219e17a526125da003e64ef22ec037917083fa/frame/evm/src/lib.rs#L739
+     *
+     * @param destination_coldkey The destination coldkey public key (32 bytes).
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in rao.
+     *
+     * Requirements:
+     * - `origin_hotkey` and `destination_hotkey` must be valid hotkeys registered on the network, ensuring 
+     * that the stake is correctly attributed.
+     */
+    function transferStake(
+        bytes32 destination_coldkey,
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external;
+
+    /**
+     * @dev Returns the amount of RAO staked by the coldkey.
+     *
+     * This function allows external accounts and contracts to query the amount of RAO staked by the coldkey
+     * which effectively calls `get_total_coldkey_stake` on the subtensor pallet with
+     * specified coldkey as a parameter.
+     *
+     * @param coldkey The coldkey public key (32 bytes).
+     * @return The amount of RAO staked by the coldkey.
+     */
+    function getTotalColdkeyStake(
+        bytes32 coldkey
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the total amount of stake under a hotkey (delegative or otherwise)
+     *
+     * This function allows external accounts and contracts to query the total amount of RAO staked under a hotkey
+     * which effectively calls `get_total_hotkey_stake` on the subtensor pallet with
+     * specified hotkey as a parameter.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @return The total amount of RAO staked under the hotkey.
+     */
+    function getTotalHotkeyStake(
+        bytes32 hotkey
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the stake amount associated with the specified `hotkey` and `coldkey`.
+     *
+     * This function retrieves the current stake amount linked to a specific hotkey and coldkey pair.
+     * It is a view function, meaning it does not modify the state of the contract and is free to call.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param coldkey The coldkey public key (32 bytes).
+     * @param netuid The subnet the stake is on (uint256).
+     * @return The current stake amount in uint256 format.
+     */
+    function getStake(
+        bytes32 hotkey,
+        bytes32 coldkey,
+        uint256 netuid
+    ) external view returns (uint256);
+
+    /**
+     * @dev Delegates staking to a proxy account.
+     *
+     * @param delegate The public key (32 bytes) of the delegate.
+     */
+    function addProxy(bytes32 delegate) external;
+
+    /**
+     * @dev Removes staking proxy account.
+     *
+     * @param delegate The public key (32 bytes) of the delegate.
+     */
+    function removeProxy(bytes32 delegate) external;
+
+    /**
+     * @dev Returns the validators that have staked alpha under a hotkey.
+     *
+     * This function retrieves the validators that have staked alpha under a specific hotkey.
+     * It is a view function, meaning it does not modify the state of the contract and is free to call.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet the stake is on (uint256).
+     * @return An array of validators that have staked alpha under the hotkey.
+     */
+    function getAlphaStakedValidators(
+        bytes32 hotkey,
+        uint256 netuid
+    ) external view returns (uint256[] memory);
+
+    /**
+     * @dev Returns the total amount of alpha staked under a hotkey.
+     *
+     * This function retrieves the total amount of alpha staked under a specific hotkey.
+     * It is a view function, meaning it does not modify the state of the contract and is free to call.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet the stake is on (uint256).
+     * @return The total amount of alpha staked under the hotkey.
+     */
+    function getTotalAlphaStaked(
+        bytes32 hotkey,
+        uint256 netuid
+    ) external view returns (uint256);
+
+    /**
+     * @dev Adds stake like `addStake`, but only as long as the alpha price stays at or below
+     * `limit_price`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to stake in rao.
+     * @param limit_price The highest alpha price to stake at, in rao per alpha.
+     * @param allow_partial Whether to stake the part of `amount` that can be staked within the limit,
+     * instead of failing if all of it cannot.
+     * @param netuid The subnet to stake to (uint256).
+     */
+    function addStakeLimit(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 limit_price,
+        bool allow_partial,
+        uint256 netuid
+    ) external payable;
+
+    /**
+     * @dev Removes stake like `removeStake`, but only as long as the alpha price stays at or above
+     * `limit_price`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to unstake in alpha.
+     * @param limit_price The lowest alpha price to unstake at, in rao per alpha.
+     * @param allow_partial Whether to unstake the part of `amount` that can be unstaked within the
+     * limit, instead of failing if all of it cannot.
+     * @param netuid The subnet to unstake from (uint256).
+     */
+    function removeStakeLimit(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 limit_price,
+        bool allow_partial,
+        uint256 netuid
+    ) external;
+
+    /**
+     * @dev Moves stake of the caller under `hotkey` from one subnet to another, which effectively
+     * calls `swap_stake` on the subtensor pallet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in alpha.
+     */
+    function swapStake(
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external;
+
+    /**
+     * @dev Swaps stake like `swapStake`, but only as long as the price of the origin alpha in
+     * destination alpha stays at or above `limit_price`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in alpha.
+     * @param limit_price The lowest price to swap at, in rao per alpha.
+     * @param allow_partial Whether to swap the part of `amount` that can be swapped within the limit,
+     * instead of failing if all of it cannot.
+     */
+    function swapStakeLimit(
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount,
+        uint256 limit_price,
+        bool allow_partial
+    ) external;
+
+    /**
+     * @dev Unstakes all the stake of the caller under `hotkey` on every subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     */
+    function unstakeAll(bytes32 hotkey) external;
+
+    /**
+     * @dev Unstakes all the alpha of the caller under `hotkey` and stakes it on the root subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     */
+    function unstakeAllAlpha(bytes32 hotkey) external;
+
+    /**
+     * @dev Recycles alpha of the caller under `hotkey`, removing it from the supply of the subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to recycle in alpha.
+     * @param netuid The subnet of the alpha (uint256).
+     */
+    function recycleAlpha(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 netuid
+    ) external;
+
+    /**
+     * @dev Burns alpha of the caller under `hotkey`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param amount The amount to burn in alpha.
+     * @param netuid The subnet of the alpha (uint256).
+     */
+    function burnAlpha(
+        bytes32 hotkey,
+        uint256 amount,
+        uint256 netuid
+    ) external;
+
+    /**
+     * @dev Schedules the children of a hotkey owned by the caller.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet of the children (uint256).
+     * @param children The children with the proportions of the stake they receive, out of u64::MAX.
+     */
+    function setChildren(
+        bytes32 hotkey,
+        uint256 netuid,
+        ChildKey[] memory children
+    ) external;
+
+    /**
+     * @dev Sets the take of a hotkey owned by the caller as a childkey.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet of the take (uint256).
+     * @param take The take, out of u16::MAX.
+     */
+    function setChildkeyTake(
+        bytes32 hotkey,
+        uint256 netuid,
+        uint16 take
+    ) external;
+
+    /**
+     * @dev Returns the price of alpha on a subnet.
+     *
+     * @param netuid The subnet (uint256).
+     * @return The price in rao per alpha.
+     */
+    function getAlphaPrice(uint256 netuid) external view returns (uint256);
+
+    /**
+     * @dev Returns the fee charged for staking `amount` rao to `hotkey`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param coldkey The coldkey public key (32 bytes).
+     * @param netuid The subnet to stake to (uint256).
+     * @param amount The amount to stake in rao.
+     * @return The fee in rao.
+     */
+    function getAddStakeFee(
+        bytes32 hotkey,
+        bytes32 coldkey,
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the fee charged for unstaking `amount` alpha from `hotkey`.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param coldkey The coldkey public key (32 bytes).
+     * @param netuid The subnet to unstake from (uint256).
+     * @param amount The amount to unstake in alpha.
+     * @return The fee in rao.
+     */
+    function getRemoveStakeFee(
+        bytes32 hotkey,
+        bytes32 coldkey,
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the fee charged for moving, transferring or swapping `amount` alpha.
+     *
+     * @param origin_hotkey The origin hotkey public key (32 bytes).
+     * @param origin_coldkey The origin coldkey public key (32 bytes).
+     * @param destination_hotkey The destination hotkey public key (32 bytes).
+     * @param destination_coldkey The destination coldkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in alpha.
+     * @return The fee in rao.
+     */
+    function getMoveStakeFee(
+        bytes32 origin_hotkey,
+        bytes32 origin_coldkey,
+        bytes32 destination_hotkey,
+        bytes32 destination_coldkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the alpha received for staking `amount` rao at the current reserves, before fees.
+     * Reverts if the pool does not have enough liquidity.
+     *
+     * @param netuid The subnet (uint256).
+     * @param amount The amount of TAO in rao.
+     * @return The amount of alpha.
+     */
+    function simSwapTaoForAlpha(
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the rao received for unstaking `amount` alpha at the current reserves, before fees.
+     * Reverts if the pool does not have enough liquidity.
+     *
+     * @param netuid The subnet (uint256).
+     * @param amount The amount of alpha.
+     * @return The amount of TAO in rao.
+     */
+    function simSwapAlphaForTao(
+        uint256 netuid,
+        uint256 amount
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the largest amount that `addStakeLimit` can stake without the price rising above
+     * `limit_price`.
+     *
+     * @param netuid The subnet (uint256).
+     * @param limit_price The limit price in rao per alpha.
+     * @return The amount in rao.
+     */
+    function getMaxAmountAdd(
+        uint256 netuid,
+        uint256 limit_price
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the largest amount that `removeStakeLimit` can unstake without the price falling
+     * below `limit_price`.
+     *
+     * @param netuid The subnet (uint256).
+     * @param limit_price The limit price in rao per alpha.
+     * @return The amount in alpha.
+     */
+    function getMaxAmountRemove(
+        uint256 netuid,
+        uint256 limit_price
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the largest amount that `swapStakeLimit` can swap without the price falling below
+     * `limit_price`.
+     *
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param limit_price The limit price in rao per alpha.
+     * @return The amount in alpha.
+     */
+    function getMaxAmountMove(
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 limit_price
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the children of a hotkey.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet (uint256).
+     * @return The children with their proportions.
+     */
+    function getChildren(
+        bytes32 hotkey,
+        uint256 netuid
+    ) external view returns (ChildKey[] memory);
+
+    /**
+     * @dev Returns the parents of a hotkey.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet (uint256).
+     * @return The parents with their proportions, in the `child` field.
+     */
+    function getParents(
+        bytes32 hotkey,
+        uint256 netuid
+    ) external view returns (ChildKey[] memory);
+
+    /**
+     * @dev Returns the childkey take of a hotkey.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuid The subnet (uint256).
+     * @return The take, out of u16::MAX.
+     */
+    function getChildkeyTake(
+        bytes32 hotkey,
+        uint256 netuid
+    ) external view returns (uint16);
+}
@@ -3,6 +3,26 @@ pragma solidity ^0.8.0;
 address constant ISUBNET_ADDRESS = 0x0000000000000000000000000000000000000803;
 
 interface ISubnet {
+    /// Emitted when `owner` registers the network `netuid` with `hotkey`.
+    event NetworkRegistered(
+        uint16 indexed netuid,
+        bytes32 indexed owner,
+        bytes32 indexed hotkey
+    );
+    /// Emitted when a hyperparameter of `netuid` is set to `value` by the owner. `setter` is the
+    /// selector of the setter called, e.g. `ISubnet.setKappa.selector`, and booleans are 0 or 1.
+    event HyperparameterSet(
+        uint16 indexed netuid,
+        bytes4 indexed setter,
+        uint256 value
+    );
+    /// Emitted when the liquid alpha bounds of `netuid` are set.
+    event AlphaValuesSet(
+        uint16 indexed netuid,
+        uint16 alphaLow,
+        uint16 alphaHigh
+    );
+
     /// Registers a new network without specifying details.
     function registerNetwork(bytes32 hotkey) external payable;
     /// Registers a new network with specified subnet name, GitHub repository, and contact information.
@@ -17,6 +37,12 @@ interface ISubnet {
         string memory additional
     ) external payable;
 
+    /// Returns the time-weighted average alpha price, in rao per alpha, over the last `window` blocks.
+    function getAlphaTwap(
+        uint16 netuid,
+        uint64 window
+    ) external view returns (uint64);
+
     function getServingRateLimit(uint16 netuid) external view returns (uint64);
 
     function setServingRateLimit(
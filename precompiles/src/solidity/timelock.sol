@@ -0,0 +1,48 @@
+pragma solidity ^0.8.0;
+
+address constant ITimelock_ADDRESS = 0x0000000000000000000000000000000000000806;
+
+interface ITimelock {
+    /**
+     * @dev Submits a ciphertext timelock encrypted to a drand quicknet round. The chain decrypts it
+     * once the pulse of that round is stored, with the origin set to the ss58 mirror of the H160 address as its owner.
+     *
+     * @param ciphertext The serialized `TLECiphertext<TinyBLS381>`, at most 4096 bytes (bytes).
+     * @param revealRound The drand round the ciphertext was encrypted to (uint64).
+     * @return The id of the submitted timelock.
+     */
+    function submitTimelock(
+        bytes memory ciphertext,
+        uint64 revealRound
+    ) external payable returns (uint64);
+
+    /**
+     * @dev Removes a pending or revealed timelock owned by the caller.
+     *
+     * @param id The id of the timelock (uint64).
+     */
+    function removeTimelock(uint64 id) external payable;
+
+    /**
+     * @dev Returns a timelock.
+     *
+     * @param id The id of the timelock (uint64).
+     * @return status 0 if unknown or removed, 1 if pending, 2 if revealed and 3 if it could not be decrypted.
+     * @return revealRound The drand round the ciphertext was encrypted to.
+     * @return payload The decrypted payload once revealed, empty otherwise.
+     */
+    function getTimelock(
+        uint64 id
+    )
+        external
+        view
+        returns (uint8 status, uint64 revealRound, bytes memory payload);
+
+    /**
+     * @dev Returns the ids of the pending and revealed timelocks submitted by an account.
+     *
+     * @param owner The ss58 public key of the owner (bytes32).
+     * @return The timelock ids.
+     */
+    function getTimelocksOf(bytes32 owner) external view returns (uint64[] memory);
+}
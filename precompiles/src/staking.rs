@@ -28,23 +28,44 @@
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use pallet_evm::{
-    AddressMapping, BalanceConverter, ExitError, PrecompileFailure, PrecompileHandle,
+    AddressMapping, BalanceConverter, ExitError, GasWeightMapping, PrecompileFailure,
+    PrecompileHandle,
 };
-use precompile_utils::EvmResult;
+use precompile_utils::{EvmResult, solidity::encode_event_data};
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
 use sp_std::vec;
+use substrate_fixed::types::U96F32;
 use subtensor_runtime_common::ProxyType;
 
-use crate::{PrecompileExt, PrecompileHandleExt};
+use crate::{PrecompileExt, PrecompileHandleExt, account_topic, uint_topic};
+
+// The events of StakingPrecompileV2 and StakingPrecompileV3, see `solidity/stakingV2.sol` and
+// `solidity/stakingV3.sol`. Amounts are the ones actually moved, net of fees and slippage.
+const STAKE_ADDED: &[u8] = b"StakeAdded(bytes32,bytes32,uint256,uint256,uint256)";
+const STAKE_REMOVED: &[u8] = b"StakeRemoved(bytes32,bytes32,uint256,uint256,uint256)";
+const STAKE_MOVED: &[u8] = b"StakeMoved(bytes32,bytes32,bytes32,uint256,uint256,uint256)";
+const STAKE_TRANSFERRED: &[u8] =
+    b"StakeTransferred(bytes32,bytes32,bytes32,uint256,uint256,uint256)";
+const STAKE_SWAPPED: &[u8] = b"StakeSwapped(bytes32,bytes32,uint256,uint256,uint256)";
+const ALL_STAKE_REMOVED: &[u8] = b"AllStakeRemoved(bytes32,bytes32,uint256)";
+const ALL_ALPHA_UNSTAKED: &[u8] = b"AllAlphaUnstaked(bytes32,bytes32,uint256)";
+const ALPHA_RECYCLED: &[u8] = b"AlphaRecycled(bytes32,bytes32,uint256,uint256)";
+const ALPHA_BURNED: &[u8] = b"AlphaBurned(bytes32,bytes32,uint256,uint256)";
+const CHILDREN_SET: &[u8] = b"ChildrenSet(bytes32,uint256,uint256)";
+const CHILDKEY_TAKE_SET: &[u8] = b"ChildkeyTakeSet(bytes32,uint256,uint16)";
+const STAKING_PROXY_ADDED: &[u8] = b"StakingProxyAdded(bytes32,bytes32)";
+const STAKING_PROXY_REMOVED: &[u8] = b"StakingProxyRemoved(bytes32,bytes32)";
 
 // Old StakingPrecompile had ETH-precision in values, which was not alligned with Substrate API. So
 // it's kinda deprecated, but exists for backward compatibility. Eventually, we should remove it
 // to stop supporting both precompiles.
 //
-// All the future extensions should happen in StakingPrecompileV2.
+// StakingPrecompileV3 is a superset of StakingPrecompileV2, and all the future extensions should
+// happen in StakingPrecompileV3.
 pub(crate) struct StakingPrecompileV2<R>(PhantomData<R>);
 
 impl<R> PrecompileExt<R::AccountId> for StakingPrecompileV2<R>
@@ -92,12 +113,12 @@ where
         let hotkey = R::AccountId::from(address.0);
         let netuid = try_u16_from_u256(netuid)?;
         let call = pallet_subtensor::Call::<R>::add_stake {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_staked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        Self::dispatch_add_stake(handle, call, account_id, hotkey, netuid)
     }
 
     #[precompile::public("removeStake(bytes32,uint256,uint256)")]
@@ -112,12 +133,12 @@ where
         let netuid = try_u16_from_u256(netuid)?;
         let amount_unstaked = amount_alpha.unique_saturated_into();
         let call = pallet_subtensor::Call::<R>::remove_stake {
-            hotkey,
+            hotkey: hotkey.clone(),
             netuid,
             amount_unstaked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        Self::dispatch_remove_stake(handle, call, account_id, hotkey, netuid)
     }
 
     #[precompile::public("moveStake(bytes32,bytes32,uint256,uint256,uint256)")]
@@ -136,14 +157,34 @@ where
         let destination_netuid = try_u16_from_u256(destination_netuid)?;
         let alpha_amount = amount_alpha.unique_saturated_into();
         let call = pallet_subtensor::Call::<R>::move_stake {
-            origin_hotkey,
-            destination_hotkey,
+            origin_hotkey: origin_hotkey.clone(),
+            destination_hotkey: destination_hotkey.clone(),
             origin_netuid,
             destination_netuid,
             alpha_amount,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        let change = Self::dispatch_tracking_stake(
+            handle,
+            call,
+            &account_id,
+            &origin_hotkey,
+            origin_netuid,
+        )?;
+
+        handle.emit_event(
+            STAKE_MOVED,
+            &[
+                account_topic(account_id),
+                account_topic(origin_hotkey),
+                account_topic(destination_hotkey),
+            ],
+            encode_event_data((
+                U256::from(origin_netuid),
+                U256::from(destination_netuid),
+                U256::from(change.alpha_removed()),
+            )),
+        )
     }
 
     #[precompile::public("transferStake(bytes32,bytes32,uint256,uint256,uint256)")]
@@ -162,14 +203,29 @@ where
         let destination_netuid = try_u16_from_u256(destination_netuid)?;
         let alpha_amount = amount_alpha.unique_saturated_into();
         let call = pallet_subtensor::Call::<R>::transfer_stake {
-            destination_coldkey,
-            hotkey,
+            destination_coldkey: destination_coldkey.clone(),
+            hotkey: hotkey.clone(),
             origin_netuid,
             destination_netuid,
             alpha_amount,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        let change =
+            Self::dispatch_tracking_stake(handle, call, &account_id, &hotkey, origin_netuid)?;
+
+        handle.emit_event(
+            STAKE_TRANSFERRED,
+            &[
+                account_topic(account_id),
+                account_topic(destination_coldkey),
+                account_topic(hotkey),
+            ],
+            encode_event_data((
+                U256::from(origin_netuid),
+                U256::from(destination_netuid),
+                U256::from(change.alpha_removed()),
+            )),
+        )
     }
 
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
@@ -252,6 +308,7 @@ where
     #[precompile::public("addProxy(bytes32)")]
     fn add_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
+        let delegate_topic = delegate;
         let delegate = R::AccountId::from(delegate.0);
         let delegate = <R as frame_system::Config>::Lookup::unlookup(delegate);
         let call = pallet_proxy::Call::<R>::add_proxy {
@@ -260,12 +317,19 @@ where
             delay: 0u32.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        handle.emit_event(
+            STAKING_PROXY_ADDED,
+            &[account_topic(account_id), delegate_topic],
+            Vec::new(),
+        )
     }
 
     #[precompile::public("removeProxy(bytes32)")]
     fn remove_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
         let account_id = handle.caller_account_id::<R>();
+        let delegate_topic = delegate;
         let delegate = R::AccountId::from(delegate.0);
         let delegate = <R as frame_system::Config>::Lookup::unlookup(delegate);
         let call = pallet_proxy::Call::<R>::remove_proxy {
@@ -274,7 +338,839 @@ where
             delay: 0u32.into(),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        handle.emit_event(
+            STAKING_PROXY_REMOVED,
+            &[account_topic(account_id), delegate_topic],
+            Vec::new(),
+        )
+    }
+}
+
+impl<R> StakingPrecompileV2<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    /// Dispatches `call` on behalf of `coldkey` and returns its TAO balance and its stake with
+    /// `hotkey` on `netuid` before and after the call.
+    fn dispatch_tracking_stake(
+        handle: &mut impl PrecompileHandle,
+        call: pallet_subtensor::Call<R>,
+        coldkey: &R::AccountId,
+        hotkey: &R::AccountId,
+        netuid: u16,
+    ) -> EvmResult<StakeChange> {
+        let stake = || {
+            pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+                hotkey, coldkey, netuid,
+            )
+        };
+        let balance = || pallet_subtensor::Pallet::<R>::get_coldkey_balance(coldkey);
+
+        // The stake (Alpha, TotalHotkeyAlpha and TotalHotkeyShares) and the balance are read
+        // before and after the call, on top of the call weight.
+        let reads = <R as frame_system::Config>::DbWeight::get().reads(8);
+        handle.record_cost(<R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+            reads,
+        ))?;
+
+        let (stake_before, balance_before) = (stake(), balance());
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey.clone()))?;
+
+        Ok(StakeChange {
+            stake_before,
+            stake_after: stake(),
+            balance_before,
+            balance_after: balance(),
+        })
+    }
+
+    /// Dispatches a call staking TAO of `coldkey` to `hotkey` and emits `StakeAdded`.
+    fn dispatch_add_stake(
+        handle: &mut impl PrecompileHandle,
+        call: pallet_subtensor::Call<R>,
+        coldkey: R::AccountId,
+        hotkey: R::AccountId,
+        netuid: u16,
+    ) -> EvmResult<()> {
+        let change = Self::dispatch_tracking_stake(handle, call, &coldkey, &hotkey, netuid)?;
+
+        handle.emit_event(
+            STAKE_ADDED,
+            &[
+                account_topic(coldkey),
+                account_topic(hotkey),
+                uint_topic(netuid.into()),
+            ],
+            encode_event_data((
+                U256::from(change.tao_spent()),
+                U256::from(change.alpha_added()),
+            )),
+        )
+    }
+
+    /// Dispatches a call unstaking alpha of `coldkey` from `hotkey` and emits `StakeRemoved`.
+    fn dispatch_remove_stake(
+        handle: &mut impl PrecompileHandle,
+        call: pallet_subtensor::Call<R>,
+        coldkey: R::AccountId,
+        hotkey: R::AccountId,
+        netuid: u16,
+    ) -> EvmResult<()> {
+        let change = Self::dispatch_tracking_stake(handle, call, &coldkey, &hotkey, netuid)?;
+
+        handle.emit_event(
+            STAKE_REMOVED,
+            &[
+                account_topic(coldkey),
+                account_topic(hotkey),
+                uint_topic(netuid.into()),
+            ],
+            encode_event_data((
+                U256::from(change.tao_received()),
+                U256::from(change.alpha_removed()),
+            )),
+        )
+    }
+}
+
+/// The TAO balance of a coldkey and its stake with a hotkey on a subnet, before and after a call.
+struct StakeChange {
+    stake_before: u64,
+    stake_after: u64,
+    balance_before: u64,
+    balance_after: u64,
+}
+
+impl StakeChange {
+    fn alpha_added(&self) -> u64 {
+        self.stake_after.saturating_sub(self.stake_before)
+    }
+
+    fn alpha_removed(&self) -> u64 {
+        self.stake_before.saturating_sub(self.stake_after)
+    }
+
+    fn tao_spent(&self) -> u64 {
+        self.balance_before.saturating_sub(self.balance_after)
+    }
+
+    fn tao_received(&self) -> u64 {
+        self.balance_after.saturating_sub(self.balance_before)
+    }
+}
+
+// Adds the limit orders, stake swaps, unstake-all, alpha recycling and burning and childkey
+// management of the subtensor pallet to StakingPrecompileV2, along with the views needed to
+// place limit orders: the alpha price, the fees and the simulated results of the swaps.
+//
+// Limit prices are in rao per alpha, e.g. 1_000_000_000 for a price of 1.0.
+pub(crate) struct StakingPrecompileV3<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for StakingPrecompileV3<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    const INDEX: u64 = 2057;
+}
+
+#[precompile_utils::precompile]
+impl<R> StakingPrecompileV3<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    #[precompile::public("addStake(bytes32,uint256,uint256)")]
+    #[precompile::payable]
+    fn add_stake(
+        handle: &mut impl PrecompileHandle,
+        address: H256,
+        amount_rao: U256,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        StakingPrecompileV2::<R>::add_stake(handle, address, amount_rao, netuid)
+    }
+
+    #[precompile::public("removeStake(bytes32,uint256,uint256)")]
+    fn remove_stake(
+        handle: &mut impl PrecompileHandle,
+        address: H256,
+        amount_alpha: U256,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        StakingPrecompileV2::<R>::remove_stake(handle, address, amount_alpha, netuid)
+    }
+
+    #[precompile::public("addStakeLimit(bytes32,uint256,uint256,bool,uint256)")]
+    #[precompile::payable]
+    fn add_stake_limit(
+        handle: &mut impl PrecompileHandle,
+        address: H256,
+        amount_rao: U256,
+        limit_price: U256,
+        allow_partial: bool,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(address.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let call = pallet_subtensor::Call::<R>::add_stake_limit {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_staked: amount_rao.unique_saturated_into(),
+            limit_price: limit_price.unique_saturated_into(),
+            allow_partial,
+        };
+
+        StakingPrecompileV2::<R>::dispatch_add_stake(handle, call, account_id, hotkey, netuid)
+    }
+
+    #[precompile::public("removeStakeLimit(bytes32,uint256,uint256,bool,uint256)")]
+    fn remove_stake_limit(
+        handle: &mut impl PrecompileHandle,
+        address: H256,
+        amount_alpha: U256,
+        limit_price: U256,
+        allow_partial: bool,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(address.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let call = pallet_subtensor::Call::<R>::remove_stake_limit {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_unstaked: amount_alpha.unique_saturated_into(),
+            limit_price: limit_price.unique_saturated_into(),
+            allow_partial,
+        };
+
+        StakingPrecompileV2::<R>::dispatch_remove_stake(handle, call, account_id, hotkey, netuid)
+    }
+
+    #[precompile::public("moveStake(bytes32,bytes32,uint256,uint256,uint256)")]
+    fn move_stake(
+        handle: &mut impl PrecompileHandle,
+        origin_hotkey: H256,
+        destination_hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<()> {
+        StakingPrecompileV2::<R>::move_stake(
+            handle,
+            origin_hotkey,
+            destination_hotkey,
+            origin_netuid,
+            destination_netuid,
+            amount_alpha,
+        )
+    }
+
+    #[precompile::public("transferStake(bytes32,bytes32,uint256,uint256,uint256)")]
+    fn transfer_stake(
+        handle: &mut impl PrecompileHandle,
+        destination_coldkey: H256,
+        hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<()> {
+        StakingPrecompileV2::<R>::transfer_stake(
+            handle,
+            destination_coldkey,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+            amount_alpha,
+        )
+    }
+
+    #[precompile::public("swapStake(bytes32,uint256,uint256,uint256)")]
+    fn swap_stake(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let call = pallet_subtensor::Call::<R>::swap_stake {
+            hotkey: hotkey.clone(),
+            origin_netuid,
+            destination_netuid,
+            alpha_amount: amount_alpha.unique_saturated_into(),
+        };
+
+        Self::dispatch_swap_stake(
+            handle,
+            call,
+            account_id,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+        )
+    }
+
+    #[precompile::public("swapStakeLimit(bytes32,uint256,uint256,uint256,uint256,bool)")]
+    fn swap_stake_limit(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+        limit_price: U256,
+        allow_partial: bool,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let call = pallet_subtensor::Call::<R>::swap_stake_limit {
+            hotkey: hotkey.clone(),
+            origin_netuid,
+            destination_netuid,
+            alpha_amount: amount_alpha.unique_saturated_into(),
+            limit_price: limit_price.unique_saturated_into(),
+            allow_partial,
+        };
+
+        Self::dispatch_swap_stake(
+            handle,
+            call,
+            account_id,
+            hotkey,
+            origin_netuid,
+            destination_netuid,
+        )
+    }
+
+    #[precompile::public("unstakeAll(bytes32)")]
+    fn unstake_all(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let call = pallet_subtensor::Call::<R>::unstake_all {
+            hotkey: hotkey.clone(),
+        };
+
+        let root_netuid = pallet_subtensor::Pallet::<R>::get_root_netuid();
+        let change = StakingPrecompileV2::<R>::dispatch_tracking_stake(
+            handle,
+            call,
+            &account_id,
+            &hotkey,
+            root_netuid,
+        )?;
+
+        handle.emit_event(
+            ALL_STAKE_REMOVED,
+            &[account_topic(account_id), account_topic(hotkey)],
+            encode_event_data(U256::from(change.tao_received())),
+        )
+    }
+
+    #[precompile::public("unstakeAllAlpha(bytes32)")]
+    fn unstake_all_alpha(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let call = pallet_subtensor::Call::<R>::unstake_all_alpha {
+            hotkey: hotkey.clone(),
+        };
+
+        let root_netuid = pallet_subtensor::Pallet::<R>::get_root_netuid();
+        let change = StakingPrecompileV2::<R>::dispatch_tracking_stake(
+            handle,
+            call,
+            &account_id,
+            &hotkey,
+            root_netuid,
+        )?;
+
+        handle.emit_event(
+            ALL_ALPHA_UNSTAKED,
+            &[account_topic(account_id), account_topic(hotkey)],
+            encode_event_data(U256::from(change.alpha_added())),
+        )
+    }
+
+    #[precompile::public("recycleAlpha(bytes32,uint256,uint256)")]
+    fn recycle_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        amount_alpha: U256,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let call = pallet_subtensor::Call::<R>::recycle_alpha {
+            hotkey: hotkey.clone(),
+            amount: amount_alpha.unique_saturated_into(),
+            netuid,
+        };
+
+        let change = StakingPrecompileV2::<R>::dispatch_tracking_stake(
+            handle,
+            call,
+            &account_id,
+            &hotkey,
+            netuid,
+        )?;
+
+        handle.emit_event(
+            ALPHA_RECYCLED,
+            &[
+                account_topic(account_id),
+                account_topic(hotkey),
+                uint_topic(netuid.into()),
+            ],
+            encode_event_data(U256::from(change.alpha_removed())),
+        )
+    }
+
+    #[precompile::public("burnAlpha(bytes32,uint256,uint256)")]
+    fn burn_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        amount_alpha: U256,
+        netuid: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let call = pallet_subtensor::Call::<R>::burn_alpha {
+            hotkey: hotkey.clone(),
+            amount: amount_alpha.unique_saturated_into(),
+            netuid,
+        };
+
+        let change = StakingPrecompileV2::<R>::dispatch_tracking_stake(
+            handle,
+            call,
+            &account_id,
+            &hotkey,
+            netuid,
+        )?;
+
+        handle.emit_event(
+            ALPHA_BURNED,
+            &[
+                account_topic(account_id),
+                account_topic(hotkey),
+                uint_topic(netuid.into()),
+            ],
+            encode_event_data(U256::from(change.alpha_removed())),
+        )
+    }
+
+    #[precompile::public("setChildren(bytes32,uint256,(uint64,bytes32)[])")]
+    fn set_children(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+        children: Vec<(u64, H256)>,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let count = children.len();
+        let children = children
+            .into_iter()
+            .map(|(proportion, child)| (proportion, R::AccountId::from(child.0)))
+            .collect();
+        let call = pallet_subtensor::Call::<R>::set_children {
+            hotkey: hotkey.clone(),
+            netuid,
+            children,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        handle.emit_event(
+            CHILDREN_SET,
+            &[account_topic(hotkey), uint_topic(netuid.into())],
+            encode_event_data(U256::from(count)),
+        )
+    }
+
+    #[precompile::public("setChildkeyTake(bytes32,uint256,uint16)")]
+    fn set_childkey_take(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+        take: u16,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let call = pallet_subtensor::Call::<R>::set_childkey_take {
+            hotkey: hotkey.clone(),
+            netuid,
+            take,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        handle.emit_event(
+            CHILDKEY_TAKE_SET,
+            &[account_topic(hotkey), uint_topic(netuid.into())],
+            encode_event_data(take),
+        )
+    }
+
+    #[precompile::public("getTotalColdkeyStake(bytes32)")]
+    #[precompile::view]
+    fn get_total_coldkey_stake(
+        handle: &mut impl PrecompileHandle,
+        coldkey: H256,
+    ) -> EvmResult<U256> {
+        StakingPrecompileV2::<R>::get_total_coldkey_stake(handle, coldkey)
+    }
+
+    #[precompile::public("getTotalHotkeyStake(bytes32)")]
+    #[precompile::view]
+    fn get_total_hotkey_stake(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<U256> {
+        StakingPrecompileV2::<R>::get_total_hotkey_stake(handle, hotkey)
+    }
+
+    #[precompile::public("getStake(bytes32,bytes32,uint256)")]
+    #[precompile::view]
+    fn get_stake(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        coldkey: H256,
+        netuid: U256,
+    ) -> EvmResult<U256> {
+        StakingPrecompileV2::<R>::get_stake(handle, hotkey, coldkey, netuid)
+    }
+
+    #[precompile::public("getAlphaStakedValidators(bytes32,uint256)")]
+    #[precompile::view]
+    fn get_alpha_staked_validators(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+    ) -> EvmResult<Vec<H256>> {
+        StakingPrecompileV2::<R>::get_alpha_staked_validators(handle, hotkey, netuid)
+    }
+
+    #[precompile::public("getTotalAlphaStaked(bytes32,uint256)")]
+    #[precompile::view]
+    fn get_total_alpha_staked(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+    ) -> EvmResult<U256> {
+        StakingPrecompileV2::<R>::get_total_alpha_staked(handle, hotkey, netuid)
+    }
+
+    #[precompile::public("getAlphaPrice(uint256)")]
+    #[precompile::view]
+    fn get_alpha_price(_: &mut impl PrecompileHandle, netuid: U256) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let price = pallet_subtensor::Pallet::<R>::get_alpha_price(netuid)
+            .saturating_mul(U96F32::saturating_from_num(1_000_000_000))
+            .saturating_to_num::<u64>();
+
+        Ok(price.into())
+    }
+
+    #[precompile::public("getAddStakeFee(bytes32,bytes32,uint256,uint256)")]
+    #[precompile::view]
+    fn get_add_stake_fee(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        coldkey: H256,
+        netuid: U256,
+        amount_rao: U256,
+    ) -> EvmResult<U256> {
+        let hotkey = R::AccountId::from(hotkey.0);
+        let coldkey = R::AccountId::from(coldkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let fee = pallet_subtensor::Pallet::<R>::get_stake_fee(
+            None,
+            coldkey.clone(),
+            Some((hotkey, netuid)),
+            coldkey,
+            amount_rao.unique_saturated_into(),
+        );
+
+        Ok(fee.into())
+    }
+
+    #[precompile::public("getRemoveStakeFee(bytes32,bytes32,uint256,uint256)")]
+    #[precompile::view]
+    fn get_remove_stake_fee(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        coldkey: H256,
+        netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<U256> {
+        let hotkey = R::AccountId::from(hotkey.0);
+        let coldkey = R::AccountId::from(coldkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+        let fee = pallet_subtensor::Pallet::<R>::get_stake_fee(
+            Some((hotkey, netuid)),
+            coldkey.clone(),
+            None,
+            coldkey,
+            amount_alpha.unique_saturated_into(),
+        );
+
+        Ok(fee.into())
+    }
+
+    #[precompile::public(
+        "getMoveStakeFee(bytes32,bytes32,bytes32,bytes32,uint256,uint256,uint256)"
+    )]
+    #[precompile::view]
+    fn get_move_stake_fee(
+        _: &mut impl PrecompileHandle,
+        origin_hotkey: H256,
+        origin_coldkey: H256,
+        destination_hotkey: H256,
+        destination_coldkey: H256,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<U256> {
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let fee = pallet_subtensor::Pallet::<R>::get_stake_fee(
+            Some((R::AccountId::from(origin_hotkey.0), origin_netuid)),
+            R::AccountId::from(origin_coldkey.0),
+            Some((R::AccountId::from(destination_hotkey.0), destination_netuid)),
+            R::AccountId::from(destination_coldkey.0),
+            amount_alpha.unique_saturated_into(),
+        );
+
+        Ok(fee.into())
+    }
+
+    #[precompile::public("simSwapTaoForAlpha(uint256,uint256)")]
+    #[precompile::view]
+    fn sim_swap_tao_for_alpha(
+        _: &mut impl PrecompileHandle,
+        netuid: U256,
+        amount_rao: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let alpha = pallet_subtensor::Pallet::<R>::sim_swap_tao_for_alpha(
+            netuid,
+            amount_rao.unique_saturated_into(),
+        )
+        .ok_or_else(insufficient_liquidity)?;
+
+        Ok(alpha.into())
+    }
+
+    #[precompile::public("simSwapAlphaForTao(uint256,uint256)")]
+    #[precompile::view]
+    fn sim_swap_alpha_for_tao(
+        _: &mut impl PrecompileHandle,
+        netuid: U256,
+        amount_alpha: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let tao = pallet_subtensor::Pallet::<R>::sim_swap_alpha_for_tao(
+            netuid,
+            amount_alpha.unique_saturated_into(),
+        )
+        .ok_or_else(insufficient_liquidity)?;
+
+        Ok(tao.into())
+    }
+
+    #[precompile::public("getMaxAmountAdd(uint256,uint256)")]
+    #[precompile::view]
+    fn get_max_amount_add(
+        _: &mut impl PrecompileHandle,
+        netuid: U256,
+        limit_price: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let amount = pallet_subtensor::Pallet::<R>::get_max_amount_add(
+            netuid,
+            limit_price.unique_saturated_into(),
+        );
+
+        Ok(amount.into())
+    }
+
+    #[precompile::public("getMaxAmountRemove(uint256,uint256)")]
+    #[precompile::view]
+    fn get_max_amount_remove(
+        _: &mut impl PrecompileHandle,
+        netuid: U256,
+        limit_price: U256,
+    ) -> EvmResult<U256> {
+        let netuid = try_u16_from_u256(netuid)?;
+        let amount = pallet_subtensor::Pallet::<R>::get_max_amount_remove(
+            netuid,
+            limit_price.unique_saturated_into(),
+        );
+
+        Ok(amount.into())
+    }
+
+    #[precompile::public("getMaxAmountMove(uint256,uint256,uint256)")]
+    #[precompile::view]
+    fn get_max_amount_move(
+        _: &mut impl PrecompileHandle,
+        origin_netuid: U256,
+        destination_netuid: U256,
+        limit_price: U256,
+    ) -> EvmResult<U256> {
+        let origin_netuid = try_u16_from_u256(origin_netuid)?;
+        let destination_netuid = try_u16_from_u256(destination_netuid)?;
+        let amount = pallet_subtensor::Pallet::<R>::get_max_amount_move(
+            origin_netuid,
+            destination_netuid,
+            limit_price.unique_saturated_into(),
+        );
+
+        Ok(amount.into())
+    }
+
+    #[precompile::public("getChildren(bytes32,uint256)")]
+    #[precompile::view]
+    fn get_children(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+    ) -> EvmResult<Vec<(u64, H256)>> {
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_children(&hotkey, netuid)
+            .into_iter()
+            .map(|(proportion, child)| {
+                let key: [u8; 32] = child.into();
+                (proportion, key.into())
+            })
+            .collect())
+    }
+
+    #[precompile::public("getParents(bytes32,uint256)")]
+    #[precompile::view]
+    fn get_parents(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+    ) -> EvmResult<Vec<(u64, H256)>> {
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_parents(&hotkey, netuid)
+            .into_iter()
+            .map(|(proportion, parent)| {
+                let key: [u8; 32] = parent.into();
+                (proportion, key.into())
+            })
+            .collect())
+    }
+
+    #[precompile::public("getChildkeyTake(bytes32,uint256)")]
+    #[precompile::view]
+    fn get_childkey_take(
+        _: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: U256,
+    ) -> EvmResult<u16> {
+        let hotkey = R::AccountId::from(hotkey.0);
+        let netuid = try_u16_from_u256(netuid)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_childkey_take(
+            &hotkey, netuid,
+        ))
+    }
+
+    #[precompile::public("addProxy(bytes32)")]
+    fn add_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
+        StakingPrecompileV2::<R>::add_proxy(handle, delegate)
+    }
+
+    #[precompile::public("removeProxy(bytes32)")]
+    fn remove_proxy(handle: &mut impl PrecompileHandle, delegate: H256) -> EvmResult<()> {
+        StakingPrecompileV2::<R>::remove_proxy(handle, delegate)
+    }
+}
+
+impl<R> StakingPrecompileV3<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    /// Dispatches a call swapping alpha of `coldkey` with `hotkey` between subnets and emits
+    /// `StakeSwapped`.
+    fn dispatch_swap_stake(
+        handle: &mut impl PrecompileHandle,
+        call: pallet_subtensor::Call<R>,
+        coldkey: R::AccountId,
+        hotkey: R::AccountId,
+        origin_netuid: u16,
+        destination_netuid: u16,
+    ) -> EvmResult<()> {
+        let change = StakingPrecompileV2::<R>::dispatch_tracking_stake(
+            handle,
+            call,
+            &coldkey,
+            &hotkey,
+            origin_netuid,
+        )?;
+
+        handle.emit_event(
+            STAKE_SWAPPED,
+            &[account_topic(coldkey), account_topic(hotkey)],
+            encode_event_data((
+                U256::from(origin_netuid),
+                U256::from(destination_netuid),
+                U256::from(change.alpha_removed()),
+            )),
+        )
     }
 }
 
@@ -482,6 +1378,12 @@ where
     }
 }
 
+fn insufficient_liquidity() -> PrecompileFailure {
+    PrecompileFailure::Error {
+        exit_status: ExitError::Other("insufficient liquidity in the subnet pool".into()),
+    }
+}
+
 fn try_u16_from_u256(value: U256) -> Result<u16, PrecompileFailure> {
     value.try_into().map_err(|_| PrecompileFailure::Error {
         exit_status: ExitError::Other("the value is outside of u16 bounds".into()),
@@ -1,14 +1,21 @@
 use core::marker::PhantomData;
 
+use fp_evm::{ExitError, PrecompileFailure};
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::traits::ConstU32;
 use frame_system::RawOrigin;
 use pallet_evm::{AddressMapping, PrecompileHandle};
-use precompile_utils::{EvmResult, prelude::BoundedString};
-use sp_core::H256;
+use precompile_utils::{EvmResult, prelude::BoundedString, solidity::encode_event_data};
+use sp_core::{H256, U256};
 use sp_runtime::traits::Dispatchable;
+use sp_std::vec::Vec;
 
-use crate::{PrecompileExt, PrecompileHandleExt};
+use crate::{PrecompileExt, PrecompileHandleExt, account_topic, uint_topic};
+
+// The events of the precompile, see `solidity/subnet.sol`.
+const NETWORK_REGISTERED: &[u8] = b"NetworkRegistered(uint16,bytes32,bytes32)";
+const HYPERPARAMETER_SET: &[u8] = b"HyperparameterSet(uint16,bytes4,uint256)";
+const ALPHA_VALUES_SET: &[u8] = b"AlphaValuesSet(uint16,uint16,uint16)";
 
 pub struct SubnetPrecompile<R>(PhantomData<R>);
 
@@ -18,7 +25,7 @@ where
         + pallet_evm::Config
         + pallet_subtensor::Config
         + pallet_admin_utils::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + GetDispatchInfo
@@ -35,7 +42,7 @@ where
         + pallet_evm::Config
         + pallet_subtensor::Config
         + pallet_admin_utils::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + GetDispatchInfo
@@ -47,14 +54,11 @@ where
     fn register_network(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
         let hotkey = R::AccountId::from(hotkey.0);
         let call = pallet_subtensor::Call::<R>::register_network_with_identity {
-            hotkey,
+            hotkey: hotkey.clone(),
             identity: None,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_register_network(handle, call, hotkey)
     }
 
     #[precompile::public(
@@ -85,13 +89,20 @@ where
         };
 
         let call = pallet_subtensor::Call::<R>::register_network_with_identity {
-            hotkey,
+            hotkey: hotkey.clone(),
             identity: Some(identity),
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        Self::dispatch_register_network(handle, call, hotkey)
+    }
+
+    #[precompile::public("getAlphaTwap(uint16,uint64)")]
+    #[precompile::view]
+    fn get_alpha_twap(_: &mut impl PrecompileHandle, netuid: u16, window: u64) -> EvmResult<u64> {
+        pallet_subtensor::Pallet::<R>::get_alpha_twap(netuid, window).ok_or(
+            PrecompileFailure::Error {
+                exit_status: ExitError::InvalidRange,
+            },
         )
     }
 
@@ -113,10 +124,7 @@ where
             serving_rate_limit,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, serving_rate_limit.into())
     }
 
     #[precompile::public("getMinDifficulty(uint16)")]
@@ -137,10 +145,7 @@ where
             min_difficulty,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, min_difficulty.into())
     }
 
     #[precompile::public("getMaxDifficulty(uint16)")]
@@ -161,10 +166,7 @@ where
             max_difficulty,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, max_difficulty.into())
     }
 
     #[precompile::public("getWeightsVersionKey(uint16)")]
@@ -185,10 +187,7 @@ where
             weights_version_key,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, weights_version_key.into())
     }
 
     #[precompile::public("getWeightsSetRateLimit(uint16)")]
@@ -226,10 +225,7 @@ where
             adjustment_alpha,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, adjustment_alpha.into())
     }
 
     #[precompile::public("getMaxWeightLimit(uint16)")]
@@ -250,10 +246,7 @@ where
             max_weight_limit,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, max_weight_limit.into())
     }
 
     #[precompile::public("getImmunityPeriod(uint16)")]
@@ -274,10 +267,7 @@ where
             immunity_period,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, immunity_period.into())
     }
 
     #[precompile::public("getMinAllowedWeights(uint16)")]
@@ -298,10 +288,7 @@ where
             min_allowed_weights,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, min_allowed_weights.into())
     }
 
     #[precompile::public("getKappa(uint16)")]
@@ -315,10 +302,7 @@ where
     fn set_kappa(handle: &mut impl PrecompileHandle, netuid: u16, kappa: u16) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_kappa { netuid, kappa };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, kappa.into())
     }
 
     #[precompile::public("getRho(uint16)")]
@@ -332,10 +316,7 @@ where
     fn set_rho(handle: &mut impl PrecompileHandle, netuid: u16, rho: u16) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_rho { netuid, rho };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, rho.into())
     }
 
     #[precompile::public("getActivityCutoff(uint16)")]
@@ -356,10 +337,7 @@ where
             activity_cutoff,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, activity_cutoff.into())
     }
 
     #[precompile::public("getNetworkRegistrationAllowed(uint16)")]
@@ -385,9 +363,11 @@ where
             registration_allowed,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
+        Self::dispatch_hyperparameter(
+            handle,
             call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+            netuid,
+            U256::from(u8::from(registration_allowed)),
         )
     }
 
@@ -414,9 +394,11 @@ where
             registration_allowed,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
+        Self::dispatch_hyperparameter(
+            handle,
             call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
+            netuid,
+            U256::from(u8::from(registration_allowed)),
         )
     }
 
@@ -452,10 +434,7 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_max_burn { netuid, max_burn };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, max_burn.into())
     }
 
     #[precompile::public("getDifficulty(uint16)")]
@@ -473,10 +452,7 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_difficulty { netuid, difficulty };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, difficulty.into())
     }
 
     #[precompile::public("getBondsMovingAverage(uint16)")]
@@ -497,10 +473,7 @@ where
             bonds_moving_average,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, bonds_moving_average.into())
     }
 
     #[precompile::public("getCommitRevealWeightsEnabled(uint16)")]
@@ -526,10 +499,7 @@ where
             enabled,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, U256::from(u8::from(enabled)))
     }
 
     #[precompile::public("getLiquidAlphaEnabled(uint16)")]
@@ -547,10 +517,7 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_liquid_alpha_enabled { netuid, enabled };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, U256::from(u8::from(enabled)))
     }
 
     #[precompile::public("getAlphaValues(uint16)")]
@@ -576,6 +543,12 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        handle.emit_event(
+            ALPHA_VALUES_SET,
+            &[uint_topic(netuid.into())],
+            encode_event_data((alpha_low, alpha_high)),
         )
     }
 
@@ -600,10 +573,7 @@ where
             interval,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        Self::dispatch_hyperparameter(handle, call, netuid, interval.into())
     }
 
     #[precompile::public("toggleTransfers(uint16,bool)")]
@@ -615,9 +585,67 @@ where
     ) -> EvmResult<()> {
         let call = pallet_admin_utils::Call::<R>::sudo_set_toggle_transfer { netuid, toggle };
 
+        Self::dispatch_hyperparameter(handle, call, netuid, U256::from(u8::from(toggle)))
+    }
+}
+
+impl<R> SubnetPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_admin_utils::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_admin_utils::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    /// Dispatches a call registering a subnet owned by the caller and emits `NetworkRegistered`.
+    fn dispatch_register_network(
+        handle: &mut impl PrecompileHandle,
+        call: pallet_subtensor::Call<R>,
+        hotkey: R::AccountId,
+    ) -> EvmResult<()> {
+        let coldkey = handle.caller_account_id::<R>();
+        // the network is always registered at the next free netuid
+        let netuid = pallet_subtensor::Pallet::<R>::get_next_netuid();
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey.clone()))?;
+
+        handle.emit_event(
+            NETWORK_REGISTERED,
+            &[
+                uint_topic(netuid.into()),
+                account_topic(coldkey),
+                account_topic(hotkey),
+            ],
+            Vec::new(),
+        )
+    }
+
+    /// Dispatches a call setting a hyperparameter of `netuid` to `value` and emits
+    /// `HyperparameterSet`, identifying the hyperparameter by the selector of its setter.
+    fn dispatch_hyperparameter(
+        handle: &mut impl PrecompileHandle,
+        call: pallet_admin_utils::Call<R>,
+        netuid: u16,
+        value: U256,
+    ) -> EvmResult<()> {
+        let mut selector = H256::zero();
+        for (byte, input) in selector.0.iter_mut().zip(handle.input().iter().take(4)) {
+            *byte = *input;
+        }
+
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+
+        handle.emit_event(
+            HYPERPARAMETER_SET,
+            &[uint_topic(netuid.into()), selector],
+            encode_event_data(value),
         )
     }
 }
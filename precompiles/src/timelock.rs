@@ -0,0 +1,109 @@
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_drand::timelock::{TimelockCiphertext, TimelockOutcome};
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::{EvmResult, prelude::UnboundedBytes};
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec::Vec;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+// The status of a timelock returned by `getTimelock`.
+const TIMELOCK_UNKNOWN: u8 = 0;
+const TIMELOCK_PENDING: u8 = 1;
+const TIMELOCK_REVEALED: u8 = 2;
+const TIMELOCK_FAILED: u8 = 3;
+
+pub struct TimelockPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for TimelockPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall:
+        From<pallet_drand::Call<R>> + GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2054;
+}
+
+#[precompile_utils::precompile]
+impl<R> TimelockPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall:
+        From<pallet_drand::Call<R>> + GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("submitTimelock(bytes,uint64)")]
+    #[precompile::payable]
+    fn submit_timelock(
+        handle: &mut impl PrecompileHandle,
+        ciphertext: UnboundedBytes,
+        reveal_round: u64,
+    ) -> EvmResult<u64> {
+        let ciphertext: Vec<u8> = ciphertext.into();
+        let ciphertext =
+            TimelockCiphertext::try_from(ciphertext).map_err(|_| PrecompileFailure::Error {
+                exit_status: ExitError::Other("ciphertext is too long".into()),
+            })?;
+        let id = pallet_drand::NextTimelockId::<R>::get();
+        let call = pallet_drand::Call::<R>::submit_timelock {
+            ciphertext,
+            reveal_round,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )?;
+        Ok(id)
+    }
+
+    #[precompile::public("removeTimelock(uint64)")]
+    #[precompile::payable]
+    fn remove_timelock(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<()> {
+        let call = pallet_drand::Call::<R>::remove_timelock { id };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getTimelock(uint64)")]
+    #[precompile::view]
+    fn get_timelock(
+        _: &mut impl PrecompileHandle,
+        id: u64,
+    ) -> EvmResult<(u8, u64, UnboundedBytes)> {
+        if let Some(pending) = pallet_drand::Timelocks::<R>::get(id) {
+            return Ok((TIMELOCK_PENDING, pending.reveal_round, Vec::new().into()));
+        }
+
+        Ok(match pallet_drand::RevealedTimelocks::<R>::get(id) {
+            Some(revealed) => match revealed.outcome {
+                TimelockOutcome::Revealed(payload) => (
+                    TIMELOCK_REVEALED,
+                    revealed.reveal_round,
+                    payload.into_inner().into(),
+                ),
+                TimelockOutcome::Failed(_) => {
+                    (TIMELOCK_FAILED, revealed.reveal_round, Vec::new().into())
+                }
+            },
+            None => (TIMELOCK_UNKNOWN, 0, Vec::new().into()),
+        })
+    }
+
+    #[precompile::public("getTimelocksOf(bytes32)")]
+    #[precompile::view]
+    fn get_timelocks_of(_: &mut impl PrecompileHandle, owner: H256) -> EvmResult<Vec<u64>> {
+        let owner = R::AccountId::from(owner.0);
+        Ok(pallet_drand::TimelocksOf::<R>::get(owner).into_inner())
+    }
+}
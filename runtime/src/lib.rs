@@ -18,7 +18,7 @@ use frame_support::{
     genesis_builder_helper::{build_state, get_preset},
     pallet_prelude::Get,
     traits::{
-        Contains, LinearStoragePrice, OnUnbalanced,
+        Contains, EitherOfDiverse, LinearStoragePrice, OnUnbalanced,
         fungible::{
             DecreaseIssuance, HoldConsideration, Imbalance as FungibleImbalance, IncreaseIssuance,
         },
@@ -31,13 +31,17 @@ use pallet_grandpa::{
 };
 use pallet_registry::CanRegisterIdentity;
 use pallet_subtensor::rpc_info::{
+    buyback_info::SubnetBuybackInfo,
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
+    emission_projection::SubnetEmissionProjection,
     metagraph::{Metagraph, SelectiveMetagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
+    rebalance_info::RebalancePlan,
     show_subnet::SubnetState,
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
+    subnet_proposal_info::SubnetProposalInfo,
 };
 use smallvec::smallvec;
 use sp_api::impl_runtime_apis;
@@ -97,13 +101,34 @@ use pallet_ethereum::{Call::transact, PostLogContent, Transaction as EthereumTra
 use pallet_evm::{Account as EVMAccount, BalanceConverter, FeeCalculator, Runner};
 
 // Drand
+parameter_types! {
+    // About one week of quicknet rounds, at one round every 3 seconds.
+    pub const DrandDefaultRetentionRounds: u64 = 201_600;
+    // Pending timelocks keep their pulse for up to about two weeks.
+    pub const DrandMaxReferenceAge: u64 = 403_200;
+    // Revealed timelocks are kept for about a week.
+    pub const DrandRevealedTimelockLifetime: BlockNumber = 50_400;
+    pub const DrandTimelockDeposit: Balance = 100_000_000; // 0.1 TAO
+}
+
 impl pallet_drand::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_drand::weights::SubstrateWeight<Runtime>;
     type AuthorityId = pallet_drand::crypto::TestAuthId;
-    type Verifier = pallet_drand::verifier::QuicknetVerifier;
+    type Verifier = pallet_drand::verifier::SchemeVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type DefaultRetentionRounds = DrandDefaultRetentionRounds;
+    type MaxPrunedPulsesPerBlock = ConstU32<100>;
+    type MaxReferenceAge = DrandMaxReferenceAge;
+    type OnTimelockRevealed = ();
+    type MaxTimelocksPerRound = ConstU32<64>;
+    type MaxTimelockRevealsPerBlock = ConstU32<16>;
+    type MaxTimelocksPerAccount = ConstU32<32>;
+    type MaxPendingTimelocks = ConstU32<1_024>;
+    type RevealedTimelockLifetime = DrandRevealedTimelockLifetime;
+    type Currency = Balances;
+    type TimelockDeposit = DrandTimelockDeposit;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -207,7 +232,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 265,
+    spec_version: 309,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -252,11 +277,12 @@ impl Contains<RuntimeCall> for NoNestingCallFilter {
                     pallet_utility::Call::force_batch { calls } => calls,
                     pallet_utility::Call::batch { calls } => calls,
                     pallet_utility::Call::batch_all { calls } => calls,
+                    pallet_utility::Call::batch_if { calls, .. } => calls,
                     _ => &Vec::new(),
                 };
 
                 !calls.iter().any(|call| {
-					matches!(call, RuntimeCall::Utility(inner) if matches!(inner, pallet_utility::Call::force_batch { .. } | pallet_utility::Call::batch_all { .. } | pallet_utility::Call::batch { .. }))
+					matches!(call, RuntimeCall::Utility(inner) if matches!(inner, pallet_utility::Call::force_batch { .. } | pallet_utility::Call::batch_all { .. } | pallet_utility::Call::batch { .. } | pallet_utility::Call::batch_if { .. }))
 				})
             }
             _ => true,
@@ -357,6 +383,8 @@ impl pallet_utility::Config for Runtime {
     type RuntimeCall = RuntimeCall;
     type PalletsOrigin = OriginCaller;
     type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+    type Predicate = pallet_subtensor::utils::batch_predicate::BatchPredicate<AccountId>;
+    type PredicateEvaluator = SubtensorModule;
 }
 
 parameter_types! {
@@ -494,6 +522,7 @@ parameter_types! {
     pub const CouncilMotionDuration: BlockNumber = 12 * HOURS;
     pub const CouncilMaxProposals: u32 = 10;
     pub const CouncilMaxMembers: u32 = 3;
+    pub CouncilMaxAutoCloseWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
 }
 
 // Configure collective pallet for Senate
@@ -501,7 +530,7 @@ parameter_types! {
     pub const SenateMaxMembers: u32 = 12;
 }
 
-use pallet_collective::{CanPropose, CanVote, GetVotingMembers};
+use pallet_collective::{CanPropose, CanVote, GetVoteWeight, GetVotingMembers};
 pub struct CanProposeToTriumvirate;
 impl CanPropose<AccountId> for CanProposeToTriumvirate {
     fn can_propose(account: &AccountId) -> bool {
@@ -563,6 +592,24 @@ impl Get<MemberCount> for GetSenateMemberCount {
     }
 }
 
+// Senate votes are weighted by the total stake of the member hotkey at vote time.
+pub struct SenateStakeWeight;
+impl GetVoteWeight<AccountId> for SenateStakeWeight {
+    fn is_weighted() -> bool {
+        true
+    }
+
+    fn vote_weight(hotkey: &AccountId) -> u64 {
+        SubtensorModule::get_total_stake_for_hotkey(hotkey)
+    }
+
+    fn total_weight() -> u64 {
+        SenateMembers::members().iter().fold(0u64, |acc, hotkey| {
+            acc.saturating_add(Self::vote_weight(hotkey))
+        })
+    }
+}
+
 pub struct TriumvirateVotes;
 impl CollectiveInterface<AccountId, Hash, u32> for TriumvirateVotes {
     fn remove_votes(hotkey: &AccountId) -> Result<bool, sp_runtime::DispatchError> {
@@ -597,6 +644,8 @@ impl pallet_collective::Config<TriumvirateCollective> for Runtime {
     type CanPropose = CanProposeToTriumvirate;
     type CanVote = CanVoteToTriumvirate;
     type GetVotingMembers = GetSenateMemberCount;
+    type VoteWeight = SenateStakeWeight;
+    type MaxAutoCloseWeight = CouncilMaxAutoCloseWeight;
 }
 
 // We call council members Triumvirate
@@ -663,14 +712,15 @@ impl pallet_multisig::Config for Runtime {
 parameter_types! {
     // One storage item; key size sizeof(AccountId) = 32, value sizeof(Balance) = 8; 40 total
     pub const ProxyDepositBase: Balance = deposit(1, 40);
-    // Adding 32 bytes + sizeof(ProxyType) = 32 + 1
-    pub const ProxyDepositFactor: Balance = deposit(0, 33);
+    // Adding 32 bytes + sizeof(ProxyType) = 32 + 130
+    pub const ProxyDepositFactor: Balance = deposit(0, 162);
     pub const MaxProxies: u32 = 20; // max num proxies per acct
     pub const MaxPending: u32 = 15 * 5; // max blocks pending ~15min
     // 16 bytes
     pub const AnnouncementDepositBase: Balance =  deposit(1, 16);
     // 68 bytes per announcement
     pub const AnnouncementDepositFactor: Balance = deposit(0, 68);
+    pub const MaxProxySpendingEntries: u32 = 32; // max spends tracked per spending-limited proxy
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -796,6 +846,97 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 }
                 _ => false,
             },
+            ProxyType::SubnetOwner(netuid) => match c {
+                RuntimeCall::AdminUtils(
+                    pallet_admin_utils::Call::schedule_hyperparameter_change { call },
+                ) => is_subnet_owner_call(call, *netuid),
+                RuntimeCall::AdminUtils(call) => is_subnet_owner_call(call, *netuid),
+                _ => false,
+            },
+            ProxyType::SubnetStaking(netuid) => match c {
+                RuntimeCall::SubtensorModule(
+                    pallet_subtensor::Call::add_stake { netuid: n, .. }
+                    | pallet_subtensor::Call::remove_stake { netuid: n, .. }
+                    | pallet_subtensor::Call::add_stake_limit { netuid: n, .. }
+                    | pallet_subtensor::Call::remove_stake_limit { netuid: n, .. },
+                ) => n == netuid,
+                RuntimeCall::SubtensorModule(
+                    pallet_subtensor::Call::swap_stake {
+                        origin_netuid,
+                        destination_netuid,
+                        ..
+                    }
+                    | pallet_subtensor::Call::swap_stake_limit {
+                        origin_netuid,
+                        destination_netuid,
+                        ..
+                    }
+                    | pallet_subtensor::Call::move_stake {
+                        origin_netuid,
+                        destination_netuid,
+                        ..
+                    },
+                ) => origin_netuid == netuid && destination_netuid == netuid,
+                _ => false,
+            },
+            ProxyType::SubnetWeights(netuid) => match c {
+                RuntimeCall::SubtensorModule(
+                    pallet_subtensor::Call::set_weights { netuid: n, .. }
+                    | pallet_subtensor::Call::commit_weights { netuid: n, .. }
+                    | pallet_subtensor::Call::reveal_weights { netuid: n, .. }
+                    | pallet_subtensor::Call::batch_reveal_weights { netuid: n, .. }
+                    | pallet_subtensor::Call::commit_crv3_weights { netuid: n, .. },
+                ) => n == netuid,
+                RuntimeCall::SubtensorModule(
+                    pallet_subtensor::Call::batch_set_weights { netuids, .. }
+                    | pallet_subtensor::Call::batch_commit_weights { netuids, .. },
+                ) => netuids.iter().all(|n| n.0 == *netuid),
+                _ => false,
+            },
+            ProxyType::HotkeyStaking(hotkeys) => match c {
+                RuntimeCall::SubtensorModule(
+                    pallet_subtensor::Call::add_stake { hotkey, .. }
+                    | pallet_subtensor::Call::remove_stake { hotkey, .. }
+                    | pallet_subtensor::Call::add_stake_limit { hotkey, .. }
+                    | pallet_subtensor::Call::remove_stake_limit { hotkey, .. }
+                    | pallet_subtensor::Call::swap_stake { hotkey, .. }
+                    | pallet_subtensor::Call::swap_stake_limit { hotkey, .. }
+                    | pallet_subtensor::Call::unstake_all { hotkey }
+                    | pallet_subtensor::Call::unstake_all_alpha { hotkey },
+                ) => hotkeys.contains(hotkey),
+                RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake {
+                    origin_hotkey,
+                    destination_hotkey,
+                    ..
+                }) => hotkeys.contains(origin_hotkey) && hotkeys.contains(destination_hotkey),
+                _ => false,
+            },
+            ProxyType::SpendingLimit { .. } => matches!(
+                c,
+                RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. })
+                    | RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_limit { .. })
+            ),
+            ProxyType::Weights => matches!(
+                c,
+                RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_weights { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::batch_set_weights { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::commit_weights { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::batch_commit_weights { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::reveal_weights { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::batch_reveal_weights { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::commit_crv3_weights { .. }
+                    )
+            ),
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
@@ -804,16 +945,65 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
             (ProxyType::NonTransfer, _) => {
-                // NonTransfer is NOT a superset of Transfer or SmallTransfer
-                !matches!(o, ProxyType::Transfer | ProxyType::SmallTransfer)
+                // NonTransfer is NOT a superset of Transfer, SmallTransfer or SpendingLimit
+                !matches!(
+                    o,
+                    ProxyType::Transfer
+                        | ProxyType::SmallTransfer
+                        | ProxyType::SpendingLimit { .. }
+                )
             }
             (ProxyType::Governance, ProxyType::Triumvirate | ProxyType::Senate) => true,
             (ProxyType::Transfer, ProxyType::SmallTransfer) => true,
+            (ProxyType::Owner, ProxyType::SubnetOwner(_)) => true,
+            (ProxyType::Weights, ProxyType::SubnetWeights(_)) => true,
+            (ProxyType::Staking, ProxyType::SubnetStaking(_) | ProxyType::HotkeyStaking(_)) => true,
+            (ProxyType::HotkeyStaking(hotkeys), ProxyType::HotkeyStaking(other)) => {
+                other.iter().all(|hotkey| hotkeys.contains(hotkey))
+            }
             _ => false,
         }
     }
 }
 
+/// Spending budgets of `ProxyType::SpendingLimit` proxies.
+pub struct ProxySpendingLimit;
+impl pallet_proxy::SpendingLimit<ProxyType, RuntimeCall, Balance, BlockNumber>
+    for ProxySpendingLimit
+{
+    fn limit(proxy_type: &ProxyType) -> Option<(Balance, BlockNumber)> {
+        match proxy_type {
+            ProxyType::SpendingLimit { budget, window } => Some((*budget, *window)),
+            _ => None,
+        }
+    }
+
+    fn spent(call: &RuntimeCall) -> Balance {
+        match call {
+            RuntimeCall::Balances(
+                pallet_balances::Call::transfer_keep_alive { value, .. }
+                | pallet_balances::Call::transfer_allow_death { value, .. },
+            ) => *value,
+            RuntimeCall::SubtensorModule(
+                pallet_subtensor::Call::add_stake { amount_staked, .. }
+                | pallet_subtensor::Call::add_stake_limit { amount_staked, .. },
+            ) => *amount_staked,
+            // Alpha is charged to the TAO budget at the current price of the origin subnet.
+            RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake {
+                origin_netuid,
+                alpha_amount,
+                ..
+            }) => SubtensorModule::alpha_to_tao_at_price(*origin_netuid, *alpha_amount),
+            _ => 0,
+        }
+    }
+}
+
+/// Returns whether the call is a change the subnet owner may make on the given subnet.
+fn is_subnet_owner_call(call: &pallet_admin_utils::Call<Runtime>, netuid: u16) -> bool {
+    pallet_admin_utils::Pallet::<Runtime>::subnet_of_call(call) == Some((netuid, true))
+}
+
 impl pallet_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -827,6 +1017,8 @@ impl pallet_proxy::Config for Runtime {
     type CallHasher = BlakeTwo256;
     type AnnouncementDepositBase = AnnouncementDepositBase;
     type AnnouncementDepositFactor = AnnouncementDepositFactor;
+    type SpendingLimit = ProxySpendingLimit;
+    type MaxSpendingEntries = MaxProxySpendingEntries;
 }
 
 parameter_types! {
@@ -927,6 +1119,9 @@ parameter_types! {
     pub const MaxAdditionalFields: u32 = 1;
     pub const InitialDeposit: Balance = 100_000_000; // 0.1 TAO
     pub const FieldDeposit: Balance = 100_000_000; // 0.1 TAO
+    pub const MaxRegistrars: u32 = 20;
+    pub const MaxSubAccounts: u32 = 100;
+    pub const SubAccountDeposit: Balance = 100_000_000; // 0.1 TAO
 }
 
 impl pallet_registry::Config for Runtime {
@@ -939,12 +1134,18 @@ impl pallet_registry::Config for Runtime {
     type MaxAdditionalFields = MaxAdditionalFields;
     type InitialDeposit = InitialDeposit;
     type FieldDeposit = FieldDeposit;
+    type RegistrarOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureMajoritySenate>;
+    type MaxRegistrars = MaxRegistrars;
+    type MaxSubAccounts = MaxSubAccounts;
+    type SubAccountDeposit = SubAccountDeposit;
 }
 
 parameter_types! {
+    // Commitments hold a single field, so commitment schemas have a single field as well
     pub const MaxCommitFieldsInner: u32 = 1;
     pub const CommitmentInitialDeposit: Balance = 0; // Free
     pub const CommitmentFieldDeposit: Balance = 0; // Free
+    pub const CommitmentByteDeposit: Balance = 10_000; // 0.00001 TAO
     pub const CommitmentRateLimit: BlockNumber = 100; // Allow commitment every 100 blocks
 }
 
@@ -981,8 +1182,36 @@ impl pallet_commitments::Config for Runtime {
     type MaxFields = MaxCommitFields;
     type InitialDeposit = CommitmentInitialDeposit;
     type FieldDeposit = CommitmentFieldDeposit;
+    type ByteDeposit = CommitmentByteDeposit;
     type DefaultRateLimit = CommitmentRateLimit;
     type TempoInterface = TempoInterface;
+    type SubnetOwnerInterface = SubnetOwnerInterface;
+}
+
+pub struct SubnetOwnerInterface;
+impl pallet_commitments::GetSubnetOwnerInterface<AccountId> for SubnetOwnerInterface {
+    fn is_subnet_owner(netuid: u16, who: &AccountId) -> bool {
+        SubtensorModule::if_subnet_exist(netuid)
+            && pallet_subtensor::SubnetOwner::<Runtime>::get(netuid) == *who
+    }
+}
+
+pub struct RegistryIdentity;
+impl pallet_subtensor::IdentityInterface<AccountId> for RegistryIdentity {
+    fn verified_parent(who: &AccountId) -> Option<AccountId> {
+        Registry::get_verified_parent(who)
+    }
+}
+
+pub struct CommitmentsCleanup;
+impl pallet_subtensor::OnNeuronRemoved<AccountId> for CommitmentsCleanup {
+    fn on_neuron_removed(netuid: u16, hotkey: &AccountId) {
+        Commitments::purge_commitment(netuid, hotkey);
+    }
+
+    fn on_network_removed(netuid: u16) {
+        Commitments::purge_netuid(netuid);
+    }
 }
 
 pub struct TempoInterface;
@@ -1071,6 +1300,7 @@ parameter_types! {
     } else {
         7 * 24 * 60 * 60 / 12 // 7 days
     };
+    pub const SubtensorMaxTwapWindowBlocks: u64 = 7200; // 1 day
 }
 
 impl pallet_subtensor::Config for Runtime {
@@ -1081,6 +1311,8 @@ impl pallet_subtensor::Config for Runtime {
     type CouncilOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnNeuronRemoved = CommitmentsCleanup;
+    type IdentityInterface = RegistryIdentity;
     type Scheduler = Scheduler;
     type InitialRho = SubtensorInitialRho;
     type InitialKappa = SubtensorInitialKappa;
@@ -1136,6 +1368,7 @@ impl pallet_subtensor::Config for Runtime {
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
     type InitialEmaPriceHalvingPeriod = InitialEmaPriceHalvingPeriod;
     type DurationOfStartCall = DurationOfStartCall;
+    type MaxTwapWindowBlocks = SubtensorMaxTwapWindowBlocks;
 }
 
 use sp_runtime::BoundedVec;
@@ -2119,6 +2352,26 @@ impl_runtime_apis! {
             SubtensorModule::get_selective_metagraph(netuid, metagraph_indexes)
         }
 
+        fn get_emission_projection(blocks: u64, epochs: u16) -> Vec<SubnetEmissionProjection> {
+            SubtensorModule::get_emission_projection(blocks, epochs)
+        }
+
+        fn get_subnet_buyback_info(netuid: u16) -> Option<SubnetBuybackInfo> {
+            SubtensorModule::get_subnet_buyback_info(netuid)
+        }
+
+        fn get_all_subnet_buyback_info() -> Vec<Option<SubnetBuybackInfo>> {
+            SubtensorModule::get_all_subnet_buyback_info()
+        }
+
+        fn get_subnet_alpha_twap(netuid: u16, window: u64) -> Option<u64> {
+            SubtensorModule::get_alpha_twap(netuid, window)
+        }
+
+        fn get_subnet_proposals(netuid: u16) -> Vec<SubnetProposalInfo<AccountId32>> {
+            SubtensorModule::get_subnet_proposals(netuid)
+        }
+
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -2137,6 +2390,10 @@ impl_runtime_apis! {
         fn get_stake_fee( origin: Option<(AccountId32, u16)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, u16)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64 {
             SubtensorModule::get_stake_fee( origin, origin_coldkey_account, destination, destination_coldkey_account, amount )
         }
+
+        fn simulate_rebalance_stake( coldkey_account: AccountId32, targets: Vec<(AccountId32, u16, u16)> ) -> Option<RebalancePlan<AccountId32>> {
+            SubtensorModule::simulate_rebalance_stake( coldkey_account, targets )
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
@@ -2144,6 +2401,77 @@ impl_runtime_apis! {
             SubtensorModule::get_network_lock_cost()
         }
     }
+
+    impl subtensor_custom_rpc_runtime_api::ProxySpendingRuntimeApi<Block> for Runtime {
+        fn get_proxy_remaining_allowance(real: AccountId32, delegate: AccountId32) -> Option<u64> {
+            Proxy::remaining_allowance(&real, &delegate)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::HyperparameterTimelockRuntimeApi<Block> for Runtime {
+        fn get_scheduled_hyperparameter_changes() -> Vec<pallet_admin_utils::ScheduledHyperparameterChangeInfo<AccountId32>> {
+            AdminUtils::get_scheduled_hyperparameter_changes()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::RegistryRuntimeApi<Block> for Runtime {
+        fn get_super_of(sub: AccountId32) -> Option<(AccountId32, pallet_registry::Data)> {
+            Registry::get_super_of(&sub)
+        }
+
+        fn get_subs_of(main: AccountId32) -> Vec<AccountId32> {
+            Registry::get_subs_of(&main)
+        }
+
+        fn get_judgements(who: AccountId32) -> Vec<(pallet_registry::RegistrarIndex, pallet_registry::Judgement<u64>)> {
+            Registry::get_judgements(&who)
+        }
+
+        fn get_registrars() -> Vec<Option<pallet_registry::RegistrarInfo<u64, AccountId32>>> {
+            Registry::get_registrars()
+        }
+
+        fn get_verified_parent(sub: AccountId32) -> Option<AccountId32> {
+            Registry::get_verified_parent(&sub)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::CommitmentsRuntimeApi<Block> for Runtime {
+        fn get_commitment_history(netuid: u16, account: AccountId32, from_block: u32, to_block: u32) -> Vec<pallet_commitments::CommitmentHistoryEntry<u32>> {
+            Commitments::get_commitment_history(netuid, &account, from_block, to_block)
+        }
+
+        fn get_commitment_schema(netuid: u16) -> Option<pallet_commitments::CommitmentSchema> {
+            pallet_commitments::CommitmentSchemas::<Runtime>::get(netuid)
+        }
+
+        fn get_decoded_commitment(netuid: u16, account: AccountId32) -> Option<Vec<pallet_commitments::DecodedCommitmentField>> {
+            Commitments::get_decoded_commitment(netuid, &account)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::DrandRuntimeApi<Block> for Runtime {
+        fn get_pulse(round: Option<pallet_drand::types::RoundNumber>) -> Option<pallet_drand::types::Pulse> {
+            Drand::pulse_of(round)
+        }
+
+        fn get_beacon_config() -> pallet_drand::types::BeaconConfiguration {
+            pallet_drand::BeaconConfig::<Runtime>::get()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::SenateRuntimeApi<Block, Hash> for Runtime {
+        fn get_weighted_tally(proposal_hash: Hash) -> Option<pallet_collective::WeightedTally> {
+            Triumvirate::weighted_tally(proposal_hash)
+        }
+
+        fn get_weighted_tallies() -> Vec<(Hash, pallet_collective::WeightedTally)> {
+            Triumvirate::proposals()
+                .into_iter()
+                .filter_map(|hash| Some((hash, Triumvirate::weighted_tally(hash)?)))
+                .collect()
+        }
+    }
 }
 
 #[test]
@@ -2238,3 +2566,27 @@ fn test_into_evm_balance_overflow() {
     let result = SubtensorEvmBalanceConverter::into_evm_balance(substrate_balance);
     assert_eq!(result, Some(expected_evm_balance)); // Should return the scaled value
 }
+
+#[test]
+fn test_no_nesting_call_filter_rejects_nested_batch_if() {
+    let remark = || RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    let batch_if = |calls: Vec<RuntimeCall>| {
+        RuntimeCall::Utility(pallet_utility::Call::batch_if {
+            predicates: BoundedVec::default(),
+            calls,
+        })
+    };
+    let batch =
+        |calls: Vec<RuntimeCall>| RuntimeCall::Utility(pallet_utility::Call::batch { calls });
+
+    assert!(NoNestingCallFilter::contains(&batch_if(vec![remark()])));
+    assert!(!NoNestingCallFilter::contains(&batch_if(vec![batch_if(
+        vec![remark()]
+    )])));
+    assert!(!NoNestingCallFilter::contains(&batch_if(vec![batch(
+        vec![remark()]
+    )])));
+    assert!(!NoNestingCallFilter::contains(&batch(vec![batch_if(
+        vec![remark()]
+    )])));
+}
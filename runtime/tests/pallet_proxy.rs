@@ -3,9 +3,10 @@
 use codec::Encode;
 use frame_support::{BoundedVec, assert_ok, traits::InstanceFilter};
 use node_subtensor_runtime::{
-    BalancesCall, BuildStorage, Proxy, Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig,
-    RuntimeOrigin, SubtensorModule, System, SystemCall,
+    BalancesCall, BuildStorage, Proxy, ProxySpendingLimit, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeGenesisConfig, RuntimeOrigin, SubtensorModule, System, SystemCall,
 };
+use pallet_proxy::SpendingLimit;
 use subtensor_runtime_common::{AccountId, ProxyType};
 
 const ACCOUNT: [u8; 32] = [1_u8; 32];
@@ -112,6 +113,17 @@ fn call_add_stake() -> RuntimeCall {
     })
 }
 
+// weights call
+fn call_set_weights() -> RuntimeCall {
+    let netuid = 1;
+    RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_weights {
+        netuid,
+        dests: vec![0],
+        weights: vec![1],
+        version_key: 0,
+    })
+}
+
 // register call, account as hotkey, delegate as coldkey
 fn call_register() -> RuntimeCall {
     let block_number: u64 = 1;
@@ -174,6 +186,16 @@ fn test_proxy_pallet() {
         ProxyType::Governance,
         ProxyType::Staking,
         ProxyType::Registration,
+        ProxyType::SubnetOwner(1),
+        ProxyType::SubnetOwner(2),
+        ProxyType::SubnetStaking(1),
+        ProxyType::SubnetStaking(2),
+        ProxyType::SubnetWeights(1),
+        ProxyType::Weights,
+        ProxyType::HotkeyStaking(BoundedVec::truncate_from(vec![AccountId::from(DELEGATE)])),
+        ProxyType::HotkeyStaking(BoundedVec::truncate_from(vec![AccountId::from(
+            OTHER_ACCOUNT,
+        )])),
     ];
 
     let calls = [
@@ -185,6 +207,7 @@ fn test_proxy_pallet() {
         call_triumvirate,
         call_senate,
         call_add_stake,
+        call_set_weights,
         call_register,
     ];
 
@@ -194,7 +217,7 @@ fn test_proxy_pallet() {
                 assert_ok!(Proxy::add_proxy(
                     RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
                     AccountId::from(DELEGATE).into(),
-                    *proxy_type,
+                    proxy_type.clone(),
                     0
                 ));
 
@@ -230,3 +253,268 @@ fn test_non_transfer_cannot_transfer() {
         );
     });
 }
+
+#[test]
+fn test_subnet_owner_proxy_is_scoped_to_netuid() {
+    let proxy_type = ProxyType::SubnetOwner(1);
+
+    assert!(proxy_type.filter(&call_owner_util()));
+    assert!(!ProxyType::SubnetOwner(2).filter(&call_owner_util()));
+
+    // Root only calls are filtered even on the delegated subnet.
+    assert!(!proxy_type.filter(&RuntimeCall::AdminUtils(
+        pallet_admin_utils::Call::sudo_set_tempo {
+            netuid: 1,
+            tempo: 1
+        }
+    )));
+
+    // Queued owner changes are checked against the inner call.
+    assert!(proxy_type.filter(&RuntimeCall::AdminUtils(
+        pallet_admin_utils::Call::schedule_hyperparameter_change {
+            call: Box::new(pallet_admin_utils::Call::sudo_set_kappa {
+                netuid: 1,
+                kappa: 1
+            })
+        }
+    )));
+    assert!(!proxy_type.filter(&RuntimeCall::AdminUtils(
+        pallet_admin_utils::Call::schedule_hyperparameter_change {
+            call: Box::new(pallet_admin_utils::Call::sudo_set_kappa {
+                netuid: 2,
+                kappa: 1
+            })
+        }
+    )));
+}
+
+#[test]
+fn test_subnet_staking_proxy_is_scoped_to_netuid() {
+    let proxy_type = ProxyType::SubnetStaking(1);
+    let move_stake = |destination_netuid| {
+        RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake {
+            origin_hotkey: AccountId::from(DELEGATE),
+            destination_hotkey: AccountId::from(DELEGATE),
+            origin_netuid: 1,
+            destination_netuid,
+            alpha_amount: 100,
+        })
+    };
+
+    assert!(proxy_type.filter(&call_add_stake()));
+    assert!(!ProxyType::SubnetStaking(2).filter(&call_add_stake()));
+    assert!(proxy_type.filter(&move_stake(1)));
+    assert!(!proxy_type.filter(&move_stake(2)));
+    assert!(!proxy_type.filter(&RuntimeCall::SubtensorModule(
+        pallet_subtensor::Call::unstake_all {
+            hotkey: AccountId::from(DELEGATE)
+        }
+    )));
+}
+
+#[test]
+fn test_subnet_weights_proxy_is_scoped_to_netuid() {
+    let proxy_type = ProxyType::SubnetWeights(1);
+    let batch_commit = |netuids: Vec<u16>| {
+        RuntimeCall::SubtensorModule(pallet_subtensor::Call::batch_commit_weights {
+            commit_hashes: netuids.iter().map(|_| Default::default()).collect(),
+            netuids: netuids.into_iter().map(Into::into).collect(),
+        })
+    };
+
+    assert!(proxy_type.filter(&call_set_weights()));
+    assert!(!ProxyType::SubnetWeights(2).filter(&call_set_weights()));
+    assert!(proxy_type.filter(&batch_commit(vec![1, 1])));
+    assert!(!proxy_type.filter(&batch_commit(vec![1, 2])));
+    assert!(!proxy_type.filter(&call_add_stake()));
+}
+
+#[test]
+fn test_hotkey_staking_proxy_is_scoped_to_hotkeys() {
+    let proxy_type = ProxyType::HotkeyStaking(BoundedVec::truncate_from(vec![
+        AccountId::from(ACCOUNT),
+        AccountId::from(DELEGATE),
+    ]));
+    let move_stake = |destination_hotkey: [u8; 32]| {
+        RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake {
+            origin_hotkey: AccountId::from(DELEGATE),
+            destination_hotkey: AccountId::from(destination_hotkey),
+            origin_netuid: 1,
+            destination_netuid: 2,
+            alpha_amount: 100,
+        })
+    };
+
+    assert!(proxy_type.filter(&call_add_stake()));
+    assert!(proxy_type.filter(&move_stake(ACCOUNT)));
+    assert!(!proxy_type.filter(&move_stake(OTHER_ACCOUNT)));
+    assert!(
+        !ProxyType::HotkeyStaking(BoundedVec::truncate_from(vec![AccountId::from(ACCOUNT)]))
+            .filter(&call_add_stake())
+    );
+}
+
+#[test]
+fn test_scoped_proxy_supersets() {
+    let both = ProxyType::HotkeyStaking(BoundedVec::truncate_from(vec![
+        AccountId::from(ACCOUNT),
+        AccountId::from(DELEGATE),
+    ]));
+    let one = ProxyType::HotkeyStaking(BoundedVec::truncate_from(vec![AccountId::from(DELEGATE)]));
+
+    assert!(ProxyType::Owner.is_superset(&ProxyType::SubnetOwner(1)));
+    assert!(!ProxyType::SubnetOwner(1).is_superset(&ProxyType::Owner));
+    assert!(!ProxyType::SubnetOwner(1).is_superset(&ProxyType::SubnetOwner(2)));
+    assert!(ProxyType::Staking.is_superset(&ProxyType::SubnetStaking(1)));
+    assert!(ProxyType::Staking.is_superset(&one));
+    assert!(both.is_superset(&one));
+    assert!(!one.is_superset(&both));
+}
+
+#[test]
+fn test_subnet_owner_proxy_cannot_act_on_other_subnet() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
+            AccountId::from(DELEGATE).into(),
+            ProxyType::SubnetOwner(2),
+            0
+        ));
+
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(AccountId::from(DELEGATE)),
+            AccountId::from(ACCOUNT).into(),
+            None,
+            Box::new(call_owner_util()),
+        ));
+
+        System::assert_last_event(
+            pallet_proxy::Event::ProxyExecuted {
+                result: Err(SystemError::CallFiltered.into()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_spending_limit_proxy_is_budgeted() {
+    new_test_ext().execute_with(|| {
+        let proxy_type = ProxyType::SpendingLimit {
+            budget: 150,
+            window: 7200,
+        };
+        assert!(proxy_type.filter(&call_transfer()));
+        assert!(proxy_type.filter(&call_add_stake()));
+        assert!(!proxy_type.filter(&call_remark()));
+        assert!(!ProxyType::NonTransfer.is_superset(&proxy_type));
+
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
+            AccountId::from(DELEGATE).into(),
+            proxy_type,
+            0
+        ));
+        assert_eq!(
+            Proxy::remaining_allowance(&AccountId::from(ACCOUNT), &AccountId::from(DELEGATE)),
+            Some(150)
+        );
+
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(AccountId::from(DELEGATE)),
+            AccountId::from(ACCOUNT).into(),
+            None,
+            Box::new(call_transfer()),
+        ));
+        System::assert_last_event(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+        assert_eq!(
+            Proxy::remaining_allowance(&AccountId::from(ACCOUNT), &AccountId::from(DELEGATE)),
+            Some(50)
+        );
+
+        // A second transfer of 100 would exceed the budget.
+        assert!(
+            Proxy::proxy(
+                RuntimeOrigin::signed(AccountId::from(DELEGATE)),
+                AccountId::from(ACCOUNT).into(),
+                None,
+                Box::new(call_transfer()),
+            )
+            .is_err()
+        );
+    });
+}
+
+#[test]
+fn test_weights_proxy_only_sets_weights() {
+    let commit_weights = RuntimeCall::SubtensorModule(pallet_subtensor::Call::commit_weights {
+        netuid: 1,
+        commit_hash: Default::default(),
+    });
+    let reveal_weights = RuntimeCall::SubtensorModule(pallet_subtensor::Call::reveal_weights {
+        netuid: 1,
+        uids: vec![0],
+        values: vec![1],
+        salt: vec![2],
+        version_key: 0,
+    });
+
+    assert!(ProxyType::Weights.filter(&call_set_weights()));
+    assert!(ProxyType::Weights.filter(&commit_weights));
+    assert!(ProxyType::Weights.filter(&reveal_weights));
+    assert!(!ProxyType::Weights.filter(&call_add_stake()));
+    assert!(!ProxyType::Weights.filter(&call_transfer()));
+    assert!(ProxyType::Weights.is_superset(&ProxyType::SubnetWeights(1)));
+    assert!(!ProxyType::SubnetWeights(1).is_superset(&ProxyType::Weights));
+}
+
+#[test]
+fn test_weights_proxy_sets_weights_as_hotkey() {
+    new_test_ext().execute_with(|| {
+        // The hotkey delegates weight setting to a separate key.
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
+            AccountId::from(DELEGATE).into(),
+            ProxyType::Weights,
+            0
+        ));
+
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(AccountId::from(DELEGATE)),
+            AccountId::from(ACCOUNT).into(),
+            None,
+            Box::new(call_set_weights()),
+        ));
+
+        // The call reaches the subtensor pallet, which rejects it since the subnet does not
+        // exist, rather than being filtered by the proxy.
+        System::assert_last_event(
+            pallet_proxy::Event::ProxyExecuted {
+                result: Err(sp_runtime::DispatchError::from(
+                    pallet_subtensor::Error::<Runtime>::SubNetworkDoesNotExist,
+                )
+                .stripped()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_spending_limit_charges_transferred_alpha_at_price() {
+    new_test_ext().execute_with(|| {
+        let netuid = 1;
+        pallet_subtensor::SubnetMechanism::<Runtime>::insert(netuid, 1);
+        pallet_subtensor::SubnetTAO::<Runtime>::insert(netuid, 2_000_000_000);
+        pallet_subtensor::SubnetAlphaIn::<Runtime>::insert(netuid, 1_000_000_000);
+
+        let call = RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake {
+            destination_coldkey: AccountId::from(OTHER_ACCOUNT),
+            hotkey: AccountId::from(DELEGATE),
+            origin_netuid: netuid,
+            destination_netuid: netuid,
+            alpha_amount: 100,
+        });
+        assert_eq!(ProxySpendingLimit::spent(&call), 200);
+    });
+}
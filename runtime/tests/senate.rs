@@ -0,0 +1,109 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::Encode;
+use frame_support::{BoundedVec, assert_noop, assert_ok, dispatch::GetDispatchInfo};
+use node_subtensor_runtime::{
+    BuildStorage, CouncilMotionDuration, Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig,
+    RuntimeOrigin, System, Triumvirate,
+};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use subtensor_runtime_common::AccountId;
+
+const PROPOSER: [u8; 32] = [1_u8; 32];
+const WHALE: [u8; 32] = [2_u8; 32];
+const MINNOW: [u8; 32] = [3_u8; 32];
+const REGISTRAR: [u8; 32] = [4_u8; 32];
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let senators = vec![
+        AccountId::from(PROPOSER),
+        AccountId::from(WHALE),
+        AccountId::from(MINNOW),
+    ];
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        triumvirate: pallet_collective::GenesisConfig {
+            members: vec![AccountId::from(PROPOSER)],
+            phantom: Default::default(),
+        },
+        senate_members: pallet_membership::GenesisConfig {
+            members: BoundedVec::try_from(senators).unwrap(),
+            phantom: Default::default(),
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        // Root stake is priced at one TAO per alpha, so it is the vote weight as is.
+        for (senator, stake) in [(PROPOSER, 10), (WHALE, 1_000), (MINNOW, 10)] {
+            pallet_subtensor::TotalHotkeyAlpha::<Runtime>::insert(
+                AccountId::from(senator),
+                0,
+                stake,
+            );
+        }
+    });
+    ext
+}
+
+// call that needs a senate majority origin
+fn call_add_registrar() -> RuntimeCall {
+    RuntimeCall::Registry(pallet_registry::Call::add_registrar {
+        account: AccountId::from(REGISTRAR),
+    })
+}
+
+#[test]
+fn test_weighted_senate_motion_dispatches_with_majority_origin() {
+    new_test_ext().execute_with(|| {
+        let proposal = call_add_registrar();
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash = BlakeTwo256::hash_of(&proposal);
+        assert_ok!(Triumvirate::propose(
+            RuntimeOrigin::signed(AccountId::from(PROPOSER)),
+            Box::new(proposal),
+            proposal_len,
+            CouncilMotionDuration::get()
+        ));
+
+        // The whale holds almost all of the stake, but a single member is not a senate majority.
+        assert_ok!(Triumvirate::do_vote(AccountId::from(WHALE), hash, 0, true));
+        assert_noop!(
+            Triumvirate::close(
+                RuntimeOrigin::signed(AccountId::from(REGISTRAR)),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            pallet_collective::Error::<Runtime, pallet_collective::Instance1>::TooEarlyToCloseProposal
+        );
+
+        assert_ok!(Triumvirate::do_vote(
+            AccountId::from(MINNOW),
+            hash,
+            0,
+            true
+        ));
+        assert_ok!(Triumvirate::close(
+            RuntimeOrigin::signed(AccountId::from(REGISTRAR)),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::Triumvirate(pallet_collective::Event::Executed {
+                proposal_hash: hash,
+                result: Ok(()),
+            })));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::Registry(pallet_registry::Event::RegistrarAdded {
+                registrar_index: 0,
+            })));
+    });
+}